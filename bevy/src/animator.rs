@@ -2,38 +2,21 @@
 
 use crate::traits::SafeTimeline;
 use bevy::prelude::*;
+use mina::PlayerState;
 use std::time::Duration;
 
-/// The state of an [Animator].
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Reflect)]
-pub enum AnimationState {
-    /// No animation. Either the animator does not have a [Timeline](mina::Timeline), or the
-    /// timeline was just added on this frame and the animation system has not run yet.
-    #[default]
-    None,
-    /// Animation has not started yet because of a configured [delay](mina::Timeline::delay), and
-    /// will start after the delay elapses.
-    Waiting,
-    /// Animation is in progress.
-    Playing,
-    /// Animation progressed to the end of the [Timeline](mina::Timeline) and there are no more
-    /// frames to animate. This state is only possible for timelines whose
-    /// [repeat](mina::Timeline::repeat) is not [Infinite](mina::Repeat::Infinite).
-    Ended,
-}
-
 /// An event that is sent whenever an animator's [Animator::state] changes.
 #[derive(Event, Reflect)]
 pub struct AnimationStateChanged {
     /// The entity to which the affected [Animator] is attached.
     pub entity: Entity,
-    /// State of the affected [Animator] when the event was created.
-    pub state: AnimationState,
+    /// State of the affected [Animator] when the event was created. See [Animator::state].
+    pub state: Option<PlayerState>,
 }
 
 impl AnimationStateChanged {
     /// Creates a new [AnimationStateChanged] event.
-    pub fn new(entity: Entity, state: AnimationState) -> Self {
+    pub fn new(entity: Entity, state: Option<PlayerState>) -> Self {
         Self { entity, state }
     }
 }
@@ -52,13 +35,13 @@ pub struct Animator<T: Component> {
     /// that the current timeline has been active on this component.
     ///
     /// Be careful when setting this property directly, as it will not change the animator's
-    /// [Self::state]. If the state is already [AnimationState::Ended], then setting the position
+    /// [Self::state]. If the state is already [PlayerState::Ended], then setting the position
     /// before the end time will not restart it. To manually restart animation using the existing
     /// timeline, call [Self::reset].
     pub timeline_position: Duration,
     #[reflect(ignore)]
     pub(super) timeline: Option<Box<dyn SafeTimeline<Target = T>>>,
-    pub(super) state: AnimationState,
+    pub(super) state: Option<PlayerState>,
 }
 
 impl<T: Component> Default for Animator<T> {
@@ -67,7 +50,7 @@ impl<T: Component> Default for Animator<T> {
             enabled: true,
             timeline_position: Duration::ZERO,
             timeline: None,
-            state: AnimationState::default(),
+            state: None,
         }
     }
 }
@@ -79,7 +62,7 @@ impl<T: Component> Animator<T> {
             enabled: true,
             timeline: None,
             timeline_position: Duration::ZERO,
-            state: AnimationState::None,
+            state: None,
         }
     }
 
@@ -89,7 +72,7 @@ impl<T: Component> Animator<T> {
             enabled: true,
             timeline: Some(Box::new(timeline)),
             timeline_position: Duration::ZERO,
-            state: AnimationState::None,
+            state: None,
         }
     }
 
@@ -109,7 +92,7 @@ impl<T: Component> Animator<T> {
     /// animation frames.
     pub fn reset(&mut self) {
         self.timeline_position = Duration::ZERO;
-        self.state = AnimationState::None;
+        self.state = None;
     }
 
     /// Configures the [Timeline](mina::Timeline) that this animator will use.
@@ -127,7 +110,16 @@ impl<T: Component> Animator<T> {
     }
 
     /// Gets the current animation state.
-    pub fn state(&self) -> AnimationState {
+    ///
+    /// This is `None` if there is no animation, i.e. the animator does not have a
+    /// [Timeline](mina::Timeline), or the timeline was just added or [reset](Self::reset) on this
+    /// frame and the animation system has not run yet. Once a timeline that does not repeat
+    /// [infinitely](mina::Repeat::Infinite) has [ended](PlayerState::Ended), it stays ended until
+    /// the animator is reset.
+    ///
+    /// This replaces the `AnimationState` enum of earlier versions, which had the same states as
+    /// [PlayerState] plus an `AnimationState::None` state, which is now represented by `None`.
+    pub fn state(&self) -> Option<PlayerState> {
         self.state
     }
 }
@@ -142,42 +134,31 @@ pub(super) fn animate<T: Component>(
         if !animator.enabled {
             continue;
         }
-        if animator.timeline.is_none() {
-            if animator.state != AnimationState::None {
-                animator.state = AnimationState::None;
-                events.send(AnimationStateChanged::new(entity, AnimationState::None));
+        let Some(timeline) = animator.timeline.as_ref() else {
+            if animator.state.is_some() {
+                animator.state = None;
+                events.send(AnimationStateChanged::new(entity, None));
             }
             continue;
-        }
+        };
         let position_secs = animator.timeline_position.as_secs_f32();
-        let timeline = animator.timeline.as_ref().unwrap();
-        // Early assignments are needed to make Rust's borrow checker happy; it won't let us read
-        // from the `timeline` struct anymore after the `update`.
-        let timeline_delay = timeline.delay();
-        let timeline_duration = timeline.duration();
-        if animator.state == AnimationState::Playing {
+        if animator.state == Some(PlayerState::Playing) {
             if let Ok(mut target) = targets.get_mut(entity) {
                 timeline.update(&mut target, position_secs);
             }
         }
-        let mut state_changed = false;
-        if animator.state == AnimationState::None {
-            animator.state = AnimationState::Waiting;
-            state_changed = true;
-        }
-        if animator.state == AnimationState::Waiting && position_secs >= timeline_delay {
-            animator.state = AnimationState::Playing;
-            state_changed = true;
-        }
-        if position_secs >= timeline_duration && animator.state != AnimationState::Ended {
-            animator.state = AnimationState::Ended;
-            state_changed = true;
-        }
-        if animator.state != AnimationState::Ended {
+        // Unlike a TimelinePlayer, the animator does not automatically restart an ended animation
+        // when its position is moved back; that requires an explicit reset.
+        let state = match animator.state {
+            Some(PlayerState::Ended) => PlayerState::Ended,
+            _ => PlayerState::at(timeline.as_ref(), position_secs, false),
+        };
+        if state != PlayerState::Ended {
             animator.timeline_position += time.delta();
         }
-        if state_changed {
-            events.send(AnimationStateChanged::new(entity, animator.state));
+        if animator.state != Some(state) {
+            animator.state = Some(state);
+            events.send(AnimationStateChanged::new(entity, Some(state)));
         }
     }
 }
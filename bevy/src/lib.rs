@@ -119,11 +119,12 @@
 //! States can also be configured to auto-transition when animation ends; for more information,
 //! refer to the [AnimationChain](crate::selection::AnimationChain) documentation.
 
-use crate::animator::{animate, AnimationState, AnimationStateChanged, Animator};
+use crate::animator::{animate, AnimationStateChanged, Animator};
 use crate::selection::{chain_animations, select_animation, AnimationChain, AnimationSelector};
 use crate::traits::*;
 use bevy::prelude::*;
 use bevy::reflect::TypePath;
+use mina::PlayerState;
 use std::marker::PhantomData;
 
 pub mod prelude;
@@ -153,7 +154,7 @@ impl<T: Component> AnimationPlugin<T> {
 
 impl<T: Component> Plugin for AnimationPlugin<T> {
     fn build(&self, app: &mut App) {
-        app.register_type::<AnimationState>()
+        app.register_type::<PlayerState>()
             .register_type::<AnimationStateChanged>()
             .add_event::<AnimationStateChanged>()
             .add_systems(Update, animate::<T>);
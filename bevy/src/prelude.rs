@@ -1,10 +1,11 @@
 //! Common types used for Mina animations in Bevy apps.
 
 pub use crate::{
-    animator::{AnimationState, AnimationStateChanged, Animator},
+    animator::{AnimationStateChanged, Animator},
     selection::{
         AnimationChain, AnimationChainBuilder, AnimationSelector, AnimationSelectorBuilder,
     },
     traits::*,
     AnimationAppExt, AnimationPlugin,
 };
+pub use mina::PlayerState;
@@ -2,10 +2,11 @@
 //! [StateAnimator](mina::StateAnimator).
 
 use crate::traits::*;
-use crate::{AnimationState, AnimationStateChanged, Animator};
+use crate::{AnimationStateChanged, Animator};
 use bevy::prelude::*;
 use bevy::utils::HashMap;
 use dyn_clone::clone_box;
+use mina::PlayerState;
 
 /// [Component] for automatically selecting the [Timeline](mina::Timeline) of an [Animator] based on
 /// some arbitrary state.
@@ -93,7 +94,7 @@ impl<K: AnimationKey, T: Component> AnimationSelectorBuilder<K, T> {
 pub struct AnimationChain<K: AnimationKey> {
     /// Map of ended keys (states) to next keys.
     ///
-    /// When an [Animator] finishes its animation (transitions to [AnimationState::Ended]), if the
+    /// When an [Animator] finishes its animation (transitions to [PlayerState::Ended]), if the
     /// [AnimationSelector::timeline_key] matches a key in this map, then it will automatically be
     /// assigned the value for that key, and the animator will be reset.
     pub next_keys: HashMap<K, K>,
@@ -134,7 +135,7 @@ impl<K: AnimationKey> AnimationChainBuilder<K> {
     }
 
     /// Adds a new transition, specifying that when the animation in `ended_key` ends ([Animator]
-    /// transitions to [AnimationState::Ended]), the [AnimationSelector] should automatically switch
+    /// transitions to [PlayerState::Ended]), the [AnimationSelector] should automatically switch
     /// its [AnimationSelector::timeline_key] to `next_key`.
     pub fn add(mut self, ended_key: K, next_key: K) -> Self {
         self.next_keys.insert(ended_key, next_key);
@@ -155,7 +156,7 @@ pub(super) fn chain_animations<K: AnimationKey, T: Component>(
 ) {
     for ev in events.iter() {
         let AnimationStateChanged { entity, state } = ev;
-        if state != &AnimationState::Ended {
+        if *state != Some(PlayerState::Ended) {
             continue;
         }
         let Ok((mut selector, chain)) = selector_query.get_mut(*entity) else {
@@ -167,6 +168,7 @@ pub(super) fn chain_animations<K: AnimationKey, T: Component>(
     }
 }
 
+#[allow(clippy::type_complexity)]
 pub(super) fn select_animation<K: AnimationKey, T: Component>(
     mut selector_query: Query<
         (Entity, &T, &mut AnimationSelector<K, T>),
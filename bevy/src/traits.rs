@@ -1,4 +1,4 @@
-/// Convenience traits for using animation types in Bevy ECS.
+//! Convenience traits for using animation types in Bevy ECS.
 
 use std::hash::Hash;
 use dyn_clone::{clone_trait_object, DynClone};
@@ -0,0 +1,246 @@
+//! Angle types with configurable interpolation policies.
+//!
+//! Plain `f32` rotations interpolate numerically, so an animation from 350° to 10° turns 340° the
+//! long way round instead of 20° through zero. The [`Degrees`] and [`Radians`] types instead
+//! interpolate according to an [`AnglePolicy`], which is chosen with a type parameter:
+//!
+//! ```
+//! use mina_core::angle::{Clockwise, Degrees};
+//! use mina_core::interpolation::Lerp;
+//!
+//! let from = Degrees::<Clockwise>::new(350.0);
+//! let to = Degrees::new(10.0);
+//!
+//! assert_eq!(from.lerp(&to, 0.5).value(), 0.0);
+//! ```
+//!
+//! Angles increase in the clockwise direction, which is the convention for screen coordinates
+//! (where the y axis points down) and CSS transforms. In a coordinate system where the y axis
+//! points up, [`Clockwise`] and [`CounterClockwise`] are reversed.
+//!
+//! Every policy except [`Raw`] produces angles normalized to a single turn, i.e. in the range
+//! `0.0..360.0` for [`Degrees`] or `0.0..TAU` for [`Radians`].
+
+use crate::interpolation::{Lerp, VectorSpace};
+use std::f32::consts::TAU;
+use std::marker::PhantomData;
+
+/// Policy for interpolating between two angles, used by the [`Degrees`] and [`Radians`] types.
+pub trait AnglePolicy {
+    /// Gets the signed angle turned through when interpolating from angle `y0` to angle `y1`,
+    /// where `turn` is the size of one full turn in the angle's units.
+    fn delta(y0: f32, y1: f32, turn: f32) -> f32;
+
+    /// Interpolates from angle `y0` to angle `y1` at normalized position `x`, where `turn` is the
+    /// size of one full turn in the angle's units.
+    fn interpolate(y0: f32, y1: f32, x: f32, turn: f32) -> f32 {
+        (y0 + Self::delta(y0, y1, turn) * x).rem_euclid(turn)
+    }
+}
+
+/// Interpolates along the shorter of the two arcs between the angles, e.g. from 350° to 10° by
+/// passing through 0°. When both arcs are the same length, turns clockwise.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct ShortestArc;
+
+impl AnglePolicy for ShortestArc {
+    fn delta(y0: f32, y1: f32, turn: f32) -> f32 {
+        let delta = (y1 - y0).rem_euclid(turn);
+        if delta > turn / 2.0 {
+            delta - turn
+        } else {
+            delta
+        }
+    }
+}
+
+/// Always interpolates clockwise, i.e. with increasing angle, turning less than one full turn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Clockwise;
+
+impl AnglePolicy for Clockwise {
+    fn delta(y0: f32, y1: f32, turn: f32) -> f32 {
+        (y1 - y0).rem_euclid(turn)
+    }
+}
+
+/// Always interpolates counter-clockwise, i.e. with decreasing angle, turning less than one full
+/// turn.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct CounterClockwise;
+
+impl AnglePolicy for CounterClockwise {
+    fn delta(y0: f32, y1: f32, turn: f32) -> f32 {
+        -(y0 - y1).rem_euclid(turn)
+    }
+}
+
+/// Interpolates the angle values numerically, the same as a plain `f32`.
+///
+/// Angles are not normalized, so this is the only policy that can animate multiple turns, e.g.
+/// spinning from 0° to 720°.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Raw;
+
+impl AnglePolicy for Raw {
+    fn delta(y0: f32, y1: f32, _turn: f32) -> f32 {
+        y1 - y0
+    }
+
+    fn interpolate(y0: f32, y1: f32, x: f32, _turn: f32) -> f32 {
+        y0.lerp(&y1, x)
+    }
+}
+
+macro_rules! angle_type {
+    ($name:ident, $unit:literal, $turn:expr) => {
+        #[doc = concat!("An angle measured in ", $unit, ", which interpolates according to")]
+        /// the [`AnglePolicy`] `P`.
+        ///
+        /// Can be converted to and from a plain `f32`, which allows an `f32` field of an
+        /// [`Animate`](crate::timeline::Animate) type to interpolate as an angle by specifying the
+        /// angle type in a `via` attribute, e.g. `#[animate(via = "Degrees<Clockwise>")]`.
+        ///
+        /// As a [`VectorSpace`], the difference between two angles is the signed angle turned
+        /// through by the policy, which is not normalized. This allows angular velocities to be
+        /// negative, or larger than a full turn per second.
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $name<P = ShortestArc> {
+            value: f32,
+            policy: PhantomData<P>,
+        }
+
+        impl<P> $name<P> {
+            #[doc = concat!("Creates a new angle from a value in ", $unit, ".")]
+            pub fn new(value: f32) -> Self {
+                Self {
+                    value,
+                    policy: PhantomData,
+                }
+            }
+
+            #[doc = concat!("Gets the value of the angle, in ", $unit, ".")]
+            pub fn value(&self) -> f32 {
+                self.value
+            }
+
+            /// Converts the angle to the same value with a different interpolation policy.
+            pub fn with_policy<Q>(self) -> $name<Q> {
+                $name::new(self.value)
+            }
+        }
+
+        impl<P: AnglePolicy> Lerp for $name<P> {
+            fn lerp(&self, y1: &Self, x: f32) -> Self {
+                Self::new(P::interpolate(self.value, y1.value, x, $turn))
+            }
+        }
+
+        impl<P: AnglePolicy> VectorSpace for $name<P> {
+            fn scaled_difference(&self, origin: &Self, scale: f32) -> Self {
+                Self::new(P::delta(origin.value, self.value, $turn) * scale)
+            }
+        }
+
+        impl<P> From<f32> for $name<P> {
+            fn from(value: f32) -> Self {
+                Self::new(value)
+            }
+        }
+
+        impl<P> From<$name<P>> for f32 {
+            fn from(angle: $name<P>) -> Self {
+                angle.value
+            }
+        }
+    };
+}
+
+angle_type!(Degrees, "degrees", 360.0);
+angle_type!(Radians, "radians", TAU);
+
+impl<P> From<Radians<P>> for Degrees<P> {
+    fn from(angle: Radians<P>) -> Self {
+        Self::new(angle.value.to_degrees())
+    }
+}
+
+impl<P> From<Degrees<P>> for Radians<P> {
+    fn from(angle: Degrees<P>) -> Self {
+        Self::new(angle.value.to_radians())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    fn lerp_degrees<P: AnglePolicy>(y0: f32, y1: f32, x: f32) -> f32 {
+        Degrees::<P>::new(y0).lerp(&Degrees::new(y1), x).value()
+    }
+
+    fn difference_degrees<P: AnglePolicy>(y0: f32, y1: f32) -> f32 {
+        Degrees::<P>::new(y1)
+            .scaled_difference(&Degrees::new(y0), 1.0)
+            .value()
+    }
+
+    #[test]
+    fn when_shortest_arc_then_turns_through_zero() {
+        assert_relative_eq!(lerp_degrees::<ShortestArc>(350.0, 10.0, 0.25), 355.0);
+        assert_relative_eq!(lerp_degrees::<ShortestArc>(350.0, 10.0, 0.75), 5.0);
+        assert_relative_eq!(lerp_degrees::<ShortestArc>(10.0, 350.0, 0.75), 355.0);
+        assert_relative_eq!(lerp_degrees::<ShortestArc>(30.0, 90.0, 0.5), 60.0);
+        assert_relative_eq!(lerp_degrees::<ShortestArc>(350.0, 10.0, 1.0), 10.0);
+    }
+
+    #[test]
+    fn when_clockwise_then_angle_always_increases() {
+        assert_relative_eq!(lerp_degrees::<Clockwise>(350.0, 10.0, 0.5), 0.0);
+        assert_relative_eq!(lerp_degrees::<Clockwise>(10.0, 350.0, 0.5), 180.0);
+        assert_relative_eq!(lerp_degrees::<Clockwise>(90.0, 90.0, 0.5), 90.0);
+    }
+
+    #[test]
+    fn when_counter_clockwise_then_angle_always_decreases() {
+        assert_relative_eq!(lerp_degrees::<CounterClockwise>(350.0, 10.0, 0.5), 180.0);
+        assert_relative_eq!(lerp_degrees::<CounterClockwise>(10.0, 350.0, 0.5), 0.0);
+        assert_relative_eq!(lerp_degrees::<CounterClockwise>(10.0, 350.0, 1.0), 350.0);
+    }
+
+    #[test]
+    fn when_raw_then_interpolates_multiple_turns() {
+        assert_relative_eq!(lerp_degrees::<Raw>(0.0, 720.0, 0.75), 540.0);
+        assert_relative_eq!(lerp_degrees::<Raw>(350.0, 10.0, 0.5), 180.0);
+    }
+
+    #[test]
+    fn when_radians_then_wraps_at_full_turn() {
+        let from = Radians::<ShortestArc>::new(TAU - 0.1);
+        let to = Radians::new(0.3);
+
+        assert_relative_eq!(from.lerp(&to, 0.25).value(), 0.0, epsilon = 1e-5);
+        assert_relative_eq!(from.lerp(&to, 0.5).value(), 0.1, epsilon = 1e-5);
+    }
+
+    #[test]
+    fn when_difference_taken_then_is_signed_turn_of_policy() {
+        assert_relative_eq!(difference_degrees::<ShortestArc>(350.0, 10.0), 20.0);
+        assert_relative_eq!(difference_degrees::<ShortestArc>(10.0, 350.0), -20.0);
+        assert_relative_eq!(difference_degrees::<Clockwise>(10.0, 350.0), 340.0);
+        assert_relative_eq!(difference_degrees::<CounterClockwise>(350.0, 10.0), -340.0);
+        assert_relative_eq!(difference_degrees::<Raw>(0.0, 720.0), 720.0);
+    }
+
+    #[test]
+    fn when_converted_between_units_then_preserves_angle() {
+        let degrees = Degrees::<Raw>::from(Radians::new(std::f32::consts::PI));
+
+        assert_relative_eq!(degrees.value(), 180.0);
+        assert_relative_eq!(
+            f32::from(Radians::<Raw>::from(degrees)),
+            std::f32::consts::PI
+        );
+    }
+}
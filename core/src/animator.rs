@@ -1,11 +1,16 @@
 //! Stateful animations that change according to external conditions such as user interaction.
 
+use crate::marker::{buffer_crossings, MarkerCrossing};
 use crate::timeline::{MergedTimeline, Timeline, TimelineOrBuilder};
 pub use enum_map::Enum as State;
 use enum_map::{EnumArray, EnumMap};
 use std::marker::PhantomData;
 use std::time::Duration;
 
+/// Time, in seconds, after the point of interruption at which a previous animation is sampled in
+/// order to carry its velocity over to the next animation.
+const VELOCITY_SAMPLE_INTERVAL: f32 = 0.01;
+
 /// Provides read-only methods that are similar to those of a [`HashMap`](std::collections::HashMap)
 /// but can be implemented by other concrete types.
 ///
@@ -64,6 +69,9 @@ pub trait StateAnimator {
     /// recent update).
     ///
     /// If the current animation has ended and does not repeat, this has no effect.
+    ///
+    /// Any [markers](crate::marker::Marker) crossed by the current animation are buffered, and can
+    /// be retrieved afterward using [`take_markers`](Self::take_markers).
     fn advance(&mut self, elapsed_seconds: f32);
 
     /// Gets the current state of the animator.
@@ -94,6 +102,20 @@ pub trait StateAnimator {
     /// but the new `state` does not have any associated timeline, then the previous animation will
     /// be stopped but the values will not be changed.
     fn set_state(&mut self, state: &Self::State);
+
+    /// Removes and returns all markers crossed since the previous call to `take_markers`.
+    ///
+    /// Crossings are accumulated by every call to [`advance`](Self::advance), in the order they
+    /// were crossed, even across state changes. When a new animation starts, markers at its very
+    /// beginning are included in the next `advance`. Crossings are buffered until taken, up to
+    /// [`MAX_BUFFERED_CROSSINGS`](crate::marker::MAX_BUFFERED_CROSSINGS), so callers that add
+    /// markers to their timelines should take them after every update.
+    ///
+    /// The default implementation, for animators that do not support markers, always returns an
+    /// empty list.
+    fn take_markers(&mut self) -> Vec<MarkerCrossing> {
+        Vec::new()
+    }
 }
 
 /// Alias for a [`MappedTimelineAnimator`] whose map type is an [`EnumMap`].
@@ -117,6 +139,8 @@ where
     timelines: TimelineMap,
     current_state: State,
     current_values: Timeline::Target,
+    crossed_markers: Vec<MarkerCrossing>,
+    marker_time: f32,
     paused_animation: Option<(State, Duration)>,
     state_duration: Duration,
     _timeline_phantom: PhantomData<Timeline>,
@@ -134,6 +158,8 @@ where
             timelines,
             current_state: initial_state.clone(),
             current_values: initial_values,
+            crossed_markers: Vec::new(),
+            marker_time: f32::NEG_INFINITY,
             paused_animation: None,
             state_duration: Duration::ZERO,
             _timeline_phantom: PhantomData,
@@ -143,11 +169,46 @@ where
     }
 
     fn blend_next_timeline(&mut self, state: &State) {
+        // To preserve momentum, the interrupted animation is sampled slightly ahead of where it
+        // stopped, so that the next timeline can continue at the same velocity. This is skipped
+        // when the next timeline would ignore the velocity anyway.
+        let responds_to_velocity = self
+            .timelines
+            .get(state)
+            .is_some_and(|timeline| timeline.responds_to_velocity());
+        let next_values = self
+            .timelines
+            .get(&self.current_state)
+            .filter(|_| responds_to_velocity && state != &self.current_state)
+            .map(|timeline| {
+                let mut next_values = self.current_values.clone();
+                let time = self.state_duration.as_secs_f32() + VELOCITY_SAMPLE_INTERVAL;
+                timeline.update(&mut next_values, time);
+                next_values
+            });
         if let Some(next_timeline) = self.timelines.get_mut(state) {
-            next_timeline.start_with(&self.current_values);
+            match next_values {
+                Some(next_values) => next_timeline.start_with_velocity(
+                    &self.current_values,
+                    &next_values,
+                    VELOCITY_SAMPLE_INTERVAL,
+                ),
+                None => next_timeline.start_with(&self.current_values),
+            }
         }
     }
 
+    fn update_crossed_markers(&mut self) {
+        let time = self.state_duration.as_secs_f32();
+        if let Some(timeline) = self.timelines.get(&self.current_state) {
+            buffer_crossings(
+                &mut self.crossed_markers,
+                timeline.markers_between(self.marker_time, time),
+            );
+        }
+        self.marker_time = time;
+    }
+
     fn update_current_values(&mut self) {
         if let Some(timeline) = self.timelines.get(&self.current_state) {
             timeline.update(&mut self.current_values, self.state_duration.as_secs_f32());
@@ -169,6 +230,7 @@ where
     fn advance(&mut self, elapsed_seconds: f32) {
         self.state_duration += Duration::from_secs_f32(elapsed_seconds);
         self.update_current_values();
+        self.update_crossed_markers();
     }
 
     fn current_state(&self) -> &Self::State {
@@ -193,6 +255,7 @@ where
         match self.paused_animation.as_ref() {
             Some((paused_state, paused_position)) if state == paused_state => {
                 self.state_duration = *paused_position;
+                self.marker_time = paused_position.as_secs_f32();
             }
             _ => {
                 let was_animating = self.timelines.get(&self.current_state).is_some();
@@ -202,11 +265,16 @@ where
                 }
                 self.blend_next_timeline(state);
                 self.state_duration = Duration::ZERO;
+                self.marker_time = f32::NEG_INFINITY;
             }
         }
         self.current_state = state.clone();
         self.update_current_values();
     }
+
+    fn take_markers(&mut self) -> Vec<MarkerCrossing> {
+        std::mem::take(&mut self.crossed_markers)
+    }
 }
 
 // Examples not provided due to https://github.com/rust-lang/rust/issues/82544.
@@ -0,0 +1,497 @@
+//! Pre-sampled [`Timeline`]s for fast playback of many identical animations.
+
+use crate::interpolation::Lerp;
+use crate::marker::MarkerCrossing;
+use crate::timeline::{MergedTimeline, Repeat, Timeline, TimelineOrBuilder};
+use std::fmt::Write;
+
+/// A [`Timeline`] that plays back values sampled from another timeline at regular intervals.
+///
+/// Updating a regular timeline involves finding the current keyframe and computing the easing and
+/// interpolation for every animated property. Baking does all of that work ahead of time, so that
+/// each update only needs to interpolate linearly between two precomputed samples. This can be
+/// much faster when playing the same timeline for a large number of targets, at the cost of some
+/// accuracy, which can be measured with [`max_error`](Self::max_error).
+///
+/// Since samples hold the entire target value, [`update`](Timeline::update) overwrites every
+/// property, including properties that the original timeline does not animate; those are set to
+/// their [`Default`] values. For the same reason, baked timelines cannot be
+/// [blended](Timeline::start_with) with other animations; a [`StateAnimator`] using them will jump
+/// to the start values of each new animation. Baked timelines also do not report
+/// [velocities](Timeline::update_velocity). Timelines generated by `derive(Animate)` do not have
+/// these limitations if they are baked with [`BakeProperties`] instead.
+///
+/// Timelines that repeat infinitely cannot be baked in their entirety, so only the delay and first
+/// cycle are baked. To loop the baked cycle, use
+/// [`TimelineExt::clip`](crate::timeline::TimelineExt::clip) with
+/// [`looping`](crate::timeline::ClippedTimeline::looping).
+///
+/// [`StateAnimator`]: crate::animator::StateAnimator
+#[derive(Clone, Debug)]
+pub struct BakedTimeline<V> {
+    cycle_duration: Option<f32>,
+    delay: f32,
+    duration: f32,
+    markers: Vec<MarkerCrossing>,
+    repeat: Repeat,
+    sample_interval: f32,
+    samples: Vec<V>,
+}
+
+/// Trait for a [`Timeline`] that can be baked one property at a time, into a timeline of the same
+/// type.
+///
+/// This is implemented for all timelines generated by `derive(Animate)`, and for
+/// [`MergedTimeline`]s of such timelines. Unlike a [`BakedTimeline`], the baked timeline only
+/// updates the animated properties, can be [blended](Timeline::start_with) with other animations
+/// and reports [velocities](Timeline::update_velocity). Neither the target type nor its properties
+/// need to implement [`Default`].
+///
+/// To blend, the baked timeline keeps the first keyframe segment of each property as it was,
+/// i.e. unbaked, and plays that segment from the blended start value with its original easing.
+/// Blending therefore takes exactly as long as in the original timeline, and responds to velocity
+/// if the original easing does; the rest of the timeline, and the first segment when not blending,
+/// is played back from the samples.
+///
+/// Samples are taken over a single cycle of the timeline, so the baked timeline keeps the delay,
+/// repetitions, direction and markers of the original.
+pub trait BakeProperties: Timeline + Sized {
+    /// Creates a copy of this timeline whose animated properties are sampled at a fixed
+    /// `sample_rate`, and interpolated linearly between samples.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample_rate` - Minimum number of samples per unit of time (generally seconds) in each
+    ///   cycle. Samples are evenly spaced over the cycle, including both its start and end, so the
+    ///   actual rate may be slightly higher.
+    fn bake_properties(&self, sample_rate: f32) -> Self;
+}
+
+/// Computes the normalized times at which to sample a cycle of the given duration, for
+/// [`BakeProperties`].
+///
+/// The times are evenly spaced, start at `0.0` and end at `1.0`, with at least `sample_rate`
+/// samples per unit of time. This is mainly used by timelines generated by `derive(Animate)`.
+pub fn cycle_sample_times(cycle_duration: f32, sample_rate: f32) -> Vec<f32> {
+    let interval_count = (cycle_duration * sample_rate).ceil();
+    let interval_count = if interval_count.is_finite() {
+        interval_count.max(1.0) as usize
+    } else {
+        1
+    };
+    (0..=interval_count)
+        .map(|index| index as f32 / interval_count as f32)
+        .collect()
+}
+
+impl<V: Clone + Default + Lerp> BakedTimeline<V> {
+    /// Creates a [`BakedTimeline`] by sampling another `timeline`.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeline` - The timeline to sample.
+    /// * `sample_rate` - Minimum number of samples per unit of time (generally seconds). Samples are
+    ///   evenly spaced over the timeline's entire duration, including both its start and end, so
+    ///   the actual rate may be slightly higher.
+    pub fn new<T: Timeline<Target = V>>(timeline: &T, sample_rate: f32) -> Self {
+        let duration = timeline.duration();
+        let (duration, repeat) = if duration.is_finite() {
+            (duration, timeline.repeat())
+        } else {
+            let cycle_duration = timeline.cycle_duration().filter(|d| d.is_finite());
+            (
+                timeline.delay() + cycle_duration.unwrap_or(0.0),
+                Repeat::None,
+            )
+        };
+        let interval_count = (duration * sample_rate).ceil().max(1.0) as usize;
+        let sample_interval = duration / interval_count as f32;
+        let sample_times = (0..=interval_count)
+            .map(|index| {
+                if index == interval_count {
+                    duration
+                } else {
+                    index as f32 * sample_interval
+                }
+            })
+            .collect::<Vec<_>>();
+        let samples = sample_times
+            .iter()
+            .map(|&time| sample(timeline, time))
+            .collect();
+        // Timelines may only report a limited number of crossings per query, so markers are
+        // collected one sample interval at a time.
+        let markers = std::iter::once(f32::NEG_INFINITY)
+            .chain(sample_times.iter().copied())
+            .zip(sample_times.iter().copied())
+            .flat_map(|(prev_time, time)| timeline.markers_between(prev_time, time))
+            .collect();
+        Self {
+            cycle_duration: timeline.cycle_duration(),
+            delay: timeline.delay(),
+            duration,
+            markers,
+            repeat,
+            sample_interval,
+            samples,
+        }
+    }
+
+    /// Estimates the largest difference between this baked timeline and the original `timeline`
+    /// from which it was baked.
+    ///
+    /// Differences are measured halfway between every pair of consecutive samples, which is where
+    /// the error of linear interpolation is normally greatest.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeline` - The original timeline, from which this instance was baked.
+    /// * `distance` - Function that computes the difference between two values, e.g. the largest
+    ///   absolute difference of any property.
+    pub fn max_error<T: Timeline<Target = V>>(
+        &self,
+        timeline: &T,
+        distance: impl Fn(&V, &V) -> f32,
+    ) -> f32 {
+        (1..self.samples.len())
+            .map(|index| {
+                let time = (index as f32 - 0.5) * self.sample_interval;
+                let mut baked_values = V::default();
+                self.update(&mut baked_values, time);
+                distance(&baked_values, &sample(timeline, time))
+            })
+            .fold(0.0, f32::max)
+    }
+}
+
+impl<V> BakedTimeline<V> {
+    /// Gets the time between consecutive samples.
+    pub fn sample_interval(&self) -> f32 {
+        self.sample_interval
+    }
+
+    /// Gets all of the samples, starting at time zero and ending at the end of the timeline, which
+    /// are separated by the [`sample_interval`](Self::sample_interval).
+    pub fn samples(&self) -> &[V] {
+        &self.samples
+    }
+
+    /// Exports the samples in CSV format, with one row per sample.
+    ///
+    /// The first column is always the sample time, with the header `time`.
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - Headers of the columns following the time column.
+    /// * `columns` - Function that converts each sample to the values of the columns following the
+    ///   time column, in the same order as the `headers`.
+    pub fn to_csv(&self, headers: &[&str], columns: impl Fn(&V) -> Vec<f32>) -> String {
+        let mut csv = String::new();
+        csv.push_str("time");
+        for header in headers {
+            csv.push(',');
+            csv.push_str(header);
+        }
+        csv.push('\n');
+        for (index, values) in self.samples.iter().enumerate() {
+            // Writing to a String cannot fail.
+            let _ = write!(csv, "{}", index as f32 * self.sample_interval);
+            for value in columns(values) {
+                let _ = write!(csv, ",{value}");
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+impl<V: Clone + Lerp> Timeline for BakedTimeline<V> {
+    type Target = V;
+
+    fn cycle_duration(&self) -> Option<f32> {
+        self.cycle_duration
+    }
+
+    fn delay(&self) -> f32 {
+        self.delay
+    }
+
+    fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        let is_backward = time < prev_time;
+        let mut crossings = self
+            .markers
+            .iter()
+            .filter(|crossing| match is_backward {
+                true => crossing.time >= time && crossing.time < prev_time,
+                false => crossing.time > prev_time && crossing.time <= time,
+            })
+            .cloned()
+            .collect::<Vec<_>>();
+        if is_backward {
+            crossings.reverse();
+        }
+        crossings
+    }
+
+    fn repeat(&self) -> Repeat {
+        self.repeat
+    }
+
+    fn start_with(&mut self, _values: &Self::Target) {}
+
+    fn responds_to_velocity(&self) -> bool {
+        false
+    }
+
+    fn update(&self, values: &mut Self::Target, time: f32) {
+        let Some(last_index) = self.samples.len().checked_sub(1) else {
+            return;
+        };
+        if last_index == 0 || self.sample_interval <= 0.0 {
+            *values = self.samples[0].clone();
+            return;
+        }
+        let position = (time / self.sample_interval).clamp(0.0, last_index as f32);
+        let index = (position.floor() as usize).min(last_index - 1);
+        *values = self.samples[index].lerp(&self.samples[index + 1], position - index as f32);
+    }
+}
+
+impl<V: Clone + Lerp> TimelineOrBuilder<BakedTimeline<V>> for BakedTimeline<V> {
+    fn build(self) -> MergedTimeline<BakedTimeline<V>> {
+        MergedTimeline::of([self])
+    }
+}
+
+fn sample<T: Timeline>(timeline: &T, time: f32) -> T::Target
+where
+    T::Target: Default,
+{
+    let mut values = T::Target::default();
+    timeline.update(&mut values, time);
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::marker::Marker;
+    use crate::time_scale::TimeScale;
+    use crate::timeline::TimelineExt;
+    use approx::assert_relative_eq;
+
+    // Timeline whose value is the square of the time since the end of the delay, which is easy to
+    // verify but cannot be baked without error.
+    #[derive(Clone)]
+    struct QuadraticTimeline {
+        delay: f32,
+        duration: f32,
+        repeat: Repeat,
+    }
+
+    impl Timeline for QuadraticTimeline {
+        type Target = f32;
+
+        fn cycle_duration(&self) -> Option<f32> {
+            Some(self.duration - self.delay)
+        }
+
+        fn delay(&self) -> f32 {
+            self.delay
+        }
+
+        fn duration(&self) -> f32 {
+            match self.repeat {
+                Repeat::Infinite => f32::INFINITY,
+                _ => self.duration,
+            }
+        }
+
+        fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+            if prev_time < 1.0 && time >= 1.0 {
+                vec![MarkerCrossing {
+                    name: "one".to_string(),
+                    time: 1.0,
+                    cycle: 0,
+                }]
+            } else {
+                Vec::new()
+            }
+        }
+
+        fn repeat(&self) -> Repeat {
+            self.repeat
+        }
+
+        fn start_with(&mut self, _values: &Self::Target) {}
+
+        fn update(&self, values: &mut Self::Target, time: f32) {
+            let time = (time - self.delay).clamp(0.0, self.duration - self.delay);
+            *values = time * time;
+        }
+    }
+
+    // Timeline with no values, whose markers are crossed according to a time scale.
+    struct MarkerTimeline {
+        timescale: TimeScale,
+    }
+
+    impl Timeline for MarkerTimeline {
+        type Target = f32;
+
+        fn cycle_duration(&self) -> Option<f32> {
+            Some(self.timescale.get_cycle_duration())
+        }
+
+        fn delay(&self) -> f32 {
+            self.timescale.get_delay()
+        }
+
+        fn duration(&self) -> f32 {
+            self.timescale.get_duration()
+        }
+
+        fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+            self.timescale.markers_between(prev_time, time)
+        }
+
+        fn repeat(&self) -> Repeat {
+            self.timescale.get_repeat()
+        }
+
+        fn start_with(&mut self, _values: &Self::Target) {}
+
+        fn update(&self, _values: &mut Self::Target, _time: f32) {}
+    }
+
+    fn timeline() -> QuadraticTimeline {
+        QuadraticTimeline {
+            delay: 0.0,
+            duration: 2.0,
+            repeat: Repeat::None,
+        }
+    }
+
+    fn baked_value(timeline: &BakedTimeline<f32>, time: f32) -> f32 {
+        let mut value = 0.0;
+        timeline.update(&mut value, time);
+        value
+    }
+
+    #[test]
+    fn samples_evenly_including_start_and_end() {
+        let baked = timeline().bake(2.0);
+
+        assert_eq!(baked.sample_interval(), 0.5);
+        assert_eq!(baked.samples(), &[0.0, 0.25, 1.0, 2.25, 4.0]);
+    }
+
+    #[test]
+    fn when_sample_rate_does_not_divide_duration_then_increases_rate() {
+        let baked = timeline().bake(1.6);
+
+        assert_eq!(baked.samples().len(), 5);
+        assert_eq!(baked.sample_interval(), 0.5);
+    }
+
+    #[test]
+    fn reports_same_timing_as_original() {
+        let original = QuadraticTimeline {
+            delay: 0.5,
+            duration: 2.5,
+            repeat: Repeat::Times(1),
+        };
+        let baked = original.bake(10.0);
+
+        assert_eq!(baked.delay(), 0.5);
+        assert_eq!(baked.duration(), 2.5);
+        assert_eq!(baked.cycle_duration(), Some(2.0));
+        assert_eq!(baked.repeat(), Repeat::Times(1));
+    }
+
+    #[test]
+    fn interpolates_linearly_between_samples() {
+        let baked = timeline().bake(2.0);
+
+        assert_eq!(baked_value(&baked, 0.5), 0.25);
+        assert_eq!(baked_value(&baked, 0.75), 0.625);
+        assert_eq!(baked_value(&baked, 2.0), 4.0);
+        assert_eq!(baked_value(&baked, 5.0), 4.0);
+        assert_eq!(baked_value(&baked, -1.0), 0.0);
+    }
+
+    #[test]
+    fn max_error_is_largest_difference_at_midpoints() {
+        let original = timeline();
+        let coarse = original.bake(2.0);
+        let fine = original.bake(20.0);
+
+        // For a quadratic, linear interpolation is off by exactly (interval / 2)^2 at every
+        // midpoint.
+        let distance = |a: &f32, b: &f32| (a - b).abs();
+        assert_relative_eq!(coarse.max_error(&original, distance), 0.0625);
+        assert_relative_eq!(
+            fine.max_error(&original, distance),
+            0.000625,
+            epsilon = 0.00001
+        );
+    }
+
+    #[test]
+    fn when_repeating_infinitely_then_bakes_first_cycle() {
+        let original = QuadraticTimeline {
+            delay: 0.5,
+            duration: 1.5,
+            repeat: Repeat::Infinite,
+        };
+        let baked = original.bake(4.0);
+
+        assert_eq!(baked.duration(), 1.5);
+        assert_eq!(baked.repeat(), Repeat::None);
+        assert_eq!(baked_value(&baked, 1.5), 1.0);
+    }
+
+    #[test]
+    fn reports_markers_of_original() {
+        let baked = timeline().bake(2.0);
+
+        assert_eq!(baked.markers_between(0.0, 0.5), &[]);
+        assert_eq!(baked.markers_between(0.5, 2.0).len(), 1);
+        assert_eq!(baked.markers_between(2.0, 0.0)[0].name, "one");
+    }
+
+    #[test]
+    fn when_more_markers_than_reported_by_one_query_then_keeps_all_markers() {
+        let original = MarkerTimeline {
+            timescale: TimeScale::new(1.0, 0.0, Repeat::Times(299), false)
+                .with_markers(vec![Marker::at("start", 0.0), Marker::at("end", 1.0)]),
+        };
+        let baked = original.bake(1.0);
+        let crossings = baked.markers_between(f32::NEG_INFINITY, 300.0);
+
+        assert_eq!(crossings.len(), 600);
+        assert_eq!(crossings[0].name, "start");
+        assert_eq!(crossings[599].name, "end");
+    }
+
+    #[test]
+    fn cycle_sample_times_are_evenly_spaced_from_start_to_end() {
+        assert_eq!(cycle_sample_times(2.0, 2.0), &[0.0, 0.25, 0.5, 0.75, 1.0]);
+        assert_eq!(cycle_sample_times(1.0, 1.6), &[0.0, 0.5, 1.0]);
+        assert_eq!(cycle_sample_times(0.0, 10.0), &[0.0, 1.0]);
+        assert_eq!(cycle_sample_times(f32::INFINITY, 10.0), &[0.0, 1.0]);
+    }
+
+    #[test]
+    fn to_csv_writes_time_and_columns() {
+        let baked = timeline().bake(1.0);
+
+        let csv = baked.to_csv(&["value", "double"], |value| vec![*value, value * 2.0]);
+
+        assert_eq!(csv, "time,value,double\n0,0,0\n1,1,2\n2,4,8\n");
+    }
+}
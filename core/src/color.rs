@@ -0,0 +1,550 @@
+//! Color types that can be animated in a choice of color spaces.
+//!
+//! Each type interpolates in its own color space, so the space used for blending is chosen by the
+//! type of the animated field. For example, a field of type [`Oklch`] blends through perceptually
+//! uniform hues, while a field of type [`Rgba`] blends in linear light. All types can be converted
+//! to and from each other with [`From`], so values can be specified and rendered in whichever
+//! space is most convenient:
+//!
+//! ```
+//! use mina_core::color::{Oklch, Rgba};
+//! use mina_core::interpolation::Lerp;
+//!
+//! let red: Oklch = Rgba::new(1.0, 0.0, 0.0, 1.0).into();
+//! let blue: Oklch = Rgba::new(0.0, 0.0, 1.0, 1.0).into();
+//! let purple = Rgba::from(red.lerp(&blue, 0.5));
+//! ```
+//!
+//! All types use straight (non-premultiplied) alpha for storage, but interpolate with premultiplied
+//! alpha, so that blending toward a fully transparent color does not shift the visible color. Polar
+//! types ([`Hsla`] and [`Oklch`]) interpolate hue along the shorter arc, and when one of the colors
+//! is achromatic (has no saturation or chroma, or a negligible amount), its hue is ignored in favor
+//! of the other color's.
+
+use crate::interpolation::Lerp;
+
+/// A color in the sRGB color space, with gamma-encoded components and straight alpha.
+///
+/// Components are normally in the range `0.0..=1.0`. Interpolation converts to linear light and
+/// premultiplies alpha, which avoids the dark bands produced by blending gamma-encoded values.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Rgba {
+    /// Red component.
+    pub r: f32,
+    /// Green component.
+    pub g: f32,
+    /// Blue component.
+    pub b: f32,
+    /// Alpha (opacity), where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub a: f32,
+}
+
+impl Rgba {
+    /// Creates a new [`Rgba`] color from its components.
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+
+    /// Creates a new [`Rgba`] color from 8-bit components, such as those of a CSS hex color.
+    pub fn from_rgba8(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self::new(
+            r as f32 / 255.0,
+            g as f32 / 255.0,
+            b as f32 / 255.0,
+            a as f32 / 255.0,
+        )
+    }
+
+    /// Converts the color to 8-bit components, rounding and clamping out-of-range values.
+    pub fn to_rgba8(&self) -> [u8; 4] {
+        [self.r, self.g, self.b, self.a].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8)
+    }
+}
+
+impl Lerp for Rgba {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        LinearRgba::from(*self)
+            .lerp(&LinearRgba::from(*y1), x)
+            .into()
+    }
+}
+
+/// A color in the linear sRGB color space, i.e. with the same primaries as [`Rgba`] but without
+/// gamma encoding, and with straight alpha.
+///
+/// Interpolates with premultiplied alpha. This is the same blending behavior as [`Rgba`], but
+/// without the cost of gamma conversion on every frame.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct LinearRgba {
+    /// Red component.
+    pub r: f32,
+    /// Green component.
+    pub g: f32,
+    /// Blue component.
+    pub b: f32,
+    /// Alpha (opacity), where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub a: f32,
+}
+
+impl LinearRgba {
+    /// Creates a new [`LinearRgba`] color from its components.
+    pub fn new(r: f32, g: f32, b: f32, a: f32) -> Self {
+        Self { r, g, b, a }
+    }
+}
+
+impl Lerp for LinearRgba {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        let ([r, g, b], a) = lerp_premultiplied(
+            [self.r, self.g, self.b],
+            self.a,
+            [y1.r, y1.g, y1.b],
+            y1.a,
+            x,
+        );
+        Self { r, g, b, a }
+    }
+}
+
+/// A color in the HSL color space, based on sRGB, with straight alpha.
+///
+/// Hue is in degrees, normally in the range `0.0..360.0`; saturation and lightness are normally in
+/// the range `0.0..=1.0`. Interpolates hue along the shorter arc, and the other components with
+/// premultiplied alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Hsla {
+    /// Hue angle, in degrees.
+    pub h: f32,
+    /// Saturation.
+    pub s: f32,
+    /// Lightness.
+    pub l: f32,
+    /// Alpha (opacity), where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub a: f32,
+}
+
+impl Hsla {
+    /// Creates a new [`Hsla`] color from its components.
+    pub fn new(h: f32, s: f32, l: f32, a: f32) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+impl Lerp for Hsla {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        let h = lerp_hue(
+            self.h,
+            self.s < ACHROMATIC_SATURATION,
+            y1.h,
+            y1.s < ACHROMATIC_SATURATION,
+            x,
+        );
+        let ([s, l], a) = lerp_premultiplied([self.s, self.l], self.a, [y1.s, y1.l], y1.a, x);
+        Self { h, s, l, a }
+    }
+}
+
+/// A color in the [Oklab](https://bottosson.github.io/posts/oklab/) color space, with straight
+/// alpha.
+///
+/// Oklab is perceptually uniform, so interpolation produces evenly-spaced changes in lightness and
+/// avoids the desaturated midpoints of linear RGB blending. Interpolates with premultiplied alpha.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklab {
+    /// Perceived lightness, normally in the range `0.0..=1.0`.
+    pub l: f32,
+    /// Green-red axis.
+    pub a: f32,
+    /// Blue-yellow axis.
+    pub b: f32,
+    /// Alpha (opacity), where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub alpha: f32,
+}
+
+impl Oklab {
+    /// Creates a new [`Oklab`] color from its components.
+    pub fn new(l: f32, a: f32, b: f32, alpha: f32) -> Self {
+        Self { l, a, b, alpha }
+    }
+}
+
+impl Lerp for Oklab {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        let ([l, a, b], alpha) = lerp_premultiplied(
+            [self.l, self.a, self.b],
+            self.alpha,
+            [y1.l, y1.a, y1.b],
+            y1.alpha,
+            x,
+        );
+        Self { l, a, b, alpha }
+    }
+}
+
+/// A color in the Oklch color space, i.e. the polar form of [`Oklab`], with straight alpha.
+///
+/// Hue is in degrees, normally in the range `0.0..360.0`. Interpolates hue along the shorter arc,
+/// and the other components with premultiplied alpha, which keeps chroma steady when blending
+/// between two saturated colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Oklch {
+    /// Perceived lightness, normally in the range `0.0..=1.0`.
+    pub l: f32,
+    /// Chroma.
+    pub c: f32,
+    /// Hue angle, in degrees.
+    pub h: f32,
+    /// Alpha (opacity), where `0.0` is fully transparent and `1.0` is fully opaque.
+    pub alpha: f32,
+}
+
+impl Oklch {
+    /// Creates a new [`Oklch`] color from its components.
+    pub fn new(l: f32, c: f32, h: f32, alpha: f32) -> Self {
+        Self { l, c, h, alpha }
+    }
+}
+
+impl Lerp for Oklch {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        let h = lerp_hue(
+            self.h,
+            self.c < ACHROMATIC_CHROMA,
+            y1.h,
+            y1.c < ACHROMATIC_CHROMA,
+            x,
+        );
+        let ([l, c], alpha) =
+            lerp_premultiplied([self.l, self.c], self.alpha, [y1.l, y1.c], y1.alpha, x);
+        Self { l, c, h, alpha }
+    }
+}
+
+impl From<Rgba> for LinearRgba {
+    fn from(color: Rgba) -> Self {
+        Self::new(
+            srgb_to_linear(color.r),
+            srgb_to_linear(color.g),
+            srgb_to_linear(color.b),
+            color.a,
+        )
+    }
+}
+
+impl From<LinearRgba> for Rgba {
+    fn from(color: LinearRgba) -> Self {
+        Self::new(
+            linear_to_srgb(color.r),
+            linear_to_srgb(color.g),
+            linear_to_srgb(color.b),
+            color.a,
+        )
+    }
+}
+
+impl From<Rgba> for Hsla {
+    fn from(color: Rgba) -> Self {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+        let chroma = max - min;
+        let l = (max + min) / 2.0;
+        if chroma == 0.0 {
+            return Self::new(0.0, 0.0, l, color.a);
+        }
+        let s = chroma / (1.0 - (2.0 * l - 1.0).abs());
+        let h = if max == color.r {
+            ((color.g - color.b) / chroma).rem_euclid(6.0)
+        } else if max == color.g {
+            (color.b - color.r) / chroma + 2.0
+        } else {
+            (color.r - color.g) / chroma + 4.0
+        };
+        Self::new(h * 60.0, s, l, color.a)
+    }
+}
+
+impl From<Hsla> for Rgba {
+    fn from(color: Hsla) -> Self {
+        let a = color.s * color.l.min(1.0 - color.l);
+        let channel = |n: f32| {
+            let k = (n + color.h / 30.0).rem_euclid(12.0);
+            color.l - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+        };
+        Self::new(channel(0.0), channel(8.0), channel(4.0), color.a)
+    }
+}
+
+#[allow(clippy::excessive_precision)] // Constants are kept exactly as published.
+impl From<LinearRgba> for Oklab {
+    fn from(color: LinearRgba) -> Self {
+        let LinearRgba { r, g, b, a } = color;
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Self::new(
+            0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+            a,
+        )
+    }
+}
+
+#[allow(clippy::excessive_precision)] // Constants are kept exactly as published.
+impl From<Oklab> for LinearRgba {
+    fn from(color: Oklab) -> Self {
+        let Oklab { l, a, b, alpha } = color;
+        let l_ = (l + 0.3963377774 * a + 0.2158037573 * b).powi(3);
+        let m_ = (l - 0.1055613458 * a - 0.0638541728 * b).powi(3);
+        let s_ = (l - 0.0894841775 * a - 1.2914855480 * b).powi(3);
+        Self::new(
+            4.0767416621 * l_ - 3.3077115913 * m_ + 0.2309699292 * s_,
+            -1.2684380046 * l_ + 2.6097574011 * m_ - 0.3413193965 * s_,
+            -0.0041960863 * l_ - 0.7034186147 * m_ + 1.7076147010 * s_,
+            alpha,
+        )
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(color: Oklab) -> Self {
+        let c = color.a.hypot(color.b);
+        let h = color.b.atan2(color.a).to_degrees().rem_euclid(360.0);
+        Self::new(color.l, c, h, color.alpha)
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(color: Oklch) -> Self {
+        let (sin, cos) = color.h.to_radians().sin_cos();
+        Self::new(color.l, color.c * cos, color.c * sin, color.alpha)
+    }
+}
+
+macro_rules! impl_from_via {
+    ($($from:ty => $via:ty => $to:ty),* $(,)?) => {
+        $(
+            impl From<$from> for $to {
+                fn from(color: $from) -> Self {
+                    Self::from(<$via>::from(color))
+                }
+            }
+        )*
+    }
+}
+
+impl_from_via!(
+    Rgba => LinearRgba => Oklab,
+    Oklab => LinearRgba => Rgba,
+    Rgba => Oklab => Oklch,
+    Oklch => Oklab => Rgba,
+    LinearRgba => Oklab => Oklch,
+    Oklch => Oklab => LinearRgba,
+    Hsla => Rgba => LinearRgba,
+    LinearRgba => Rgba => Hsla,
+    Hsla => Rgba => Oklab,
+    Oklab => Rgba => Hsla,
+    Hsla => Rgba => Oklch,
+    Oklch => Rgba => Hsla,
+);
+
+fn srgb_to_linear(c: f32) -> f32 {
+    let magnitude = c.abs();
+    let linear = if magnitude <= 0.04045 {
+        magnitude / 12.92
+    } else {
+        ((magnitude + 0.055) / 1.055).powf(2.4)
+    };
+    linear.copysign(c)
+}
+
+fn linear_to_srgb(c: f32) -> f32 {
+    let magnitude = c.abs();
+    let encoded = if magnitude <= 0.0031308 {
+        magnitude * 12.92
+    } else {
+        1.055 * magnitude.powf(1.0 / 2.4) - 0.055
+    };
+    encoded.copysign(c)
+}
+
+// Chroma (of an Oklch color) and saturation (of an Hsla color) below which the hue is considered
+// meaningless. Grays converted through Oklab are never exactly achromatic due to rounding errors,
+// and their hue is then arbitrary.
+const ACHROMATIC_CHROMA: f32 = 1e-4;
+const ACHROMATIC_SATURATION: f32 = 1e-4;
+
+// Interpolates hue angles (in degrees) along the shorter arc. The hue of an achromatic color is
+// meaningless, so the other color's hue is used for the entire interpolation.
+fn lerp_hue(h0: f32, is_achromatic0: bool, h1: f32, is_achromatic1: bool, x: f32) -> f32 {
+    let (h0, h1) = match (is_achromatic0, is_achromatic1) {
+        (true, false) => (h1, h1),
+        (false, true) => (h0, h0),
+        _ => (h0, h1),
+    };
+    let mut delta = (h1 - h0).rem_euclid(360.0);
+    if delta > 180.0 {
+        delta -= 360.0;
+    }
+    (h0 + delta * x).rem_euclid(360.0)
+}
+
+// Interpolates color components with premultiplied alpha, returning straight-alpha results.
+//
+// Un-premultiplying the interpolated components is the same as interpolating the straight
+// components at a position weighted toward the more opaque color. When the interpolated alpha is
+// zero, the color is invisible and there is nothing to un-premultiply, so the components are
+// interpolated directly instead. As in CSS, alpha is limited to `0.0..=1.0`; when an easing
+// overshoots, the weighted position is also limited to the overshoot of `x`, since it would
+// otherwise grow without bound as the alpha approaches zero.
+fn lerp_premultiplied<const N: usize>(
+    components0: [f32; N],
+    alpha0: f32,
+    components1: [f32; N],
+    alpha1: f32,
+    x: f32,
+) -> ([f32; N], f32) {
+    let alpha0 = alpha0.clamp(0.0, 1.0);
+    let alpha1 = alpha1.clamp(0.0, 1.0);
+    let alpha = alpha0.lerp(&alpha1, x);
+    let components_x = if alpha <= 0.0 {
+        x
+    } else {
+        (x * alpha1 / alpha).clamp(x.min(0.0), x.max(1.0))
+    };
+    let components = std::array::from_fn(|i| components0[i].lerp(&components1[i], components_x));
+    (components, alpha.clamp(0.0, 1.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn when_rgba_interpolated_then_blends_in_linear_light() {
+        let red = Rgba::new(1.0, 0.0, 0.0, 1.0);
+        let green = Rgba::new(0.0, 1.0, 0.0, 1.0);
+
+        let mid = red.lerp(&green, 0.5);
+
+        assert_relative_eq!(mid.r, 0.7353569, epsilon = 1e-5);
+        assert_relative_eq!(mid.g, 0.7353569, epsilon = 1e-5);
+        assert_relative_eq!(mid.b, 0.0);
+        assert_relative_eq!(mid.a, 1.0);
+    }
+
+    #[test]
+    fn when_interpolating_toward_transparent_then_color_does_not_shift() {
+        let red = Rgba::new(1.0, 0.0, 0.0, 1.0);
+        let transparent_blue = Rgba::new(0.0, 0.0, 1.0, 0.0);
+
+        let mid = red.lerp(&transparent_blue, 0.5);
+
+        assert_relative_eq!(mid.r, 1.0, epsilon = 1e-5);
+        assert_relative_eq!(mid.b, 0.0);
+        assert_relative_eq!(mid.a, 0.5);
+    }
+
+    #[test]
+    fn when_both_transparent_then_interpolates_components_directly() {
+        let a = LinearRgba::new(1.0, 0.0, 0.0, 0.0);
+        let b = LinearRgba::new(0.0, 1.0, 0.0, 0.0);
+
+        assert_eq!(a.lerp(&b, 0.25), LinearRgba::new(0.75, 0.25, 0.0, 0.0));
+    }
+
+    #[test]
+    fn when_easing_overshoots_then_alpha_and_components_stay_bounded() {
+        let red = LinearRgba::new(1.0, 0.0, 0.0, 1.0);
+        let faint_blue = LinearRgba::new(0.0, 0.0, 1.0, 0.1);
+
+        for x in [-0.5, 1.1, 1.11, 1.5] {
+            let color = red.lerp(&faint_blue, x);
+
+            assert!(
+                (0.0..=1.0).contains(&color.a),
+                "alpha at {x} was {}",
+                color.a
+            );
+            for component in [color.r, color.g, color.b] {
+                assert!(component.abs() <= 1.5, "component at {x} was {component}");
+            }
+        }
+        assert_eq!(red.lerp(&faint_blue, -0.5).a, 1.0);
+        assert_eq!(red.lerp(&faint_blue, 1.5).a, 0.0);
+    }
+
+    #[test]
+    fn when_hue_interpolated_then_takes_shorter_arc() {
+        let a = Hsla::new(350.0, 1.0, 0.5, 1.0);
+        let b = Hsla::new(30.0, 1.0, 0.5, 1.0);
+
+        assert_relative_eq!(a.lerp(&b, 0.25).h, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(a.lerp(&b, 0.75).h, 20.0, epsilon = 1e-4);
+        assert_relative_eq!(b.lerp(&a, 0.5).h, 10.0, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn when_one_color_achromatic_then_uses_hue_of_other() {
+        let gray = Oklch::new(0.5, 0.0, 0.0, 1.0);
+        let blue = Oklch::new(0.5, 0.2, 260.0, 1.0);
+
+        let mid = gray.lerp(&blue, 0.5);
+
+        assert_relative_eq!(mid.h, 260.0, epsilon = 1e-4);
+        assert_relative_eq!(mid.c, 0.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn when_gray_converted_to_oklch_then_uses_hue_of_other() {
+        let white = Oklch::from(Rgba::new(1.0, 1.0, 1.0, 1.0));
+        let gray = Oklch::from(Rgba::new(0.2, 0.2, 0.2, 1.0));
+        let blue = Oklch::from(Rgba::new(0.0, 0.0, 1.0, 1.0));
+
+        assert_relative_eq!(white.lerp(&blue, 0.5).h, blue.h, epsilon = 1e-4);
+        assert_relative_eq!(gray.lerp(&blue, 0.5).h, blue.h, epsilon = 1e-4);
+        assert_relative_eq!(blue.lerp(&white, 0.75).h, blue.h, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn when_converted_to_oklab_then_matches_reference_values() {
+        let white = Oklab::from(Rgba::new(1.0, 1.0, 1.0, 1.0));
+        let red = Oklab::from(Rgba::new(1.0, 0.0, 0.0, 1.0));
+
+        assert_relative_eq!(white.l, 1.0, epsilon = 1e-4);
+        assert_relative_eq!(white.a, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(white.b, 0.0, epsilon = 1e-4);
+        assert_relative_eq!(red.l, 0.627955, epsilon = 1e-4);
+        assert_relative_eq!(red.a, 0.224863, epsilon = 1e-4);
+        assert_relative_eq!(red.b, 0.125846, epsilon = 1e-4);
+    }
+
+    #[test]
+    fn when_converted_between_spaces_then_round_trips() {
+        let color = Rgba::new(0.2, 0.6, 0.9, 0.8);
+
+        for round_trip in [
+            Rgba::from(LinearRgba::from(color)),
+            Rgba::from(Hsla::from(color)),
+            Rgba::from(Oklab::from(color)),
+            Rgba::from(Oklch::from(color)),
+        ] {
+            assert_relative_eq!(round_trip.r, color.r, epsilon = 1e-4);
+            assert_relative_eq!(round_trip.g, color.g, epsilon = 1e-4);
+            assert_relative_eq!(round_trip.b, color.b, epsilon = 1e-4);
+            assert_relative_eq!(round_trip.a, color.a);
+        }
+    }
+
+    #[test]
+    fn when_converted_to_rgba8_then_rounds_and_clamps() {
+        let color = Rgba::new(1.2, 0.5, -0.1, 1.0);
+
+        assert_eq!(color.to_rgba8(), [255, 128, 0, 255]);
+        assert_eq!(
+            Rgba::from_rgba8(255, 0, 51, 255),
+            Rgba::new(1.0, 0.0, 0.2, 1.0)
+        );
+    }
+}
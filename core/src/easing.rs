@@ -3,8 +3,13 @@
 
 use dyn_clone::{clone_trait_object, DynClone};
 use lazy_static::lazy_static;
-use lyon_geom::{CubicBezierSegment, Point};
+use std::f32::consts::TAU;
 use std::fmt::Debug;
+use std::str::FromStr;
+
+mod parse;
+
+pub use parse::{ParseEasingError, ParseEasingErrorKind};
 
 /// Provides an easing function, AKA animation timing function, for non-linear interpolation of
 /// values, typically along some curve.
@@ -20,6 +25,35 @@ pub trait EasingFunction: Debug + DynClone + Send + Sync {
     /// Expects `x` to be normalized (from 0 to 1) and returns a normalized y-value which is
     /// typically between 0 and 1, but may be outside that range (e.g. [Easing::OutBack]).
     fn calc(&self, x: f32) -> f32;
+
+    /// Computes the slope of the curve, i.e. the first derivative of `y` with respect to `x`, at a
+    /// given `x` position.
+    ///
+    /// This determines how fast an animated property is changing at any point, and is used to
+    /// compute velocities; see
+    /// [`Timeline::update_velocity`](crate::timeline::Timeline::update_velocity).
+    ///
+    /// The default implementation returns [`None`], meaning that the derivative is not known
+    /// analytically and should be approximated numerically, e.g. with [`Easing::slope`].
+    fn derivative(&self, _x: f32) -> Option<f32> {
+        None
+    }
+
+    /// Computes the additional `y` offset caused by an initial velocity of `1.0` units per second,
+    /// for easings that model physical motion, such as a
+    /// [`SpringEasing`](crate::spring::SpringEasing).
+    ///
+    /// This is used to preserve momentum when an animation is interrupted and a new one starts from
+    /// its current values; see
+    /// [`Timeline::start_with_velocity`](crate::timeline::Timeline::start_with_velocity). Unlike
+    /// [`calc`](Self::calc), the result is in seconds rather than normalized units, as velocity is
+    /// measured in real time.
+    ///
+    /// The default implementation returns [`None`], meaning that the easing ignores any initial
+    /// velocity.
+    fn velocity_response(&self, _x: f32) -> Option<f32> {
+        None
+    }
 }
 
 clone_trait_object!(EasingFunction);
@@ -29,9 +63,23 @@ clone_trait_object!(EasingFunction);
 /// Available easings include:
 /// - CSS standard: `Ease`, `In`, `Out`, `InOut` corresponding to `ease`, `ease-in`, `ease-out` and
 ///   `ease-in-out`
-/// - Common easings that can be implemented with a cubic bezier function, i.e. the majority of
-///   functions listed on <https://easings.net> except for the "elastic" and "bounce" types.
+/// - All functions listed on <https://easings.net>. The majority of these are implemented with a
+///   cubic bezier function; the "elastic" and "bounce" types use [ElasticEasing] and
+///   [BounceEasing], which can also be used directly to customize their parameters.
 /// - User-defined functions via [`Custom`](Easing::Custom).
+///
+/// Any CSS [`<easing-function>`](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function)
+/// can also be parsed from a string, including `cubic-bezier()`, `steps()` and `linear()`:
+///
+/// ```
+/// use mina_core::easing::{Easing, EasingFunction};
+///
+/// let easing: Easing = "cubic-bezier(0.25, 0.1, 0.25, 1)".parse().unwrap();
+/// assert!((easing.calc(0.5) - Easing::Ease.calc(0.5)).abs() < 0.0001);
+///
+/// let error = "steps(4, middle)".parse::<Easing>().unwrap_err();
+/// assert_eq!(error.position(), 9);
+/// ```
 #[derive(Clone, Debug, Default)]
 pub enum Easing {
     /// Linear easing, i.e. no easing or curve, only straight-line interpolation.
@@ -169,10 +217,152 @@ pub enum Easing {
     ///
     /// See: <https://easings.net/#easeInOutBack>
     InOutBack,
+    /// A curve that starts with small oscillations of increasing amplitude, like a stretched
+    /// elastic band being released, before snapping to the terminal value.
+    ///
+    /// See: <https://easings.net/#easeInElastic>
+    InElastic,
+    /// A curve that accelerates quickly past the terminal value and then oscillates around it with
+    /// decreasing amplitude, like an elastic band after being released.
+    ///
+    /// See: <https://easings.net/#easeOutElastic>
+    OutElastic,
+    /// Combination of [InElastic](Easing::InElastic) and [OutElastic](Easing::OutElastic), which
+    /// oscillates at both the beginning and end of the animation.
+    ///
+    /// See: <https://easings.net/#easeInOutElastic>
+    InOutElastic,
+    /// A curve that bounces away from the start value a few times with increasing height, and then
+    /// accelerates toward the terminal value. The reverse of [OutBounce](Easing::OutBounce).
+    ///
+    /// See: <https://easings.net/#easeInBounce>
+    InBounce,
+    /// A curve that accelerates toward the terminal value, like a falling ball, and then bounces
+    /// back a few times with decreasing height before settling.
+    ///
+    /// See: <https://easings.net/#easeOutBounce>
+    OutBounce,
+    /// Combination of [InBounce](Easing::InBounce) and [OutBounce](Easing::OutBounce), which
+    /// bounces at both the beginning and end of the animation.
+    ///
+    /// See: <https://easings.net/#easeInOutBounce>
+    InOutBounce,
+    /// Stepped (discontinuous) timing equivalent to CSS
+    /// [`steps()`](https://developer.mozilla.org/en-US/docs/Web/CSS/easing-function#steps), which
+    /// divides the animation into a number of equal intervals and jumps from one value to the next
+    /// at the start or end of each interval, as specified by the [StepPosition].
+    ///
+    /// See [StepsEasing] for details.
+    Steps(u32, StepPosition),
     /// User-defined easing, such as an ad-hoc [CubicBezierEasing].
     Custom(Box<dyn EasingFunction>),
 }
 
+impl Easing {
+    /// Creates a [`Custom`](Easing::Custom) easing from a closure that maps normalized `x` values
+    /// to `y` values, without having to define a separate [EasingFunction] type.
+    ///
+    /// The closure follows the same rules as [`EasingFunction::calc`]. To give the easing a name
+    /// in debug output, use [`FnEasing::with_label`] and convert it with `into()`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mina_core::easing::{Easing, EasingFunction};
+    ///
+    /// let easing = Easing::from_fn(|x| x * x * x);
+    /// assert_eq!(easing.calc(0.5), 0.125);
+    /// ```
+    pub fn from_fn<F>(f: F) -> Self
+    where
+        F: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+    {
+        FnEasing::new(f).into()
+    }
+
+    /// Creates an easing that plays this curve backward, i.e. `1 - f(1 - x)`.
+    ///
+    /// This turns an "in" easing into the corresponding "out" easing and vice versa; for example,
+    /// `Easing::OutBack.reversed()` overshoots at the start instead of the end.
+    pub fn reversed(self) -> Easing {
+        Easing::Custom(Box::new(ReversedEasing(self)))
+    }
+
+    /// Creates an "in-out" easing from this curve, which is assumed to be an "in" easing.
+    ///
+    /// The first half of the result is this curve compressed into half the duration and half the
+    /// output range, and the second half is the [reversed](Self::reversed) curve, so that e.g.
+    /// `Easing::InQuad.mirrored()` closely matches [Easing::InOutQuad].
+    pub fn mirrored(self) -> Easing {
+        Easing::Custom(Box::new(MirroredEasing(self)))
+    }
+
+    /// Creates an easing that uses this curve until `split`, then the `other` curve for the rest of
+    /// the duration.
+    ///
+    /// Both curves are scaled so that the output is at `split` when the input is at `split`; for
+    /// example, `Easing::InQuad.then(Easing::OutElastic, 0.5)` accelerates to the halfway point and
+    /// then springs into the end value. The `split` is clamped between 0 and 1, and a NaN `split`
+    /// is treated as 0, i.e. only the `other` curve is used.
+    pub fn then(self, other: Easing, split: f32) -> Easing {
+        let split = if split.is_nan() {
+            0.0
+        } else {
+            split.clamp(0.0, 1.0)
+        };
+        Easing::Custom(Box::new(ChainedEasing {
+            first: self,
+            second: other,
+            split,
+        }))
+    }
+
+    /// Creates an easing that is a weighted average of this curve and the `other` curve.
+    ///
+    /// A `weight` of `0` evaluates to this curve, `1` evaluates to the `other` curve, and values in
+    /// between combine the two proportionally.
+    pub fn blend(self, other: Easing, weight: f32) -> Easing {
+        Easing::Custom(Box::new(BlendedEasing {
+            first: self,
+            second: other,
+            weight,
+        }))
+    }
+
+    /// Computes the slope of the curve at a given `x` position.
+    ///
+    /// Uses the analytic [`derivative`](EasingFunction::derivative) if the easing has one, and
+    /// otherwise approximates it from nearby values of [`calc`](EasingFunction::calc).
+    pub fn slope(&self, x: f32) -> f32 {
+        self.derivative(x)
+            .unwrap_or_else(|| numeric_derivative(|x| self.calc(x), x))
+    }
+
+    /// Creates an easing that limits the output of this curve to the range `0..=1`.
+    ///
+    /// This removes the overshoot of curves like [Easing::OutBack] or [Easing::OutElastic], which
+    /// is useful for properties that must not leave their start-to-end range.
+    pub fn clamped(self) -> Easing {
+        Easing::Custom(Box::new(ClampedEasing(self)))
+    }
+}
+
+impl FromStr for Easing {
+    type Err = ParseEasingError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse::parse_easing(s)
+    }
+}
+
+impl TryFrom<&str> for Easing {
+    type Error = ParseEasingError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
 impl EasingFunction for Easing {
     fn calc(&self, x: f32) -> f32 {
         match self {
@@ -205,9 +395,65 @@ impl EasingFunction for Easing {
             Self::InBack => EASE_IN_BACK.calc(x),
             Self::OutBack => EASE_OUT_BACK.calc(x),
             Self::InOutBack => EASE_IN_OUT_BACK.calc(x),
+            Self::InElastic => EASE_IN_ELASTIC.calc(x),
+            Self::OutElastic => EASE_OUT_ELASTIC.calc(x),
+            Self::InOutElastic => EASE_IN_OUT_ELASTIC.calc(x),
+            Self::InBounce => EASE_IN_BOUNCE.calc(x),
+            Self::OutBounce => EASE_OUT_BOUNCE.calc(x),
+            Self::InOutBounce => EASE_IN_OUT_BOUNCE.calc(x),
+            Self::Steps(steps, position) => StepsEasing::new(*steps, *position).calc(x),
             Self::Custom(custom) => custom.calc(x),
         }
     }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        match self {
+            Self::Linear => EASE_LINEAR.derivative(x),
+            Self::Ease => EASE_WEB.derivative(x),
+            Self::In => EASE_IN.derivative(x),
+            Self::Out => EASE_OUT.derivative(x),
+            Self::InOut => EASE_IN_OUT.derivative(x),
+            Self::InSine => EASE_IN_SINE.derivative(x),
+            Self::OutSine => EASE_OUT_SINE.derivative(x),
+            Self::InOutSine => EASE_IN_OUT_SINE.derivative(x),
+            Self::InQuad => EASE_IN_QUAD.derivative(x),
+            Self::OutQuad => EASE_OUT_QUAD.derivative(x),
+            Self::InOutQuad => EASE_IN_OUT_QUAD.derivative(x),
+            Self::InCubic => EASE_IN_CUBIC.derivative(x),
+            Self::OutCubic => EASE_OUT_CUBIC.derivative(x),
+            Self::InOutCubic => EASE_IN_OUT_CUBIC.derivative(x),
+            Self::InQuart => EASE_IN_QUART.derivative(x),
+            Self::OutQuart => EASE_OUT_QUART.derivative(x),
+            Self::InOutQuart => EASE_IN_OUT_QUART.derivative(x),
+            Self::InQuint => EASE_IN_QUINT.derivative(x),
+            Self::OutQuint => EASE_OUT_QUINT.derivative(x),
+            Self::InOutQuint => EASE_IN_OUT_QUINT.derivative(x),
+            Self::InExpo => EASE_IN_EXPO.derivative(x),
+            Self::OutExpo => EASE_OUT_EXPO.derivative(x),
+            Self::InOutExpo => EASE_IN_OUT_EXPO.derivative(x),
+            Self::InCirc => EASE_IN_CIRC.derivative(x),
+            Self::OutCirc => EASE_OUT_CIRC.derivative(x),
+            Self::InOutCirc => EASE_IN_OUT_CIRC.derivative(x),
+            Self::InBack => EASE_IN_BACK.derivative(x),
+            Self::OutBack => EASE_OUT_BACK.derivative(x),
+            Self::InOutBack => EASE_IN_OUT_BACK.derivative(x),
+            Self::InElastic => EASE_IN_ELASTIC.derivative(x),
+            Self::OutElastic => EASE_OUT_ELASTIC.derivative(x),
+            Self::InOutElastic => EASE_IN_OUT_ELASTIC.derivative(x),
+            Self::InBounce => EASE_IN_BOUNCE.derivative(x),
+            Self::OutBounce => EASE_OUT_BOUNCE.derivative(x),
+            Self::InOutBounce => EASE_IN_OUT_BOUNCE.derivative(x),
+            Self::Steps(steps, position) => StepsEasing::new(*steps, *position).derivative(x),
+            Self::Custom(custom) => custom.derivative(x),
+        }
+    }
+
+    fn velocity_response(&self, x: f32) -> Option<f32> {
+        match self {
+            Self::Custom(custom) => custom.velocity_response(x),
+            _ => None,
+        }
+    }
 }
 
 lazy_static! {
@@ -240,6 +486,263 @@ lazy_static! {
     static ref EASE_IN_BACK: CubicBezierEasing = cubic_bezier(0.36, 0.0, 0.66, -0.56);
     static ref EASE_OUT_BACK: CubicBezierEasing = cubic_bezier(0.34, 1.56, 0.64, 1.0);
     static ref EASE_IN_OUT_BACK: CubicBezierEasing = cubic_bezier(0.68, -0.6, 0.32, 1.6);
+    static ref EASE_IN_ELASTIC: ElasticEasing = ElasticEasing::new(EasingDirection::In);
+    static ref EASE_OUT_ELASTIC: ElasticEasing = ElasticEasing::new(EasingDirection::Out);
+    static ref EASE_IN_OUT_ELASTIC: ElasticEasing = ElasticEasing::new(EasingDirection::InOut);
+    static ref EASE_IN_BOUNCE: BounceEasing = BounceEasing::new(EasingDirection::In);
+    static ref EASE_OUT_BOUNCE: BounceEasing = BounceEasing::new(EasingDirection::Out);
+    static ref EASE_IN_OUT_BOUNCE: BounceEasing = BounceEasing::new(EasingDirection::InOut);
+}
+
+/// Easing function made of straight line segments between a list of points, equivalent to the CSS
+/// [`linear()`](https://www.w3.org/TR/css-easing-2/#the-linear-easing-function) timing function.
+///
+/// Piecewise-linear easings can approximate almost any curve, including springs and bounces, given
+/// enough points. This makes them a convenient format for exchanging curves with design tools,
+/// which often export them as CSS `linear()` functions.
+///
+/// Each [LinearStop] specifies an output (`y`) value and an optional input (`x`) position. As with
+/// CSS, positions that are omitted are filled in automatically:
+///
+/// - If the first stop has no input, it is placed at `0.0`.
+/// - If the last stop has no input, it is placed at `1.0`, or the largest input of any previous
+///   stop if that is greater.
+/// - Any input that is smaller than the input of a preceding stop is raised to match it.
+/// - Any other stops without inputs are spaced evenly between the nearest stops before and after
+///   them that do have inputs.
+///
+/// # Example
+///
+/// ```
+/// use mina_core::easing::{EasingFunction, LinearStop, PiecewiseLinearEasing};
+///
+/// // Equivalent to CSS: linear(0, 0.25 75%, 1)
+/// let easing = PiecewiseLinearEasing::new([
+///     LinearStop::new(0.0),
+///     LinearStop::at(0.25, 0.75),
+///     LinearStop::new(1.0),
+/// ]);
+///
+/// assert_eq!(easing.calc(0.375), 0.125);
+/// assert_eq!(easing.calc(0.875), 0.625);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PiecewiseLinearEasing {
+    points: Vec<(f32, f32)>,
+}
+
+impl PiecewiseLinearEasing {
+    /// Creates a new [PiecewiseLinearEasing] from a list of stops, filling in any missing input
+    /// positions according to the CSS rules.
+    ///
+    /// With no stops, the easing is equivalent to [LinearEasing]. With a single stop, the output is
+    /// always that stop's value.
+    pub fn new(stops: impl IntoIterator<Item = LinearStop>) -> Self {
+        let stops = stops.into_iter().collect::<Vec<_>>();
+        let mut inputs = stops.iter().map(|stop| stop.input).collect::<Vec<_>>();
+        if let Some(first_input) = inputs.first_mut() {
+            first_input.get_or_insert(0.0);
+        }
+        let max_input = inputs.iter().flatten().copied().fold(1.0, f32::max);
+        if let Some(last_input) = inputs.last_mut() {
+            last_input.get_or_insert(max_input);
+        }
+        let mut largest_input = f32::NEG_INFINITY;
+        for input in inputs.iter_mut().flatten() {
+            largest_input = largest_input.max(*input);
+            *input = largest_input;
+        }
+        let mut index = 0;
+        while index < inputs.len() {
+            let Some(start_input) = inputs[index] else {
+                index += 1;
+                continue;
+            };
+            // First and last inputs are always defined at this point, so any run of missing
+            // inputs must be followed by a defined one.
+            let Some((run_end, end_input)) =
+                (index + 1..inputs.len()).find_map(|i| inputs[i].map(|input| (i, input)))
+            else {
+                break;
+            };
+            let segment_count = (run_end - index) as f32;
+            for (offset, input) in inputs[index + 1..run_end].iter_mut().enumerate() {
+                let fraction = (offset + 1) as f32 / segment_count;
+                *input = Some(start_input + (end_input - start_input) * fraction);
+            }
+            index = run_end;
+        }
+        let points = inputs
+            .into_iter()
+            .zip(stops)
+            .map(|(input, stop)| (input.unwrap_or_default(), stop.output))
+            .collect();
+        Self { points }
+    }
+
+    /// Gets the `(input, output)` points of the curve, after filling in any missing inputs.
+    pub fn points(&self) -> &[(f32, f32)] {
+        &self.points
+    }
+}
+
+impl EasingFunction for PiecewiseLinearEasing {
+    fn calc(&self, x: f32) -> f32 {
+        // Implements https://www.w3.org/TR/css-easing-2/#linear-easing-function-output
+        match self.points.as_slice() {
+            [] => return x,
+            [(_, output)] => return *output,
+            _ => {}
+        }
+        let last_index = self.points.len() - 1;
+        let point_a_index = self
+            .points
+            .iter()
+            .rposition(|(input, _)| *input <= x)
+            .unwrap_or(0)
+            .min(last_index - 1);
+        let (input_a, output_a) = self.points[point_a_index];
+        let (input_b, output_b) = self.points[point_a_index + 1];
+        if input_a == input_b {
+            return output_b;
+        }
+        let progress = (x - input_a) / (input_b - input_a);
+        output_a + progress * (output_b - output_a)
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        match self.points.as_slice() {
+            [] => return Some(1.0),
+            [_] => return Some(0.0),
+            _ => {}
+        }
+        let last_index = self.points.len() - 1;
+        let point_a_index = self
+            .points
+            .iter()
+            .rposition(|(input, _)| *input <= x)
+            .unwrap_or(0)
+            .min(last_index - 1);
+        let (input_a, output_a) = self.points[point_a_index];
+        let (input_b, output_b) = self.points[point_a_index + 1];
+        if input_a == input_b {
+            return Some(0.0);
+        }
+        Some((output_b - output_a) / (input_b - input_a))
+    }
+}
+
+/// A single stop in a [PiecewiseLinearEasing], equivalent to one of the comma-separated items in
+/// a CSS `linear()` function.
+///
+/// CSS stops may specify two input percentages, e.g. `0.5 25% 75%`, which is shorthand for two
+/// stops with the same output; these must be specified as two separate [LinearStop]s.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct LinearStop {
+    input: Option<f32>,
+    output: f32,
+}
+
+impl LinearStop {
+    /// Creates a [LinearStop] with the specified `output` value and an automatic input position.
+    pub fn new(output: f32) -> Self {
+        Self {
+            input: None,
+            output,
+        }
+    }
+
+    /// Creates a [LinearStop] with the specified `output` value at a specific `input` position.
+    ///
+    /// Input positions are normalized, i.e. `0.5` corresponds to the CSS `50%`.
+    pub fn at(output: f32, input: f32) -> Self {
+        Self {
+            input: Some(input),
+            output,
+        }
+    }
+}
+
+/// Easing function that implements the CSS
+/// [`steps()`](https://www.w3.org/TR/css-easing-1/#step-easing-functions) timing function.
+///
+/// The output is a staircase made of equally-sized steps, each of which holds a constant value. The
+/// [StepPosition] determines whether the first and last steps are at the start and end values, or
+/// whether the curve jumps immediately from the start value and/or only reaches the end value at
+/// the very end. This is commonly used for sprite animations, or for revealing text one character
+/// at a time.
+#[derive(Clone, Debug)]
+pub struct StepsEasing {
+    position: StepPosition,
+    steps: u32,
+}
+
+impl StepsEasing {
+    /// Creates a new [StepsEasing] with the specified number of `steps` and jump `position`.
+    ///
+    /// CSS requires at least one step, or at least two steps with [StepPosition::JumpNone]; smaller
+    /// values are raised to the minimum.
+    pub fn new(steps: u32, position: StepPosition) -> Self {
+        let min_steps = match position {
+            StepPosition::JumpNone => 2,
+            _ => 1,
+        };
+        Self {
+            position,
+            steps: steps.max(min_steps),
+        }
+    }
+}
+
+impl EasingFunction for StepsEasing {
+    fn calc(&self, x: f32) -> f32 {
+        // Implements https://www.w3.org/TR/css-easing-1/#step-easing-algo. The "before flag" only
+        // applies to the before phase of a CSS animation, which does not exist in Mina.
+        let steps = self.steps as f32;
+        let mut current_step = (x * steps).floor();
+        if matches!(
+            self.position,
+            StepPosition::JumpStart | StepPosition::JumpBoth
+        ) {
+            current_step += 1.0;
+        }
+        let jumps = match self.position {
+            StepPosition::JumpStart | StepPosition::JumpEnd => steps,
+            StepPosition::JumpNone => steps - 1.0,
+            StepPosition::JumpBoth => steps + 1.0,
+        };
+        if x >= 0.0 && current_step < 0.0 {
+            current_step = 0.0;
+        }
+        if x <= 1.0 && current_step > jumps {
+            current_step = jumps;
+        }
+        current_step / jumps
+    }
+
+    fn derivative(&self, _x: f32) -> Option<f32> {
+        // The jumps themselves are instantaneous, so the curve is flat everywhere else.
+        Some(0.0)
+    }
+}
+
+/// Specifies when the jumps occur in a [StepsEasing], equivalent to the CSS
+/// [`<step-position>`](https://www.w3.org/TR/css-easing-1/#step-position).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StepPosition {
+    /// The first jump happens when the animation begins, so the start value is never shown. Same
+    /// as the CSS `jump-start` or `start` keywords.
+    JumpStart,
+    /// The last jump happens when the animation ends, so the end value is only reached at the very
+    /// end. Same as the CSS `jump-end` or `end` keywords, and the default position.
+    #[default]
+    JumpEnd,
+    /// There is no jump at either end; the start and end values are each held for one step. Same
+    /// as the CSS `jump-none` keyword.
+    JumpNone,
+    /// There are jumps at both ends, adding an extra step so that neither the start nor end value
+    /// is held. Same as the CSS `jump-both` keyword.
+    JumpBoth,
 }
 
 /// Linear easing which returns the `x` value as the `y` result. Has the same behavior as
@@ -251,6 +754,10 @@ impl EasingFunction for LinearEasing {
     fn calc(&self, x: f32) -> f32 {
         x
     }
+
+    fn derivative(&self, _x: f32) -> Option<f32> {
+        Some(1.0)
+    }
 }
 
 /// Easing function defined by a cubic bezier curve with the start and end points fixed at `(0, 0)`
@@ -258,33 +765,1093 @@ impl EasingFunction for LinearEasing {
 ///
 /// Most standard easing functions use `CubicBezierEasing`. Instances of this may be created and
 /// used in [Easing::Custom] in cases where the standard easings do not suffice.
+///
+/// The curve is evaluated the same way as the CSS
+/// [`cubic-bezier()`](https://www.w3.org/TR/css-easing-1/#cubic-bezier-easing-functions) function
+/// in browsers: the input is treated as the curve's `x` coordinate, and the output is the `y`
+/// coordinate of the point on the curve with that `x`. Inputs outside the `0..1` range are
+/// extrapolated along the tangent at the nearest end point.
 #[derive(Clone, Debug)]
 pub struct CubicBezierEasing {
-    segment: CubicBezierSegment<f32>,
+    x1: f64,
+    y1: f64,
+    x2: f64,
+    y2: f64,
+    x_coefficients: [f64; 3],
+    y_coefficients: [f64; 3],
+    x_samples: [f64; BEZIER_SAMPLE_COUNT],
 }
 
+/// Number of precomputed `x` values used to find an initial guess for `t`.
+const BEZIER_SAMPLE_COUNT: usize = 11;
+/// Minimum slope for which Newton-Raphson iteration is expected to converge quickly; for flatter
+/// parts of the curve, bisection is used instead.
+const BEZIER_NEWTON_MIN_SLOPE: f64 = 0.02;
+const BEZIER_NEWTON_ITERATIONS: usize = 4;
+const BEZIER_BISECTION_PRECISION: f64 = 1e-7;
+const BEZIER_BISECTION_MAX_ITERATIONS: usize = 20;
+
 impl CubicBezierEasing {
     /// Creates a new [CubicBezierEasing] with control points `(x1, y1)` and `(x2, y2)`.
     ///
+    /// As with CSS, the `x` coordinates should be in the range `0..1`; otherwise the curve may
+    /// have more than one `y` value for some `x` values, and the result is unspecified.
+    ///
     /// To experiment with different curves, see: <https://cubic-bezier.com/>
     pub fn new(x1: f32, y1: f32, x2: f32, y2: f32) -> Self {
+        let (x1, y1, x2, y2) = (x1 as f64, y1 as f64, x2 as f64, y2 as f64);
+        let x_coefficients = bezier_coefficients(x1, x2);
+        let x_samples = std::array::from_fn(|index| {
+            let t = index as f64 / (BEZIER_SAMPLE_COUNT - 1) as f64;
+            bezier_sample(&x_coefficients, t)
+        });
         Self {
-            segment: CubicBezierSegment {
-                from: Point::new(0.0, 0.0),
-                to: Point::new(1.0, 1.0),
-                ctrl1: Point::new(x1, y1),
-                ctrl2: Point::new(x2, y2),
-            },
+            x1,
+            y1,
+            x2,
+            y2,
+            x_coefficients,
+            y_coefficients: bezier_coefficients(y1, y2),
+            x_samples,
+        }
+    }
+
+    fn extrapolate(&self, x: f64) -> f64 {
+        let slope = self.extrapolation_slope(x);
+        if x < 0.0 {
+            slope * x
+        } else {
+            1.0 + slope * (x - 1.0)
+        }
+    }
+
+    fn extrapolation_slope(&self, x: f64) -> f64 {
+        if x < 0.0 {
+            if self.x1 > 0.0 {
+                self.y1 / self.x1
+            } else if self.y1 == 0.0 && self.x2 > 0.0 {
+                self.y2 / self.x2
+            } else {
+                0.0
+            }
+        } else if self.x2 < 1.0 {
+            (self.y2 - 1.0) / (self.x2 - 1.0)
+        } else if self.y2 == 1.0 && self.x1 < 1.0 {
+            (self.y1 - 1.0) / (self.x1 - 1.0)
+        } else {
+            0.0
+        }
+    }
+
+    // Finds the curve parameter `t` whose x-coordinate is equal to `x`.
+    fn solve_t(&self, x: f64) -> f64 {
+        let step = 1.0 / (BEZIER_SAMPLE_COUNT - 1) as f64;
+        let interval_index = self.x_samples[1..BEZIER_SAMPLE_COUNT - 1]
+            .iter()
+            .take_while(|&&sample| sample <= x)
+            .count();
+        let interval_start = self.x_samples[interval_index];
+        let interval_end = self.x_samples[interval_index + 1];
+        let distance = (x - interval_start) / (interval_end - interval_start);
+        let guess = (interval_index as f64 + distance) * step;
+        let initial_slope = bezier_slope(&self.x_coefficients, guess);
+        if initial_slope >= BEZIER_NEWTON_MIN_SLOPE {
+            self.newton_raphson(x, guess)
+        } else if initial_slope == 0.0 {
+            guess
+        } else {
+            let start = interval_index as f64 * step;
+            self.bisect(x, start, start + step)
+        }
+    }
+
+    fn newton_raphson(&self, x: f64, guess: f64) -> f64 {
+        let mut t = guess;
+        for _ in 0..BEZIER_NEWTON_ITERATIONS {
+            let slope = bezier_slope(&self.x_coefficients, t);
+            if slope == 0.0 {
+                break;
+            }
+            t -= (bezier_sample(&self.x_coefficients, t) - x) / slope;
         }
+        t
+    }
+
+    fn bisect(&self, x: f64, mut start: f64, mut end: f64) -> f64 {
+        let mut t = (start + end) / 2.0;
+        for _ in 0..BEZIER_BISECTION_MAX_ITERATIONS {
+            let error = bezier_sample(&self.x_coefficients, t) - x;
+            if error.abs() <= BEZIER_BISECTION_PRECISION {
+                break;
+            }
+            if error > 0.0 {
+                end = t;
+            } else {
+                start = t;
+            }
+            t = (start + end) / 2.0;
+        }
+        t
     }
 }
 
 impl EasingFunction for CubicBezierEasing {
     fn calc(&self, x: f32) -> f32 {
-        self.segment.y(x)
+        let x = x as f64;
+        if !(0.0..=1.0).contains(&x) {
+            return self.extrapolate(x) as f32;
+        }
+        let t = self.solve_t(x);
+        bezier_sample(&self.y_coefficients, t) as f32
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        let x = x as f64;
+        if !(0.0..=1.0).contains(&x) {
+            return Some(self.extrapolation_slope(x) as f32);
+        }
+        // By the chain rule, dy/dx = (dy/dt) / (dx/dt). Where the curve is vertical, this is
+        // undefined, and the numeric approximation is the best available answer.
+        let t = self.solve_t(x);
+        let x_slope = bezier_slope(&self.x_coefficients, t);
+        (x_slope != 0.0).then(|| (bezier_slope(&self.y_coefficients, t) / x_slope) as f32)
     }
 }
 
+// Computes the polynomial coefficients [a, b, c] for one dimension of a cubic bezier curve with end
+// points at 0 and 1, such that the coordinate at `t` is `((a * t + b) * t + c) * t`.
+fn bezier_coefficients(p1: f64, p2: f64) -> [f64; 3] {
+    let c = 3.0 * p1;
+    let b = 3.0 * (p2 - p1) - c;
+    let a = 1.0 - c - b;
+    [a, b, c]
+}
+
+fn bezier_sample([a, b, c]: &[f64; 3], t: f64) -> f64 {
+    ((a * t + b) * t + c) * t
+}
+
+fn bezier_slope([a, b, c]: &[f64; 3], t: f64) -> f64 {
+    (3.0 * a * t + 2.0 * b) * t + c
+}
+
 fn cubic_bezier(x1: f32, y1: f32, x2: f32, y2: f32) -> CubicBezierEasing {
     CubicBezierEasing::new(x1, y1, x2, y2)
 }
+
+// Approximates the derivative of `f` at `x` with a central difference. Near the ends of the `0..1`
+// range, the difference is taken only within the range, since many easings are clamped or
+// discontinuous outside it.
+fn numeric_derivative(f: impl Fn(f32) -> f32, x: f32) -> f32 {
+    let (x0, x1) = if (0.0..=1.0).contains(&x) {
+        (
+            (x - NUMERIC_DERIVATIVE_STEP).max(0.0),
+            (x + NUMERIC_DERIVATIVE_STEP).min(1.0),
+        )
+    } else {
+        (x - NUMERIC_DERIVATIVE_STEP, x + NUMERIC_DERIVATIVE_STEP)
+    };
+    (f(x1) - f(x0)) / (x1 - x0)
+}
+
+/// Distance between the sample points used to approximate derivatives numerically.
+const NUMERIC_DERIVATIVE_STEP: f32 = 1e-3;
+
+/// Easing function backed by a closure.
+///
+/// This is mainly a convenience for one-off curves that would otherwise need their own type with
+/// [Debug] and [Clone] implementations. It is normally created via [Easing::from_fn], or with
+/// [FnEasing::new] and converted into an [Easing] when a debug label is needed.
+#[derive(Clone)]
+pub struct FnEasing<F> {
+    f: F,
+    label: Option<String>,
+}
+
+impl<F> FnEasing<F>
+where
+    F: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    /// Creates a new [FnEasing] which evaluates the closure `f`.
+    pub fn new(f: F) -> Self {
+        Self { f, label: None }
+    }
+
+    /// Sets a label to identify this easing in [Debug] output, instead of the closure type.
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// Gets the debug label, if one was set.
+    pub fn label(&self) -> Option<&str> {
+        self.label.as_deref()
+    }
+}
+
+impl<F> Debug for FnEasing<F> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.label {
+            Some(label) => f.debug_tuple("FnEasing").field(label).finish(),
+            None => f
+                .debug_tuple("FnEasing")
+                .field(&std::any::type_name::<F>())
+                .finish(),
+        }
+    }
+}
+
+impl<F> EasingFunction for FnEasing<F>
+where
+    F: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    fn calc(&self, x: f32) -> f32 {
+        (self.f)(x)
+    }
+}
+
+impl<F> From<FnEasing<F>> for Easing
+where
+    F: Fn(f32) -> f32 + Clone + Send + Sync + 'static,
+{
+    fn from(value: FnEasing<F>) -> Self {
+        Easing::Custom(Box::new(value))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ReversedEasing(Easing);
+
+impl EasingFunction for ReversedEasing {
+    fn calc(&self, x: f32) -> f32 {
+        1.0 - self.0.calc(1.0 - x)
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        Some(self.0.slope(1.0 - x))
+    }
+}
+
+#[derive(Clone, Debug)]
+struct MirroredEasing(Easing);
+
+impl EasingFunction for MirroredEasing {
+    fn calc(&self, x: f32) -> f32 {
+        if x < 0.5 {
+            self.0.calc(x * 2.0) / 2.0
+        } else {
+            1.0 - self.0.calc(2.0 - x * 2.0) / 2.0
+        }
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        if x < 0.5 {
+            Some(self.0.slope(x * 2.0))
+        } else {
+            Some(self.0.slope(2.0 - x * 2.0))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ChainedEasing {
+    first: Easing,
+    second: Easing,
+    split: f32,
+}
+
+impl ChainedEasing {
+    // A split at 0 leaves no room for the first curve, even when the input is below 0, and would
+    // otherwise divide by zero.
+    fn is_first(&self, x: f32) -> bool {
+        self.split > 0.0 && (x < self.split || self.split >= 1.0)
+    }
+}
+
+impl EasingFunction for ChainedEasing {
+    fn calc(&self, x: f32) -> f32 {
+        if self.is_first(x) {
+            self.split * self.first.calc(x / self.split)
+        } else {
+            let remaining = 1.0 - self.split;
+            self.split + remaining * self.second.calc((x - self.split) / remaining)
+        }
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        if self.is_first(x) {
+            Some(self.first.slope(x / self.split))
+        } else {
+            let remaining = 1.0 - self.split;
+            Some(self.second.slope((x - self.split) / remaining))
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+struct BlendedEasing {
+    first: Easing,
+    second: Easing,
+    weight: f32,
+}
+
+impl EasingFunction for BlendedEasing {
+    fn calc(&self, x: f32) -> f32 {
+        let first = self.first.calc(x);
+        first + (self.second.calc(x) - first) * self.weight
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        let first = self.first.slope(x);
+        Some(first + (self.second.slope(x) - first) * self.weight)
+    }
+}
+
+#[derive(Clone, Debug)]
+struct ClampedEasing(Easing);
+
+impl EasingFunction for ClampedEasing {
+    fn calc(&self, x: f32) -> f32 {
+        self.0.calc(x).clamp(0.0, 1.0)
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        if (0.0..=1.0).contains(&self.0.calc(x)) {
+            Some(self.0.slope(x))
+        } else {
+            Some(0.0)
+        }
+    }
+}
+
+/// Specifies which end(s) of the curve are affected by a parameterized easing, such as
+/// [ElasticEasing] or [BounceEasing].
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EasingDirection {
+    /// The effect is applied at the start of the animation.
+    In,
+    /// The effect is applied at the end of the animation.
+    Out,
+    /// The effect is applied at both the start and end of the animation, with each taking up half
+    /// of the duration.
+    InOut,
+}
+
+impl EasingDirection {
+    // Applies the direction to an "out" easing function.
+    fn apply(&self, x: f32, ease_out: impl Fn(f32) -> f32) -> f32 {
+        match self {
+            Self::In => 1.0 - ease_out(1.0 - x),
+            Self::Out => ease_out(x),
+            Self::InOut if x < 0.5 => (1.0 - ease_out(1.0 - 2.0 * x)) / 2.0,
+            Self::InOut => (1.0 + ease_out(2.0 * x - 1.0)) / 2.0,
+        }
+    }
+}
+
+/// Smallest period allowed for an [ElasticEasing], which must be positive for the oscillations to
+/// be defined.
+const MIN_ELASTIC_PERIOD: f32 = 1e-6;
+
+/// Easing function that oscillates with exponentially decaying amplitude, like an elastic band.
+///
+/// With default parameters, this is equivalent to [Easing::InElastic], [Easing::OutElastic] or
+/// [Easing::InOutElastic], depending on the [EasingDirection]. The [amplitude](Self::amplitude)
+/// and [period](Self::period) can be changed for stronger, weaker, faster or slower oscillations.
+#[derive(Clone, Debug)]
+pub struct ElasticEasing {
+    amplitude: f32,
+    direction: EasingDirection,
+    period: f32,
+}
+
+impl ElasticEasing {
+    /// Creates a new [ElasticEasing] in the specified `direction`, using the same parameters as
+    /// the corresponding standard easing.
+    pub fn new(direction: EasingDirection) -> Self {
+        let period = match direction {
+            EasingDirection::InOut => 0.45,
+            _ => 0.3,
+        };
+        Self {
+            amplitude: 1.0,
+            direction,
+            period,
+        }
+    }
+
+    /// Configures the amplitude (maximum height) of the oscillations, where `1.0` is the distance
+    /// between start and end values.
+    ///
+    /// Amplitudes below `1.0`, including NaN, have the same effect as `1.0`, since the curve must
+    /// cover the entire distance.
+    pub fn amplitude(mut self, amplitude: f32) -> Self {
+        self.amplitude = if amplitude.is_nan() {
+            1.0
+        } else {
+            amplitude.clamp(1.0, f32::MAX)
+        };
+        self
+    }
+
+    /// Configures the period of the oscillations, i.e. the normalized time that each oscillation
+    /// takes. Smaller periods result in more oscillations.
+    ///
+    /// For [EasingDirection::InOut], the period is relative to each half of the curve. The period
+    /// must be positive; zero, negative and NaN periods are replaced with a very small period.
+    pub fn period(mut self, period: f32) -> Self {
+        self.period = if period.is_nan() {
+            MIN_ELASTIC_PERIOD
+        } else {
+            period.clamp(MIN_ELASTIC_PERIOD, f32::MAX)
+        };
+        self
+    }
+
+    fn ease_out(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+        let phase_shift = self.period / TAU * (1.0 / self.amplitude).asin();
+        let decay = 2f32.powf(-10.0 * x);
+        self.amplitude * decay * ((x - phase_shift) / self.period * TAU).sin() + 1.0
+    }
+}
+
+impl EasingFunction for ElasticEasing {
+    fn calc(&self, x: f32) -> f32 {
+        self.direction.apply(x, |x| self.ease_out(x))
+    }
+}
+
+/// Easing function that simulates a ball dropped from the start value, which bounces on the end
+/// value with a constant loss of energy on each bounce.
+///
+/// With default parameters, this is equivalent to [Easing::InBounce], [Easing::OutBounce] or
+/// [Easing::InOutBounce], depending on the [EasingDirection]. The number of
+/// [bounces](Self::bounces) and the [restitution](Self::restitution) can be changed to produce
+/// more, fewer, higher or lower bounces.
+#[derive(Clone, Debug)]
+pub struct BounceEasing {
+    bounces: u32,
+    direction: EasingDirection,
+    restitution: f32,
+}
+
+impl BounceEasing {
+    /// Creates a new [BounceEasing] in the specified `direction`, using the same parameters as the
+    /// corresponding standard easing.
+    pub fn new(direction: EasingDirection) -> Self {
+        Self {
+            bounces: 3,
+            direction,
+            restitution: 0.5,
+        }
+    }
+
+    /// Configures the number of bounces after the initial drop.
+    pub fn bounces(mut self, bounces: u32) -> Self {
+        self.bounces = bounces;
+        self
+    }
+
+    /// Configures the fraction of speed retained after each bounce, from `0.0` to `1.0`.
+    ///
+    /// Each bounce lasts `restitution` times as long as the previous one, and reaches a height of
+    /// `restitution` squared times the previous height. The default value of `0.5` makes each
+    /// bounce a quarter of the height of the previous one. Values outside the valid range are
+    /// clamped to it, and NaN is treated as `0.0`.
+    pub fn restitution(mut self, restitution: f32) -> Self {
+        self.restitution = if restitution.is_nan() {
+            0.0
+        } else {
+            restitution.clamp(0.0, 1.0)
+        };
+        self
+    }
+
+    fn ease_out(&self, x: f32) -> f32 {
+        if x <= 0.0 {
+            return 0.0;
+        }
+        if x >= 1.0 {
+            return 1.0;
+        }
+        // Time is measured in units of the initial drop, which is half of a parabola taking 1 unit
+        // of time. Each bounce is a full parabola whose duration is scaled by the restitution.
+        let bounce_half_widths =
+            (1..=self.bounces).map(|bounce| self.restitution.powi(bounce as i32));
+        let total_time = 1.0 + 2.0 * bounce_half_widths.clone().sum::<f32>();
+        let time = x * total_time;
+        if time < 1.0 {
+            return time * time;
+        }
+        let mut bounce_start = 1.0;
+        for half_width in bounce_half_widths {
+            let bounce_end = bounce_start + 2.0 * half_width;
+            if time < bounce_end {
+                let center = bounce_start + half_width;
+                return 1.0 - half_width * half_width + (time - center).powi(2);
+            }
+            bounce_start = bounce_end;
+        }
+        1.0
+    }
+}
+
+impl EasingFunction for BounceEasing {
+    fn calc(&self, x: f32) -> f32 {
+        self.direction.apply(x, |x| self.ease_out(x))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    // Reference implementations from https://easings.net.
+
+    fn reference_out_elastic(x: f32) -> f32 {
+        let c4 = TAU / 3.0;
+        2f32.powf(-10.0 * x) * ((x * 10.0 - 0.75) * c4).sin() + 1.0
+    }
+
+    fn reference_in_elastic(x: f32) -> f32 {
+        let c4 = TAU / 3.0;
+        -(2f32.powf(10.0 * x - 10.0)) * ((x * 10.0 - 10.75) * c4).sin()
+    }
+
+    fn reference_in_out_elastic(x: f32) -> f32 {
+        let c5 = TAU / 4.5;
+        if x < 0.5 {
+            -(2f32.powf(20.0 * x - 10.0) * ((20.0 * x - 11.125) * c5).sin()) / 2.0
+        } else {
+            (2f32.powf(-20.0 * x + 10.0) * ((20.0 * x - 11.125) * c5).sin()) / 2.0 + 1.0
+        }
+    }
+
+    fn reference_out_bounce(x: f32) -> f32 {
+        let n1 = 7.5625;
+        let d1 = 2.75;
+        if x < 1.0 / d1 {
+            n1 * x * x
+        } else if x < 2.0 / d1 {
+            let x = x - 1.5 / d1;
+            n1 * x * x + 0.75
+        } else if x < 2.5 / d1 {
+            let x = x - 2.25 / d1;
+            n1 * x * x + 0.9375
+        } else {
+            let x = x - 2.625 / d1;
+            n1 * x * x + 0.984375
+        }
+    }
+
+    fn reference_in_bounce(x: f32) -> f32 {
+        1.0 - reference_out_bounce(1.0 - x)
+    }
+
+    fn reference_in_out_bounce(x: f32) -> f32 {
+        if x < 0.5 {
+            (1.0 - reference_out_bounce(1.0 - 2.0 * x)) / 2.0
+        } else {
+            (1.0 + reference_out_bounce(2.0 * x - 1.0)) / 2.0
+        }
+    }
+
+    fn assert_matches_reference(easing: &Easing, reference: impl Fn(f32) -> f32) {
+        assert_eq!(easing.calc(0.0), 0.0);
+        assert_eq!(easing.calc(1.0), 1.0);
+        for step in 1..100 {
+            let x = step as f32 / 100.0;
+            assert_relative_eq!(easing.calc(x), reference(x), epsilon = 0.0001);
+        }
+    }
+
+    fn assert_steps(easing: &Easing, expected: &[(f32, f32)]) {
+        for &(x, y) in expected {
+            assert_eq!(easing.calc(x), y, "Wrong output for {easing:?} at x = {x}");
+        }
+    }
+
+    #[test]
+    fn when_steps_jump_start_then_jumps_at_start_of_each_interval() {
+        assert_steps(
+            &Easing::Steps(4, StepPosition::JumpStart),
+            &[
+                (0.0, 0.25),
+                (0.2, 0.25),
+                (0.25, 0.5),
+                (0.5, 0.75),
+                (0.74, 0.75),
+                (0.75, 1.0),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn when_steps_jump_end_then_jumps_at_end_of_each_interval() {
+        assert_steps(
+            &Easing::Steps(4, StepPosition::JumpEnd),
+            &[
+                (0.0, 0.0),
+                (0.2, 0.0),
+                (0.25, 0.25),
+                (0.5, 0.5),
+                (0.99, 0.75),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn when_steps_jump_none_then_holds_start_and_end_values() {
+        assert_steps(
+            &Easing::Steps(5, StepPosition::JumpNone),
+            &[
+                (0.0, 0.0),
+                (0.19, 0.0),
+                (0.2, 0.25),
+                (0.4, 0.5),
+                (0.6, 0.75),
+                (0.8, 1.0),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn when_steps_jump_both_then_skips_start_and_end_values() {
+        assert_steps(
+            &Easing::Steps(3, StepPosition::JumpBoth),
+            &[
+                (0.0, 0.25),
+                (0.33, 0.25),
+                (0.34, 0.5),
+                (0.67, 0.75),
+                (0.99, 0.75),
+                (1.0, 1.0),
+            ],
+        );
+    }
+
+    #[test]
+    fn when_single_step_then_matches_step_start_and_step_end() {
+        let step_start = Easing::Steps(1, StepPosition::JumpStart);
+        let step_end = Easing::Steps(1, StepPosition::JumpEnd);
+
+        assert_steps(&step_start, &[(0.0, 1.0), (0.5, 1.0), (1.0, 1.0)]);
+        assert_steps(&step_end, &[(0.0, 0.0), (0.5, 0.0), (1.0, 1.0)]);
+    }
+
+    #[test]
+    fn when_too_few_steps_then_uses_minimum() {
+        assert_steps(
+            &Easing::Steps(0, StepPosition::JumpEnd),
+            &[(0.0, 0.0), (0.5, 0.0), (1.0, 1.0)],
+        );
+        assert_steps(
+            &Easing::Steps(1, StepPosition::JumpNone),
+            &[(0.0, 0.0), (0.49, 0.0), (0.5, 1.0), (1.0, 1.0)],
+        );
+    }
+
+    #[test]
+    fn when_linear_stops_have_no_inputs_then_spaces_evenly() {
+        let easing = PiecewiseLinearEasing::new([
+            LinearStop::new(0.0),
+            LinearStop::new(0.25),
+            LinearStop::new(1.0),
+        ]);
+
+        assert_eq!(easing.points(), &[(0.0, 0.0), (0.5, 0.25), (1.0, 1.0)]);
+        assert_eq!(easing.calc(0.25), 0.125);
+        assert_eq!(easing.calc(0.75), 0.625);
+    }
+
+    #[test]
+    fn when_linear_stops_partially_have_inputs_then_spaces_missing_inputs_evenly() {
+        let easing = PiecewiseLinearEasing::new([
+            LinearStop::new(0.0),
+            LinearStop::at(0.1, 0.2),
+            LinearStop::new(0.2),
+            LinearStop::new(0.3),
+            LinearStop::at(0.4, 0.8),
+            LinearStop::new(1.0),
+        ]);
+
+        assert_relative_eq!(easing.points()[2].0, 0.4);
+        assert_relative_eq!(easing.points()[3].0, 0.6);
+        assert_eq!(easing.points()[5], (1.0, 1.0));
+    }
+
+    #[test]
+    fn when_linear_input_decreases_then_raises_to_previous_input() {
+        let easing = PiecewiseLinearEasing::new([
+            LinearStop::new(0.0),
+            LinearStop::at(0.5, 0.5),
+            LinearStop::at(0.7, 0.25),
+            LinearStop::new(1.0),
+        ]);
+
+        assert_eq!(
+            easing.points(),
+            &[(0.0, 0.0), (0.5, 0.5), (0.5, 0.7), (1.0, 1.0)]
+        );
+        // Equal inputs create a discontinuity; the later stop applies at the exact position.
+        assert_eq!(easing.calc(0.25), 0.25);
+        assert_eq!(easing.calc(0.5), 0.7);
+        assert_relative_eq!(easing.calc(0.75), 0.85);
+    }
+
+    #[test]
+    fn when_linear_inputs_exceed_one_then_last_stop_uses_largest_input() {
+        let easing = PiecewiseLinearEasing::new([
+            LinearStop::new(0.0),
+            LinearStop::at(0.5, 1.2),
+            LinearStop::new(1.0),
+        ]);
+
+        assert_eq!(easing.points(), &[(0.0, 0.0), (1.2, 0.5), (1.2, 1.0)]);
+    }
+
+    #[test]
+    fn when_linear_input_outside_stops_then_extrapolates() {
+        let easing =
+            PiecewiseLinearEasing::new([LinearStop::at(0.0, 0.2), LinearStop::at(1.0, 0.8)]);
+
+        assert_eq!(easing.calc(0.2), 0.0);
+        assert_eq!(easing.calc(0.5), 0.5);
+        assert_relative_eq!(easing.calc(0.0), -1.0 / 3.0);
+        assert_relative_eq!(easing.calc(1.0), 4.0 / 3.0);
+    }
+
+    #[test]
+    fn when_linear_has_fewer_than_two_stops_then_uses_fallback() {
+        let empty = PiecewiseLinearEasing::new([]);
+        let single = PiecewiseLinearEasing::new([LinearStop::new(0.3)]);
+
+        assert_eq!(empty.calc(0.4), 0.4);
+        assert_eq!(single.calc(0.0), 0.3);
+        assert_eq!(single.calc(1.0), 0.3);
+    }
+
+    fn assert_css_outputs(easing: &Easing, expected: [f32; 5]) {
+        let inputs = [0.1, 0.25, 0.5, 0.75, 0.9];
+        assert_eq!(easing.calc(0.0), 0.0);
+        assert_eq!(easing.calc(1.0), 1.0);
+        for (x, y) in inputs.into_iter().zip(expected) {
+            assert_relative_eq!(easing.calc(x), y, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn css_easings_match_browser_output() {
+        assert_css_outputs(
+            &Easing::Ease,
+            [0.094796, 0.408511, 0.802403, 0.960459, 0.994316],
+        );
+        assert_css_outputs(
+            &Easing::In,
+            [0.017027, 0.093465, 0.315357, 0.621862, 0.839428],
+        );
+        assert_css_outputs(
+            &Easing::Out,
+            [0.160572, 0.378138, 0.684643, 0.906535, 0.982973],
+        );
+        assert_css_outputs(
+            &Easing::InOut,
+            [0.019722, 0.129162, 0.5, 0.870838, 0.980278],
+        );
+    }
+
+    #[test]
+    fn cubic_bezier_solves_flat_and_overshooting_curves() {
+        // Nearly-vertical start (x1 = 0) requires bisection rather than Newton-Raphson.
+        assert_css_outputs(
+            &Easing::OutCirc,
+            [0.434606, 0.65965, 0.865128, 0.968157, 0.995001],
+        );
+        assert_css_outputs(
+            &Easing::InOutBack,
+            [-0.072823, -0.097708, 0.5, 1.097708, 1.072823],
+        );
+    }
+
+    #[test]
+    fn when_cubic_bezier_input_out_of_range_then_extrapolates_along_tangent() {
+        let easing = CubicBezierEasing::new(0.25, 0.1, 0.25, 1.0);
+        let vertical_start = CubicBezierEasing::new(0.0, 0.5, 1.0, 1.0);
+
+        assert_relative_eq!(easing.calc(-0.5), -0.2);
+        assert_relative_eq!(easing.calc(1.5), 1.0);
+        assert_eq!(vertical_start.calc(-0.5), 0.0);
+    }
+
+    #[test]
+    fn elastic_easings_match_reference() {
+        assert_matches_reference(&Easing::InElastic, reference_in_elastic);
+        assert_matches_reference(&Easing::OutElastic, reference_out_elastic);
+        assert_matches_reference(&Easing::InOutElastic, reference_in_out_elastic);
+    }
+
+    #[test]
+    fn bounce_easings_match_reference() {
+        assert_matches_reference(&Easing::InBounce, reference_in_bounce);
+        assert_matches_reference(&Easing::OutBounce, reference_out_bounce);
+        assert_matches_reference(&Easing::InOutBounce, reference_in_out_bounce);
+    }
+
+    #[test]
+    fn when_elastic_amplitude_increased_then_overshoots_further() {
+        let default_easing = ElasticEasing::new(EasingDirection::Out);
+        let strong_easing = ElasticEasing::new(EasingDirection::Out).amplitude(1.5);
+
+        let max_default = (1..100).map(|i| default_easing.calc(i as f32 / 100.0));
+        let max_strong = (1..100).map(|i| strong_easing.calc(i as f32 / 100.0));
+
+        assert!(max_strong.fold(0.0, f32::max) > max_default.fold(0.0, f32::max));
+        assert_eq!(strong_easing.calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn when_elastic_period_decreased_then_oscillates_more() {
+        let count_crossings = |easing: ElasticEasing| {
+            (1..1000)
+                .map(|i| easing.calc(i as f32 / 1000.0) > 1.0)
+                .collect::<Vec<_>>()
+                .windows(2)
+                .filter(|w| w[0] != w[1])
+                .count()
+        };
+
+        let default_crossings = count_crossings(ElasticEasing::new(EasingDirection::Out));
+        let fast_crossings = count_crossings(ElasticEasing::new(EasingDirection::Out).period(0.1));
+
+        assert!(fast_crossings > default_crossings);
+    }
+
+    #[test]
+    fn when_bounce_count_changed_then_touches_end_value_once_per_bounce() {
+        let count_touches = |easing: BounceEasing| {
+            // Every contact with the end value (1.0) is a local maximum. The final contact at
+            // x = 1 is not counted, which leaves one contact per bounce.
+            (1..=1000)
+                .map(|i| easing.calc(i as f32 / 1000.0))
+                .collect::<Vec<_>>()
+                .windows(3)
+                .filter(|w| w[1] > w[0] && w[1] > w[2])
+                .count()
+        };
+
+        assert_eq!(count_touches(BounceEasing::new(EasingDirection::Out)), 3);
+        assert_eq!(
+            count_touches(BounceEasing::new(EasingDirection::Out).bounces(5)),
+            5
+        );
+        assert_eq!(
+            count_touches(BounceEasing::new(EasingDirection::Out).bounces(0)),
+            0
+        );
+    }
+
+    #[test]
+    fn when_restitution_increased_then_bounces_higher() {
+        let min_value = |easing: BounceEasing| {
+            // Lowest point after the first contact with the end value.
+            (60..100)
+                .map(|i| easing.calc(i as f32 / 100.0))
+                .fold(1.0, f32::min)
+        };
+
+        let default_min = min_value(BounceEasing::new(EasingDirection::Out));
+        let bouncy_min = min_value(BounceEasing::new(EasingDirection::Out).restitution(0.7));
+
+        assert!(bouncy_min < default_min);
+    }
+
+    #[test]
+    fn when_elastic_or_bounce_parameters_out_of_range_then_output_is_finite() {
+        let easings: Vec<Box<dyn EasingFunction>> = vec![
+            Box::new(ElasticEasing::new(EasingDirection::Out).period(0.0)),
+            Box::new(ElasticEasing::new(EasingDirection::Out).period(-0.3)),
+            Box::new(ElasticEasing::new(EasingDirection::InOut).period(f32::NAN)),
+            Box::new(ElasticEasing::new(EasingDirection::Out).period(f32::INFINITY)),
+            Box::new(ElasticEasing::new(EasingDirection::Out).amplitude(f32::NAN)),
+            Box::new(ElasticEasing::new(EasingDirection::Out).amplitude(-2.0)),
+            Box::new(BounceEasing::new(EasingDirection::Out).restitution(-0.5)),
+            Box::new(BounceEasing::new(EasingDirection::Out).restitution(f32::NAN)),
+        ];
+
+        for easing in easings {
+            for i in 0..=100 {
+                let y = easing.calc(i as f32 / 100.0);
+                assert!(y.is_finite(), "{easing:?} at {i}% was {y}");
+            }
+            assert_eq!(easing.calc(1.0), 1.0);
+        }
+    }
+
+    #[test]
+    fn when_elastic_amplitude_below_one_then_same_as_default() {
+        let default_easing = ElasticEasing::new(EasingDirection::Out);
+        let weak_easing = ElasticEasing::new(EasingDirection::Out).amplitude(0.5);
+
+        for i in 0..=10 {
+            let x = i as f32 / 10.0;
+            assert_relative_eq!(weak_easing.calc(x), default_easing.calc(x), epsilon = 1e-6);
+        }
+    }
+
+    #[test]
+    fn fn_easing_evaluates_closure() {
+        let exponent = 3;
+        let easing = Easing::from_fn(move |x| x.powi(exponent));
+
+        assert_eq!(easing.calc(0.0), 0.0);
+        assert_eq!(easing.calc(0.5), 0.125);
+        assert_eq!(easing.clone().calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn fn_easing_debug_output_uses_label_when_set() {
+        let unlabeled = FnEasing::new(|x| x);
+        let labeled = FnEasing::new(|x| x).with_label("identity");
+
+        assert!(format!("{unlabeled:?}").starts_with("FnEasing(\""));
+        assert_eq!(format!("{labeled:?}"), "FnEasing(\"identity\")");
+        assert_eq!(
+            format!("{:?}", Easing::from(labeled)),
+            "Custom(FnEasing(\"identity\"))"
+        );
+    }
+
+    #[test]
+    fn reversed_easing_swaps_in_and_out() {
+        let easing = Easing::from_fn(|x| x * x).reversed();
+
+        for step in 0..=10 {
+            let x = step as f32 / 10.0;
+            assert_relative_eq!(easing.calc(x), 1.0 - (1.0 - x).powi(2), epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn mirrored_easing_creates_in_out_curve() {
+        let easing = Easing::from_fn(|x| x * x * x).mirrored();
+
+        // Reference implementation of easeInOutCubic from https://easings.net.
+        for step in 0..=20 {
+            let x = step as f32 / 20.0;
+            let expected = if x < 0.5 {
+                4.0 * x * x * x
+            } else {
+                1.0 - (-2.0 * x + 2.0).powi(3) / 2.0
+            };
+            assert_relative_eq!(easing.calc(x), expected, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn chained_easing_switches_curves_at_split() {
+        let easing = Easing::from_fn(|x| x * x).then(Easing::Linear, 0.4);
+
+        assert_relative_eq!(easing.calc(0.0), 0.0);
+        assert_relative_eq!(easing.calc(0.2), 0.4 * 0.25, epsilon = 0.0001);
+        assert_relative_eq!(easing.calc(0.4), 0.4);
+        assert_relative_eq!(easing.calc(0.7), 0.7);
+        assert_relative_eq!(easing.calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn when_chain_split_at_either_end_then_uses_one_curve_only() {
+        let start = Easing::Linear.then(Easing::from_fn(|x| x * x), 0.0);
+        let nan = Easing::Linear.then(Easing::from_fn(|x| x * x), f32::NAN);
+        let end = Easing::from_fn(|x| x * x).then(Easing::Linear, 1.0);
+
+        assert_relative_eq!(start.calc(0.5), 0.25, epsilon = 0.0001);
+        assert_relative_eq!(start.calc(-0.5), 0.25, epsilon = 0.0001);
+        assert_relative_eq!(start.slope(0.0), 0.0, epsilon = 0.01);
+        assert_relative_eq!(nan.calc(0.5), 0.25, epsilon = 0.0001);
+        assert_relative_eq!(end.calc(0.5), 0.25, epsilon = 0.0001);
+        assert_relative_eq!(end.calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn blended_easing_interpolates_between_curves() {
+        let easing = Easing::Linear.blend(Easing::from_fn(|x| x * x), 0.25);
+
+        assert_relative_eq!(easing.calc(0.0), 0.0);
+        assert_relative_eq!(easing.calc(0.5), 0.75 * 0.5 + 0.25 * 0.25);
+        assert_relative_eq!(easing.calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn clamped_easing_removes_overshoot() {
+        let easing = Easing::OutBack.clamped();
+
+        let max = (0..=100)
+            .map(|i| easing.calc(i as f32 / 100.0))
+            .fold(0.0, f32::max);
+        assert!(Easing::OutBack.calc(0.6) > 1.0);
+        assert_eq!(max, 1.0);
+        assert_eq!(Easing::InBack.clamped().calc(0.2), 0.0);
+    }
+
+    #[test]
+    fn cubic_bezier_derivative_matches_slope_of_curve() {
+        for easing in [Easing::Ease, Easing::InOutCubic, Easing::OutBack] {
+            for step in 0..=10 {
+                let x = step as f32 / 10.0;
+                let derivative = easing.derivative(x).unwrap();
+                let numeric = numeric_derivative(|x| easing.calc(x), x);
+                assert_relative_eq!(derivative, numeric, epsilon = 0.01, max_relative = 0.01);
+            }
+        }
+        assert_relative_eq!(Easing::InBack.derivative(-1.0).unwrap(), 0.0);
+    }
+
+    #[test]
+    fn linear_and_steps_derivatives_are_constant() {
+        assert_eq!(Easing::Linear.derivative(0.3), Some(1.0));
+        assert_eq!(
+            Easing::Steps(4, StepPosition::JumpEnd).derivative(0.3),
+            Some(0.0)
+        );
+        let piecewise = PiecewiseLinearEasing::new([
+            LinearStop::new(0.0),
+            LinearStop::at(0.5, 0.25),
+            LinearStop::new(1.0),
+        ]);
+        assert_eq!(piecewise.derivative(0.1), Some(2.0));
+        assert_eq!(piecewise.derivative(0.5), Some(2.0 / 3.0));
+    }
+
+    #[test]
+    fn when_no_analytic_derivative_then_slope_is_approximated() {
+        let easing = Easing::from_fn(|x| x * x);
+
+        assert_eq!(easing.derivative(0.5), None);
+        assert_relative_eq!(easing.slope(0.5), 1.0, epsilon = 0.001);
+        assert_relative_eq!(easing.slope(1.0), 2.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn combined_easing_derivatives_follow_inner_curves() {
+        let quad = Easing::from_fn(|x| x * x);
+
+        assert_relative_eq!(quad.clone().reversed().slope(0.25), 1.5, epsilon = 0.001);
+        assert_relative_eq!(quad.clone().mirrored().slope(0.25), 1.0, epsilon = 0.001);
+        assert_relative_eq!(
+            Easing::Linear.blend(quad, 0.5).slope(1.0),
+            1.5,
+            epsilon = 0.01
+        );
+        assert_eq!(Easing::OutBack.clamped().slope(0.6), 0.0);
+    }
+}
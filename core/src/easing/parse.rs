@@ -0,0 +1,602 @@
+//! Parser for the CSS [`<easing-function>`](https://www.w3.org/TR/css-easing-2/#easing-functions)
+//! syntax.
+
+use super::{CubicBezierEasing, Easing, LinearStop, PiecewiseLinearEasing, StepPosition};
+use std::fmt::{Display, Formatter};
+
+/// Error returned when parsing an [`Easing`] from a CSS string fails.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ParseEasingError {
+    kind: ParseEasingErrorKind,
+    position: usize,
+}
+
+impl ParseEasingError {
+    /// Gets the kind of error that occurred.
+    pub fn kind(&self) -> &ParseEasingErrorKind {
+        &self.kind
+    }
+
+    /// Gets the position of the error, as a byte offset from the start of the input.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+}
+
+impl Display for ParseEasingError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} at position {}", self.kind, self.position)
+    }
+}
+
+impl std::error::Error for ParseEasingError {}
+
+/// Specifies the reason that parsing an [`Easing`] failed.
+#[derive(Clone, Debug, PartialEq)]
+pub enum ParseEasingErrorKind {
+    /// A specific token, such as a number or closing parenthesis, was required but a different
+    /// character was found.
+    Expected(&'static str, char),
+    /// A specific token, such as a number or closing parenthesis, was required but the input ended.
+    UnexpectedEnd(&'static str),
+    /// The input contains a keyword that is not a valid easing function.
+    UnknownKeyword(String),
+    /// The input contains a function name that is not a valid easing function.
+    UnknownFunction(String),
+    /// The input contains a `<step-position>` keyword that is not valid for `steps()`.
+    UnknownStepPosition(String),
+    /// A function argument was syntactically valid, but its value is not allowed.
+    InvalidArgument(&'static str),
+}
+
+impl Display for ParseEasingErrorKind {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Expected(expected, found) => write!(f, "expected {expected}, found '{found}'"),
+            Self::UnexpectedEnd(expected) => write!(f, "expected {expected}, found end of input"),
+            Self::UnknownKeyword(keyword) => write!(f, "unknown easing keyword '{keyword}'"),
+            Self::UnknownFunction(name) => write!(f, "unknown easing function '{name}()'"),
+            Self::UnknownStepPosition(name) => write!(f, "unknown step position '{name}'"),
+            Self::InvalidArgument(message) => write!(f, "invalid argument: {message}"),
+        }
+    }
+}
+
+pub(super) fn parse_easing(input: &str) -> Result<Easing, ParseEasingError> {
+    let mut parser = Parser::new(input);
+    parser.skip_whitespace();
+    let easing = parser.easing()?;
+    parser.skip_whitespace();
+    match parser.peek() {
+        Some(c) => Err(parser.error(ParseEasingErrorKind::Expected("end of input", c))),
+        None => Ok(easing),
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    position: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, position: 0 }
+    }
+
+    fn easing(&mut self) -> Result<Easing, ParseEasingError> {
+        let start = self.position;
+        let name = self.ident()?.to_ascii_lowercase();
+        if self.peek() == Some('(') {
+            self.position += 1;
+            let easing = match name.as_str() {
+                "cubic-bezier" => self.cubic_bezier_args()?,
+                "linear" => self.linear_args()?,
+                "steps" => self.steps_args()?,
+                _ => return Err(self.error_at(start, ParseEasingErrorKind::UnknownFunction(name))),
+            };
+            self.skip_whitespace();
+            self.expect(')', "')'")?;
+            return Ok(easing);
+        }
+        match name.as_str() {
+            "linear" => Ok(Easing::Linear),
+            "ease" => Ok(Easing::Ease),
+            "ease-in" => Ok(Easing::In),
+            "ease-out" => Ok(Easing::Out),
+            "ease-in-out" => Ok(Easing::InOut),
+            "step-start" => Ok(Easing::Steps(1, StepPosition::JumpStart)),
+            "step-end" => Ok(Easing::Steps(1, StepPosition::JumpEnd)),
+            _ => Err(self.error_at(start, ParseEasingErrorKind::UnknownKeyword(name))),
+        }
+    }
+
+    fn cubic_bezier_args(&mut self) -> Result<Easing, ParseEasingError> {
+        let mut args = [0.0; 4];
+        for (index, arg) in args.iter_mut().enumerate() {
+            if index > 0 {
+                self.comma()?;
+            }
+            self.skip_whitespace();
+            let start = self.position;
+            *arg = self.number()?;
+            // Only the x coordinates are restricted; y coordinates may overshoot.
+            if index % 2 == 0 && !(0.0..=1.0).contains(arg) {
+                return Err(self.error_at(
+                    start,
+                    ParseEasingErrorKind::InvalidArgument("x coordinates must be between 0 and 1"),
+                ));
+            }
+        }
+        let [x1, y1, x2, y2] = args;
+        Ok(Easing::Custom(Box::new(CubicBezierEasing::new(
+            x1, y1, x2, y2,
+        ))))
+    }
+
+    fn linear_args(&mut self) -> Result<Easing, ParseEasingError> {
+        self.skip_whitespace();
+        let args_start = self.position;
+        let mut stops = Vec::new();
+        let mut stop_count = 0;
+        loop {
+            self.skip_whitespace();
+            let stop_start = self.position;
+            let mut output = None;
+            let mut inputs = Vec::new();
+            while matches!(self.peek(), Some(c) if is_number_start(c)) {
+                let start = self.position;
+                let value = self.number()?;
+                if self.peek() == Some('%') {
+                    self.position += 1;
+                    if inputs.len() == 2 {
+                        return Err(self.error_at(
+                            start,
+                            ParseEasingErrorKind::InvalidArgument(
+                                "linear stops have at most two percentages",
+                            ),
+                        ));
+                    }
+                    inputs.push(value / 100.0);
+                } else if output.replace(value).is_some() {
+                    return Err(self.error_at(
+                        start,
+                        ParseEasingErrorKind::InvalidArgument(
+                            "linear stops have exactly one output number",
+                        ),
+                    ));
+                }
+                self.skip_whitespace();
+            }
+            let Some(output) = output else {
+                if self.position == stop_start {
+                    return Err(self.unexpected("number"));
+                }
+                return Err(self.error_at(
+                    stop_start,
+                    ParseEasingErrorKind::InvalidArgument(
+                        "linear stops have exactly one output number",
+                    ),
+                ));
+            };
+            if inputs.is_empty() {
+                stops.push(LinearStop::new(output));
+            }
+            for input in inputs {
+                stops.push(LinearStop::at(output, input));
+            }
+            stop_count += 1;
+            if self.peek() != Some(',') {
+                break;
+            }
+            self.position += 1;
+        }
+        if stop_count < 2 {
+            return Err(self.error_at(
+                args_start,
+                ParseEasingErrorKind::InvalidArgument("linear requires at least two stops"),
+            ));
+        }
+        Ok(Easing::Custom(Box::new(PiecewiseLinearEasing::new(stops))))
+    }
+
+    fn steps_args(&mut self) -> Result<Easing, ParseEasingError> {
+        self.skip_whitespace();
+        let count_start = self.position;
+        let count = self.integer()?;
+        self.skip_whitespace();
+        let position = if self.peek() == Some(',') {
+            self.position += 1;
+            self.skip_whitespace();
+            let position_start = self.position;
+            let name = self.ident()?.to_ascii_lowercase();
+            match name.as_str() {
+                "jump-start" | "start" => StepPosition::JumpStart,
+                "jump-end" | "end" => StepPosition::JumpEnd,
+                "jump-none" => StepPosition::JumpNone,
+                "jump-both" => StepPosition::JumpBoth,
+                _ => {
+                    return Err(self.error_at(
+                        position_start,
+                        ParseEasingErrorKind::UnknownStepPosition(name),
+                    ))
+                }
+            }
+        } else {
+            StepPosition::default()
+        };
+        let min_count = match position {
+            StepPosition::JumpNone => 2,
+            _ => 1,
+        };
+        if count < min_count {
+            let message = match position {
+                StepPosition::JumpNone => "jump-none requires at least 2 steps",
+                _ => "step count must be at least 1",
+            };
+            return Err(self.error_at(count_start, ParseEasingErrorKind::InvalidArgument(message)));
+        }
+        Ok(Easing::Steps(count, position))
+    }
+
+    fn comma(&mut self) -> Result<(), ParseEasingError> {
+        self.skip_whitespace();
+        self.expect(',', "','")
+    }
+
+    fn error(&self, kind: ParseEasingErrorKind) -> ParseEasingError {
+        self.error_at(self.position, kind)
+    }
+
+    fn error_at(&self, position: usize, kind: ParseEasingErrorKind) -> ParseEasingError {
+        ParseEasingError { kind, position }
+    }
+
+    fn expect(
+        &mut self,
+        expected_char: char,
+        expected: &'static str,
+    ) -> Result<(), ParseEasingError> {
+        if self.peek() == Some(expected_char) {
+            self.position += expected_char.len_utf8();
+            Ok(())
+        } else {
+            Err(self.unexpected(expected))
+        }
+    }
+
+    fn ident(&mut self) -> Result<&'a str, ParseEasingError> {
+        let start = self.position;
+        match self.peek() {
+            Some(c) if c.is_ascii_alphabetic() || c == '-' || c == '_' => {}
+            _ => return Err(self.unexpected("identifier")),
+        }
+        let rest = &self.input[start..];
+        let length = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+            .unwrap_or(rest.len());
+        self.position += length;
+        Ok(&self.input[start..self.position])
+    }
+
+    fn integer(&mut self) -> Result<u32, ParseEasingError> {
+        let start = self.position;
+        if self.peek() == Some('+') {
+            self.position += 1;
+        }
+        let digits = self.digits();
+        if digits.is_empty() {
+            self.position = start;
+            return Err(self.unexpected("integer"));
+        }
+        if matches!(self.peek(), Some('.' | 'e' | 'E')) {
+            return Err(self.error_at(
+                start,
+                ParseEasingErrorKind::InvalidArgument("step count must be an integer"),
+            ));
+        }
+        digits.parse().map_err(|_| {
+            self.error_at(
+                start,
+                ParseEasingErrorKind::InvalidArgument("step count is too large"),
+            )
+        })
+    }
+
+    fn digits(&mut self) -> &'a str {
+        let start = self.position;
+        let rest = &self.input[start..];
+        let length = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        self.position += length;
+        &self.input[start..self.position]
+    }
+
+    // Parses a CSS <number>: optional sign, integer and/or fractional digits, optional exponent.
+    fn number(&mut self) -> Result<f32, ParseEasingError> {
+        let start = self.position;
+        if matches!(self.peek(), Some('+' | '-')) {
+            self.position += 1;
+        }
+        let mut digit_count = self.digits().len();
+        if self.peek() == Some('.') {
+            let dot_position = self.position;
+            self.position += 1;
+            let fraction_length = self.digits().len();
+            if fraction_length == 0 {
+                // A trailing dot is not part of a CSS number.
+                self.position = dot_position;
+            }
+            digit_count += fraction_length;
+        }
+        if digit_count == 0 {
+            self.position = start;
+            return Err(self.unexpected("number"));
+        }
+        if matches!(self.peek(), Some('e' | 'E')) {
+            let exponent_start = self.position;
+            self.position += 1;
+            if matches!(self.peek(), Some('+' | '-')) {
+                self.position += 1;
+            }
+            if self.digits().is_empty() {
+                self.position = exponent_start;
+            }
+        }
+        self.input[start..self.position]
+            .parse()
+            .map_err(|_| self.error_at(start, ParseEasingErrorKind::InvalidArgument("bad number")))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.position..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        let rest = &self.input[self.position..];
+        self.position += rest.len() - rest.trim_start().len();
+    }
+
+    fn unexpected(&self, expected: &'static str) -> ParseEasingError {
+        match self.peek() {
+            Some(c) => self.error(ParseEasingErrorKind::Expected(expected, c)),
+            None => self.error(ParseEasingErrorKind::UnexpectedEnd(expected)),
+        }
+    }
+}
+
+fn is_number_start(c: char) -> bool {
+    c.is_ascii_digit() || matches!(c, '+' | '-' | '.')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::easing::EasingFunction;
+    use approx::assert_relative_eq;
+
+    fn parse(input: &str) -> Easing {
+        input
+            .parse()
+            .unwrap_or_else(|e| panic!("Failed to parse {input:?}: {e}"))
+    }
+
+    fn parse_error(input: &str) -> ParseEasingError {
+        match input.parse::<Easing>() {
+            Ok(easing) => panic!("Expected {input:?} to fail, but parsed {easing:?}"),
+            Err(e) => e,
+        }
+    }
+
+    fn assert_same_curve(actual: &Easing, expected: &dyn EasingFunction) {
+        for step in 0..=20 {
+            let x = step as f32 / 20.0;
+            assert_relative_eq!(actual.calc(x), expected.calc(x), epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn parses_keywords() {
+        assert!(matches!(parse("linear"), Easing::Linear));
+        assert!(matches!(parse("ease"), Easing::Ease));
+        assert!(matches!(parse("ease-in"), Easing::In));
+        assert!(matches!(parse("ease-out"), Easing::Out));
+        assert!(matches!(parse("ease-in-out"), Easing::InOut));
+        assert!(matches!(
+            parse("step-start"),
+            Easing::Steps(1, StepPosition::JumpStart)
+        ));
+        assert!(matches!(
+            parse("step-end"),
+            Easing::Steps(1, StepPosition::JumpEnd)
+        ));
+    }
+
+    #[test]
+    fn keywords_are_case_insensitive_and_ignore_surrounding_whitespace() {
+        assert!(matches!(parse("  Ease-In-Out\t"), Easing::InOut));
+        assert!(matches!(
+            parse("STEPS(2, Jump-Both)"),
+            Easing::Steps(2, StepPosition::JumpBoth)
+        ));
+    }
+
+    #[test]
+    fn parses_cubic_bezier() {
+        let easing = parse("cubic-bezier(.17,.67,.83,.67)");
+        let spaced_easing = parse("cubic-bezier( 0.17 , 6.7e-1, 0.83,+0.67 )");
+
+        let expected = CubicBezierEasing::new(0.17, 0.67, 0.83, 0.67);
+        assert_same_curve(&easing, &expected);
+        assert_same_curve(&spaced_easing, &expected);
+    }
+
+    #[test]
+    fn parses_cubic_bezier_with_overshooting_y() {
+        let easing = parse("cubic-bezier(0.68, -0.6, 0.32, 1.6)");
+
+        assert_same_curve(&easing, &CubicBezierEasing::new(0.68, -0.6, 0.32, 1.6));
+    }
+
+    #[test]
+    fn parses_steps() {
+        assert!(matches!(
+            parse("steps(4)"),
+            Easing::Steps(4, StepPosition::JumpEnd)
+        ));
+        assert!(matches!(
+            parse("steps(4, end)"),
+            Easing::Steps(4, StepPosition::JumpEnd)
+        ));
+        assert!(matches!(
+            parse("steps(4, start)"),
+            Easing::Steps(4, StepPosition::JumpStart)
+        ));
+        assert!(matches!(
+            parse("steps(3,jump-start)"),
+            Easing::Steps(3, StepPosition::JumpStart)
+        ));
+        assert!(matches!(
+            parse("steps(3, jump-end)"),
+            Easing::Steps(3, StepPosition::JumpEnd)
+        ));
+        assert!(matches!(
+            parse("steps(2, jump-none)"),
+            Easing::Steps(2, StepPosition::JumpNone)
+        ));
+        assert!(matches!(
+            parse("steps(5, jump-both)"),
+            Easing::Steps(5, StepPosition::JumpBoth)
+        ));
+    }
+
+    #[test]
+    fn parses_linear_function() {
+        let easing = parse("linear(0, 0.25 75%, 1)");
+
+        assert_same_curve(
+            &easing,
+            &PiecewiseLinearEasing::new([
+                LinearStop::new(0.0),
+                LinearStop::at(0.25, 0.75),
+                LinearStop::new(1.0),
+            ]),
+        );
+    }
+
+    #[test]
+    fn parses_linear_function_with_leading_and_double_percentages() {
+        let easing = parse("linear(0%  0, 0.5 25% 75%, 100% 1)");
+
+        assert_same_curve(
+            &easing,
+            &PiecewiseLinearEasing::new([
+                LinearStop::at(0.0, 0.0),
+                LinearStop::at(0.5, 0.25),
+                LinearStop::at(0.5, 0.75),
+                LinearStop::at(1.0, 1.0),
+            ]),
+        );
+    }
+
+    #[test]
+    fn supports_try_from() {
+        assert!(matches!(Easing::try_from("ease"), Ok(Easing::Ease)));
+        assert!(Easing::try_from("bogus").is_err());
+    }
+
+    #[test]
+    fn when_keyword_unknown_then_reports_keyword_and_position() {
+        let error = parse_error("  bounce");
+
+        assert_eq!(
+            error.kind(),
+            &ParseEasingErrorKind::UnknownKeyword("bounce".to_string())
+        );
+        assert_eq!(error.position(), 2);
+        assert_eq!(
+            error.to_string(),
+            "unknown easing keyword 'bounce' at position 2"
+        );
+    }
+
+    #[test]
+    fn when_function_unknown_then_reports_function_and_position() {
+        let error = parse_error("spring(1, 2)");
+
+        assert_eq!(
+            error.kind(),
+            &ParseEasingErrorKind::UnknownFunction("spring".to_string())
+        );
+        assert_eq!(error.position(), 0);
+    }
+
+    #[test]
+    fn when_argument_missing_then_reports_position_of_unexpected_token() {
+        let error = parse_error("cubic-bezier(0.1, 0.2, 0.3)");
+
+        assert_eq!(error.kind(), &ParseEasingErrorKind::Expected("','", ')'));
+        assert_eq!(error.position(), 26);
+    }
+
+    #[test]
+    fn when_input_ends_early_then_reports_end_position() {
+        let error = parse_error("steps(4, end");
+
+        assert_eq!(error.kind(), &ParseEasingErrorKind::UnexpectedEnd("')'"));
+        assert_eq!(error.position(), 12);
+    }
+
+    #[test]
+    fn when_trailing_characters_then_reports_position() {
+        let error = parse_error("ease-in foo");
+
+        assert_eq!(
+            error.kind(),
+            &ParseEasingErrorKind::Expected("end of input", 'f')
+        );
+        assert_eq!(error.position(), 8);
+    }
+
+    #[test]
+    fn when_cubic_bezier_x_out_of_range_then_fails() {
+        let error = parse_error("cubic-bezier(0.1, 0.2, 1.5, 1)");
+
+        assert!(matches!(
+            error.kind(),
+            ParseEasingErrorKind::InvalidArgument(_)
+        ));
+        assert_eq!(error.position(), 23);
+    }
+
+    #[test]
+    fn when_steps_invalid_then_fails() {
+        assert_eq!(parse_error("steps(0)").position(), 6);
+        assert_eq!(parse_error("steps(1, jump-none)").position(), 6);
+        assert_eq!(parse_error("steps(2.5)").position(), 6);
+        assert_eq!(
+            parse_error("steps(2, middle)").kind(),
+            &ParseEasingErrorKind::UnknownStepPosition("middle".to_string())
+        );
+    }
+
+    #[test]
+    fn when_linear_stop_invalid_then_fails() {
+        assert_eq!(parse_error("linear()").position(), 7);
+        assert_eq!(parse_error("linear(0, 50%, 1)").position(), 10);
+        assert_eq!(parse_error("linear(0, 0.5 0.6, 1)").position(), 14);
+        assert_eq!(parse_error("linear(0, 0.5 10% 20% 30%, 1)").position(), 22);
+    }
+
+    #[test]
+    fn when_linear_has_fewer_than_two_stops_then_fails() {
+        let error = parse_error("linear(0.5)");
+
+        assert_eq!(
+            error.kind(),
+            &ParseEasingErrorKind::InvalidArgument("linear requires at least two stops")
+        );
+        assert_eq!(error.position(), 7);
+        assert_eq!(parse_error("linear(0.5 0% 100%)").position(), 7);
+    }
+}
@@ -1,5 +1,18 @@
-/// Support for the Glam library. Adds [Lerp] trait implementations for vector types.
-use crate::interpolation::Lerp;
+//! Support for the Glam library. Adds [Lerp] trait implementations for vector and quaternion
+//! types, and [VectorSpace] implementations for floating-point vector types.
+//!
+//! Quaternions ([`Quat`] and [`DQuat`]) use spherical linear interpolation (slerp) along the
+//! shortest path, which rotates at a constant angular velocity. Other modes are available by
+//! wrapping the quaternion in [`Nlerp`] or [`Slerp`], either as the field type of an
+//! [`Animate`](crate::timeline::Animate) type, or by keeping the field type as a quaternion and
+//! specifying the wrapper in a `via` attribute, e.g. `#[animate(via = "Nlerp<Quat>")]`.
+//!
+//! Smooth paths through several rotations use [`Squad`], which needs tangents computed from the
+//! neighboring keyframes with [`Squad::spline`], so it must be the field type itself. It is not
+//! useful in a `via` attribute, since a quaternion converted to a `Squad` has no tangent of its
+//! own and interpolates the same as slerp.
+
+use crate::interpolation::{Lerp, VectorSpace};
 use glam::{
     DQuat, DVec2, DVec3, DVec4, I64Vec2, I64Vec3, I64Vec4, IVec2, IVec3, IVec4, Quat, U64Vec2,
     U64Vec3, U64Vec4, UVec2, UVec3, UVec4, Vec2, Vec3, Vec3A, Vec4,
@@ -39,14 +52,299 @@ impl_lerp2! { Vec2, DVec2, IVec2, I64Vec2, UVec2, U64Vec2 }
 impl_lerp3! { Vec3, Vec3A, DVec3, IVec3, I64Vec3, UVec3, U64Vec3 }
 impl_lerp4! { Vec4, DVec4, IVec4, I64Vec4, UVec4, U64Vec4 }
 
+macro_rules! impl_vector_space {
+    ($($t:ty: $scalar:ty),*) => {
+        $( impl VectorSpace for $t {
+            fn scaled_difference(&self, origin: &Self, scale: f32) -> Self {
+                (*self - *origin) * scale as $scalar
+            }
+        }) *
+    }
+}
+
+impl_vector_space! { Vec2: f32, Vec3: f32, Vec3A: f32, Vec4: f32 }
+impl_vector_space! { DVec2: f64, DVec3: f64, DVec4: f64 }
+
 impl Lerp for Quat {
     fn lerp(&self, y1: &Self, x: f32) -> Self {
-        Quat::lerp(*self, *y1, x)
+        Quat::slerp(*self, *y1, x)
     }
 }
 
 impl Lerp for DQuat {
     fn lerp(&self, y1: &Self, x: f32) -> Self {
-        DQuat::lerp(*self, *y1, x as f64)
+        DQuat::slerp(*self, *y1, x as f64)
+    }
+}
+
+/// Quaternion wrapper that interpolates using normalized linear interpolation (nlerp) along the
+/// shortest path.
+///
+/// Nlerp is cheaper than slerp and follows the same path, but the angular velocity is not
+/// constant; rotation is slightly faster in the middle than at either end, and the difference
+/// grows with the angle between the rotations.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Nlerp<T>(pub T);
+
+/// Quaternion wrapper that interpolates using spherical linear interpolation (slerp) along the
+/// shortest path.
+///
+/// This is the same as the default [`Lerp`] implementation for quaternions, and exists to make the
+/// choice explicit.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Slerp<T>(pub T);
+
+/// Quaternion with a tangent, which interpolates using spherical quadrangle interpolation (squad).
+///
+/// Squad produces a rotation path that is smooth across keyframes, as opposed to slerp, which
+/// changes direction abruptly at each keyframe. The tangents depend on the neighboring rotations,
+/// so they are normally computed for an entire sequence of rotations using [`Squad::spline`].
+///
+/// A `Squad` converted from a plain quaternion uses the quaternion as its own tangent, which is
+/// equivalent to slerp.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Squad<T> {
+    /// The rotation at this keyframe.
+    pub rotation: T,
+    /// The control point that shapes the curve entering and leaving this keyframe.
+    pub tangent: T,
+}
+
+impl<T: SquadRotation> Squad<T> {
+    /// Creates a [`Squad`] with the specified `rotation`, using the rotation as its own tangent.
+    pub fn new(rotation: T) -> Self {
+        Self {
+            rotation,
+            tangent: rotation,
+        }
+    }
+
+    /// Computes a smooth spline through a sequence of rotations, returning one [`Squad`] per
+    /// rotation, with its tangent derived from the neighboring rotations.
+    ///
+    /// Signs of the rotations are adjusted so that each one is on the same hemisphere as the
+    /// previous one, which keeps the spline on the shortest path.
+    pub fn spline(rotations: &[T]) -> Vec<Self> {
+        let mut aligned: Vec<T> = Vec::with_capacity(rotations.len());
+        for &rotation in rotations {
+            let rotation = match aligned.last() {
+                Some(&previous) => rotation.align_with(previous),
+                None => rotation,
+            };
+            aligned.push(rotation);
+        }
+        (0..aligned.len())
+            .map(|i| {
+                let previous = aligned[i.saturating_sub(1)];
+                let next = aligned[(i + 1).min(aligned.len() - 1)];
+                Self {
+                    rotation: aligned[i],
+                    tangent: T::tangent(previous, aligned[i], next),
+                }
+            })
+            .collect()
+    }
+}
+
+impl<T: SquadRotation> Lerp for Squad<T> {
+    fn lerp(&self, y1: &Self, x: f32) -> Self {
+        let rotation = self.rotation.slerp_by(y1.rotation, x);
+        let tangent = self.tangent.slerp_by(y1.tangent, x);
+        Self::new(rotation.slerp_by(tangent, 2.0 * x * (1.0 - x)))
+    }
+}
+
+/// Quaternion type that can be interpolated by a [`Squad`].
+pub trait SquadRotation: Copy {
+    /// Computes the spherical linear interpolation from this rotation to the `end` rotation, along
+    /// the shortest path.
+    fn slerp_by(self, end: Self, x: f32) -> Self;
+
+    /// Computes the tangent at the `current` rotation, given the `previous` and `next` rotations.
+    fn tangent(previous: Self, current: Self, next: Self) -> Self;
+
+    /// Gets either this rotation or its negation (which is the same rotation), whichever is on the
+    /// same hemisphere as `other`.
+    fn align_with(self, other: Self) -> Self;
+}
+
+macro_rules! impl_quat_interpolation {
+    ($quat:ty, $scalar:ty) => {
+        impl Lerp for Nlerp<$quat> {
+            fn lerp(&self, y1: &Self, x: f32) -> Self {
+                Self(self.0.lerp(y1.0, x as $scalar))
+            }
+        }
+
+        impl Lerp for Slerp<$quat> {
+            fn lerp(&self, y1: &Self, x: f32) -> Self {
+                Self(self.0.slerp(y1.0, x as $scalar))
+            }
+        }
+
+        impl SquadRotation for $quat {
+            fn slerp_by(self, end: Self, x: f32) -> Self {
+                self.slerp(end, x as $scalar)
+            }
+
+            fn tangent(previous: Self, current: Self, next: Self) -> Self {
+                // The standard formula is q * exp(-(log(q⁻¹p) + log(q⁻¹n)) / 4), where the log of
+                // a unit quaternion is half of its scaled axis.
+                let inverse = current.inverse();
+                let log_sum =
+                    (inverse * previous).to_scaled_axis() + (inverse * next).to_scaled_axis();
+                current * <$quat>::from_scaled_axis(log_sum * -0.25)
+            }
+
+            fn align_with(self, other: Self) -> Self {
+                if self.dot(other) < 0.0 {
+                    -self
+                } else {
+                    self
+                }
+            }
+        }
+
+        impl From<$quat> for Nlerp<$quat> {
+            fn from(rotation: $quat) -> Self {
+                Self(rotation)
+            }
+        }
+
+        impl From<Nlerp<$quat>> for $quat {
+            fn from(rotation: Nlerp<$quat>) -> Self {
+                rotation.0
+            }
+        }
+
+        impl From<$quat> for Slerp<$quat> {
+            fn from(rotation: $quat) -> Self {
+                Self(rotation)
+            }
+        }
+
+        impl From<Slerp<$quat>> for $quat {
+            fn from(rotation: Slerp<$quat>) -> Self {
+                rotation.0
+            }
+        }
+
+        impl From<$quat> for Squad<$quat> {
+            fn from(rotation: $quat) -> Self {
+                Self::new(rotation)
+            }
+        }
+
+        impl From<Squad<$quat>> for $quat {
+            fn from(rotation: Squad<$quat>) -> Self {
+                rotation.rotation
+            }
+        }
+    };
+}
+
+impl_quat_interpolation!(Quat, f32);
+impl_quat_interpolation!(DQuat, f64);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use std::f32::consts::PI;
+
+    const STEPS: usize = 16;
+
+    fn angular_steps<T: Lerp + Copy + Into<Quat>>(y0: T, y1: T) -> Vec<f32> {
+        let samples = (0..=STEPS)
+            .map(|i| y0.lerp(&y1, i as f32 / STEPS as f32).into())
+            .collect::<Vec<Quat>>();
+        samples
+            .windows(2)
+            .map(|pair| pair[0].angle_between(pair[1]))
+            .collect()
+    }
+
+    #[test]
+    fn when_quat_interpolated_then_angular_velocity_is_constant() {
+        let y0 = Quat::from_rotation_z(0.0);
+        let y1 = Quat::from_rotation_z(PI * 0.9);
+
+        for step in angular_steps(y0, y1) {
+            assert_relative_eq!(step, PI * 0.9 / STEPS as f32, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn when_dquat_interpolated_then_angular_velocity_is_constant() {
+        let y0 = DQuat::from_rotation_x(0.2);
+        let y1 = DQuat::from_rotation_x(2.6);
+
+        for i in 0..STEPS {
+            let a = Lerp::lerp(&y0, &y1, i as f32 / STEPS as f32);
+            let b = Lerp::lerp(&y0, &y1, (i + 1) as f32 / STEPS as f32);
+            assert_relative_eq!(a.angle_between(b), 2.4 / STEPS as f64, epsilon = 1e-3);
+        }
+    }
+
+    #[test]
+    fn when_nlerp_then_angular_velocity_is_faster_in_middle() {
+        let y0 = Nlerp(Quat::from_rotation_y(0.0));
+        let y1 = Nlerp(Quat::from_rotation_y(PI * 0.9));
+
+        let steps = angular_steps(y0, y1);
+
+        assert!(steps[STEPS / 2] > steps[0] * 1.2);
+    }
+
+    #[test]
+    fn when_rotations_on_opposite_hemispheres_then_takes_shortest_path() {
+        let y0 = Quat::from_rotation_z(0.0);
+        let y1 = -Quat::from_rotation_z(PI * 0.5);
+
+        let mid = Lerp::lerp(&y0, &y1, 0.5);
+        let nlerp_mid: Quat = Nlerp(y0).lerp(&Nlerp(y1), 0.5).into();
+
+        assert_relative_eq!(mid.angle_between(y0), PI * 0.25, epsilon = 1e-3);
+        assert_relative_eq!(nlerp_mid.angle_between(y0), PI * 0.25, epsilon = 1e-3);
+    }
+
+    #[test]
+    fn when_squad_without_tangents_then_matches_slerp() {
+        let y0 = Quat::from_rotation_x(0.3);
+        let y1 = Quat::from_rotation_y(1.2);
+
+        let squad: Quat = Squad::new(y0).lerp(&Squad::new(y1), 0.3).into();
+
+        assert!(squad.abs_diff_eq(y0.slerp(y1, 0.3), 1e-5));
+    }
+
+    #[test]
+    fn when_squad_spline_then_passes_through_keyframes_smoothly() {
+        let rotations = [
+            Quat::from_rotation_z(0.0),
+            Quat::from_rotation_z(1.0),
+            Quat::from_rotation_x(1.0) * Quat::from_rotation_z(2.0),
+        ];
+        let spline = Squad::spline(&rotations);
+
+        assert!(spline[0]
+            .lerp(&spline[1], 0.0)
+            .rotation
+            .abs_diff_eq(rotations[0], 1e-5));
+        assert!(spline[0]
+            .lerp(&spline[1], 1.0)
+            .rotation
+            .abs_diff_eq(rotations[1], 1e-5));
+        // Angular velocity should be continuous through the middle keyframe.
+        let h = 1e-3;
+        let before = spline[0]
+            .lerp(&spline[1], 1.0 - h)
+            .rotation
+            .angle_between(rotations[1]);
+        let after = spline[1]
+            .lerp(&spline[2], h)
+            .rotation
+            .angle_between(rotations[1]);
+        assert_relative_eq!(before, after, epsilon = 1e-4);
     }
 }
@@ -1,6 +1,7 @@
 //! Traits and implementations related to interpolation of animatable values.
 
 use num_traits::FromPrimitive;
+use std::marker::PhantomData;
 
 /// Trait for a type that supports the standard `lerp` (**l**inear int**erp**olation) operation.
 ///
@@ -16,15 +17,15 @@ use num_traits::FromPrimitive;
 /// with a type that is narrower (e.g. `u32`) **or** wider (`f64`). For any other type that is
 /// composed entirely of numeric values, the trait can be implemented by `lerp`ing all of the
 /// individual values.
+///
+/// Integer results are rounded to the nearest integer, and results outside the range of the
+/// integer type, e.g. when an easing such as [`Easing::OutBack`](crate::easing::Easing::OutBack)
+/// overshoots, are clamped to the nearest bound. To wrap around or panic instead, use the
+/// [`Integer`] wrapper with a different [`RangePolicy`].
 pub trait Lerp {
     /// Computes the linear interpolation between this value (`y0`) and a second (`y1`) value of the
     /// same type, at normalized (from 0 to 1) position `x`.
     ///
-    /// # Panics
-    ///
-    /// The default implementation for primitives will panic if `self` or `y1` are too large to fit
-    /// in an `f32`, or if the resulting interpolated value is out of bounds for the `y` type.
-    ///
     /// # Example
     ///
     /// ```
@@ -41,6 +42,31 @@ pub trait Lerp {
     fn lerp(&self, y1: &Self, x: f32) -> Self;
 }
 
+/// Trait for a [`Lerp`] type whose values form a vector space, so that the rate at which a value
+/// changes can itself be expressed as a value of the same type.
+///
+/// This is required for an animated property to report its velocity, e.g. with
+/// [`Timeline::update_velocity`](crate::timeline::Timeline::update_velocity). It is implemented for
+/// the floating-point types, the [angle](crate::angle) types, and the floating-point vector types
+/// of the `glam` feature.
+///
+/// Integer types do not implement this trait, since they cannot represent fractional velocities or,
+/// if unsigned, negative velocities. Neither do quaternions and colors, since the difference
+/// between two quaternions or two colors is not a meaningful quaternion or color.
+pub trait VectorSpace: Lerp {
+    /// Computes the difference between this value and the `origin` value, multiplied by `scale`,
+    /// i.e. `(self - origin) * scale`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mina_core::interpolation::VectorSpace;
+    ///
+    /// assert_eq!(15.0f32.scaled_difference(&5.0, 0.5), 5.0);
+    /// ```
+    fn scaled_difference(&self, origin: &Self, scale: f32) -> Self;
+}
+
 // There are (roughly) two ways to represent the "lerp equation":
 //
 // 1. a + t(b - a)
@@ -53,18 +79,112 @@ pub trait Lerp {
 
 macro_rules! impl_lerp_for_integer_types {
     ($($t:ty),*) => {
-        $( impl Lerp for $t {
-            fn lerp(&self, y1: &Self, x: f32) -> Self {
-                let result_f32 = (*self as f32).lerp(&(*y1 as f32), x);
-                Self::from_f32(result_f32.round())
-                    .expect("Converted value was outside the valid range for this type.")
+        $(
+            impl Lerp for $t {
+                fn lerp(&self, y1: &Self, x: f32) -> Self {
+                    Saturate::convert(lerp_integer_f32(*self as f32, *y1 as f32, x))
+                }
+            }
+
+            impl RangePolicy<$t> for Saturate {
+                fn convert(value: f32) -> $t {
+                    // Float-to-integer casts saturate, and convert NaN to zero.
+                    value as $t
+                }
+            }
+
+            impl RangePolicy<$t> for Wrap {
+                fn convert(value: f32) -> $t {
+                    // Every supported integer type fits in an i128, and narrowing casts wrap.
+                    value as i128 as $t
+                }
+            }
+
+            impl RangePolicy<$t> for Panic {
+                fn convert(value: f32) -> $t {
+                    <$t>::from_f32(value)
+                        .expect("Converted value was outside the valid range for this type.")
+                }
+            }
+
+            impl<P: RangePolicy<$t>> Lerp for Integer<$t, P> {
+                fn lerp(&self, y1: &Self, x: f32) -> Self {
+                    Self::new(P::convert(lerp_integer_f32(self.value as f32, y1.value as f32, x)))
+                }
+            }
+
+            impl<P> From<Integer<$t, P>> for $t {
+                fn from(integer: Integer<$t, P>) -> Self {
+                    integer.value
+                }
             }
-        }) *
+        )*
     }
 }
 
 impl_lerp_for_integer_types! { i8, i16, i32, i64, u8, u16, u32, u64, usize }
 
+fn lerp_integer_f32(y0: f32, y1: f32, x: f32) -> f32 {
+    y0.lerp(&y1, x).round()
+}
+
+/// Policy for converting an interpolated value to an integer type `T` when the value may be
+/// outside the range of `T`.
+///
+/// Used by the [`Integer`] wrapper type. The `value` is always rounded to the nearest integer
+/// before conversion.
+pub trait RangePolicy<T> {
+    /// Converts the rounded interpolated `value` to the integer type.
+    fn convert(value: f32) -> T;
+}
+
+/// Clamps out-of-range values to the nearest bound of the integer type, e.g. `-10.0` becomes `0`
+/// for a `u8`. This is the default behavior of [`Lerp`] for integer types.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Saturate;
+
+/// Wraps out-of-range values around the bounds of the integer type, using two's complement
+/// arithmetic, e.g. `-10.0` becomes `246` for a `u8`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Wrap;
+
+/// Panics if the value is outside the range of the integer type.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Panic;
+
+/// Integer wrapper that handles interpolated values outside the range of the integer type according
+/// to the [`RangePolicy`] `P`.
+///
+/// Can be converted to and from the integer type, which allows an integer field of an
+/// [`Animate`](crate::timeline::Animate) type to use a different policy by specifying the wrapper
+/// in a `via` attribute, e.g. `#[animate(via = "Integer<u8, Wrap>")]`.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, PartialOrd)]
+pub struct Integer<T, P = Saturate> {
+    value: T,
+    policy: PhantomData<P>,
+}
+
+impl<T, P> Integer<T, P> {
+    /// Creates a new [`Integer`] with the specified value.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            policy: PhantomData,
+        }
+    }
+
+    /// Gets the integer value.
+    pub fn value(self) -> T {
+        self.value
+    }
+}
+
+impl<T, P> From<T> for Integer<T, P> {
+    fn from(value: T) -> Self {
+        Self::new(value)
+    }
+}
+
 impl Lerp for f32 {
     fn lerp(&self, y1: &Self, x: f32) -> Self {
         self * (1.0 - x) + y1 * x
@@ -80,6 +200,37 @@ impl Lerp for f64 {
     }
 }
 
+impl VectorSpace for f32 {
+    fn scaled_difference(&self, origin: &Self, scale: f32) -> Self {
+        (self - origin) * scale
+    }
+}
+
+impl VectorSpace for f64 {
+    fn scaled_difference(&self, origin: &Self, scale: f32) -> Self {
+        (self - origin) * scale as f64
+    }
+}
+
+/// Computes the weighted combination of three values using only [`Lerp`] operations.
+///
+/// The `weights` must add up to `1.0`, but individual weights may be negative or greater than
+/// `1.0`, i.e. the result is not required to lie between the values.
+pub(crate) fn lerp_weighted<T: Lerp>(values: [&T; 3], weights: [f32; 3]) -> T {
+    // The combination is computed as two nested lerps, where the inner lerp combines two of the
+    // values and is rescaled by the sum of their weights. Since all three weights add up to 1, the
+    // weight farthest from 1 is always at least 2/3 away from it; choosing that value for the outer
+    // lerp keeps the rescaling well-conditioned.
+    let distance_from_one = |index: usize| (1.0 - weights[index]).abs();
+    let outer = (0..3)
+        .max_by(|&a, &b| distance_from_one(a).total_cmp(&distance_from_one(b)))
+        .unwrap();
+    let (first, second) = ((outer + 1) % 3, (outer + 2) % 3);
+    let inner_weight = weights[first] + weights[second];
+    let inner = values[first].lerp(values[second], weights[second] / inner_weight);
+    inner.lerp(values[outer], weights[outer])
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -128,6 +279,37 @@ mod tests {
         test_lerp(0.5, 0.5, 1.0, 0.5);
     }
 
+    #[test]
+    fn lerp_weighted_combines_values() {
+        assert_relative_eq!(lerp_weighted([&1.0, &2.0, &4.0], [0.5, 0.25, 0.25]), 2.0f32);
+        assert_relative_eq!(lerp_weighted([&1.0, &2.0, &4.0], [1.0, 0.0, 0.0]), 1.0f32);
+        assert_relative_eq!(lerp_weighted([&1.0, &2.0, &4.0], [-2.0, 1.5, 1.5]), 7.0f32);
+        assert_eq!(lerp_weighted([&10u8, &40, &20], [0.5, 1.0, -0.5]), 35u8);
+    }
+
+    #[test]
+    fn when_integer_result_out_of_range_then_saturates() {
+        test_lerp(10, 250, 1.2, 255u8);
+        test_lerp(10, 250, -0.2, 0u8);
+        test_lerp(-100, 100, 1.5, 127i8);
+    }
+
+    #[test]
+    fn when_integer_policy_is_wrap_then_wraps_around() {
+        let y0 = Integer::<u8, Wrap>::new(10);
+        let y1 = Integer::new(250);
+
+        assert_eq!(y0.lerp(&y1, 1.2).value(), 42);
+        assert_eq!(y0.lerp(&y1, -0.2).value(), 218);
+        assert_eq!(y0.lerp(&y1, 0.5).value(), 130);
+    }
+
+    #[test]
+    #[should_panic(expected = "outside the valid range")]
+    fn when_integer_policy_is_panic_then_panics_out_of_range() {
+        Integer::<u8, Panic>::new(10).lerp(&Integer::new(250), 1.2);
+    }
+
     fn test_lerp<V: Debug + Lerp + PartialEq>(from: V, to: V, t: f32, expected: V) {
         assert_eq!(from.lerp(&to, t), expected);
     }
@@ -3,11 +3,21 @@
 //! This is an internal crate that exists primarily to support Mina's proc macros, and should not be
 //! used directly. All important types are re-exported by Mina.
 
+pub mod angle;
 pub mod animator;
+pub mod bake;
+#[cfg(feature = "color")]
+pub mod color;
 pub mod easing;
 #[cfg(feature = "glam")]
 pub mod glam;
 pub mod interpolation;
+pub mod marker;
+pub mod player;
+pub mod spring;
+pub mod stagger;
+#[cfg(test)]
+mod test_timelines;
 pub mod time_scale;
 pub mod timeline;
 pub mod timeline_helpers;
@@ -0,0 +1,92 @@
+//! Named cue points that can be placed on a [`Timeline`](crate::timeline::Timeline).
+
+/// A named point on a timeline, used to trigger side effects such as sounds or particles when the
+/// animation reaches a specific position.
+///
+/// Markers are added to a timeline using
+/// [`TimelineConfigurationBuilder::marker`](crate::timeline::TimelineConfigurationBuilder::marker)
+/// and do not affect the animated values in any way. To find out which markers were reached
+/// between two updates, use
+/// [`Timeline::markers_between`](crate::timeline::Timeline::markers_between).
+#[derive(Clone, Debug, PartialEq)]
+pub struct Marker {
+    name: String,
+    position: MarkerPosition,
+}
+
+impl Marker {
+    /// Creates a new [`Marker`] with the specified `name` and `position`.
+    pub fn new(name: impl Into<String>, position: MarkerPosition) -> Self {
+        Self {
+            name: name.into(),
+            position,
+        }
+    }
+
+    /// Creates a [`Marker`] at a normalized position, using the same scale as keyframes, i.e.
+    /// `0.0` for the 0% keyframe and `1.0` for the 100% keyframe.
+    pub fn at(name: impl Into<String>, normalized_time: f32) -> Self {
+        Self::new(name, MarkerPosition::Normalized(normalized_time))
+    }
+
+    /// Creates a [`Marker`] at a fixed time, in seconds, after the start of each cycle.
+    pub fn at_seconds(name: impl Into<String>, seconds: f32) -> Self {
+        Self::new(name, MarkerPosition::Seconds(seconds))
+    }
+
+    /// Gets the name of this marker.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Gets the position of this marker.
+    pub fn position(&self) -> MarkerPosition {
+        self.position
+    }
+}
+
+/// Specifies where a [`Marker`] is placed on a timeline.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum MarkerPosition {
+    /// Position on the keyframe scale, from `0.0` (0%) to `1.0` (100%).
+    ///
+    /// The marker is crossed whenever the animation passes through this position, regardless of
+    /// direction. For example, a marker at `0.5` on a timeline that
+    /// [reverses](crate::timeline::TimelineConfigurationBuilder::reverse) is crossed twice per
+    /// cycle, once on the way to the 100% keyframe and once more on the way back.
+    Normalized(f32),
+    /// Elapsed time, in seconds, since the start of the cycle, not including any delay.
+    ///
+    /// The marker is crossed exactly once per cycle, at the same time in every cycle, regardless
+    /// of the direction in which the cycle plays. Markers outside the cycle duration are ignored.
+    Seconds(f32),
+}
+
+/// Describes a [`Marker`] that was crossed during a
+/// [`markers_between`](crate::timeline::Timeline::markers_between) query.
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarkerCrossing {
+    /// Name of the crossed marker.
+    pub name: String,
+    /// Time at which the marker was crossed, in the same units and scale as the time given to
+    /// [`Timeline::update`](crate::timeline::Timeline::update), i.e. including any delay.
+    pub time: f32,
+    /// Zero-based index of the cycle in which the marker was crossed. For a
+    /// [`SequenceTimeline`](crate::timeline::SequenceTimeline), this is the cycle of the entire
+    /// sequence, not of the individual stage.
+    pub cycle: u32,
+}
+
+/// Maximum number of [`MarkerCrossing`]s that a [`StateAnimator`](crate::animator::StateAnimator)
+/// or [`TimelinePlayer`](crate::player::TimelinePlayer) keeps until they are taken. If more markers
+/// are crossed in the meantime, the oldest crossings are discarded.
+pub const MAX_BUFFERED_CROSSINGS: usize = 256;
+
+/// Appends new `crossings` to a `buffer` of crossings that have not been taken yet, discarding the
+/// oldest crossings beyond [`MAX_BUFFERED_CROSSINGS`].
+pub(crate) fn buffer_crossings(buffer: &mut Vec<MarkerCrossing>, crossings: Vec<MarkerCrossing>) {
+    buffer.extend(crossings);
+    if let Some(excess) = buffer.len().checked_sub(MAX_BUFFERED_CROSSINGS) {
+        buffer.drain(..excess);
+    }
+}
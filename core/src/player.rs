@@ -0,0 +1,389 @@
+//! Stateful playback of a single [`Timeline`], independent of any particular framework.
+
+use crate::marker::{buffer_crossings, MarkerCrossing};
+use crate::timeline::Timeline;
+
+/// The playback state of a [`TimelinePlayer`].
+///
+/// With the `bevy_reflect` feature, this also implements Bevy's `Reflect` trait, so that it can be
+/// part of reflected components and events.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(feature = "bevy_reflect", derive(bevy_reflect::Reflect))]
+pub enum PlayerState {
+    /// Animation has not started yet because of a configured [delay](Timeline::delay), and will
+    /// start after the delay elapses.
+    #[default]
+    Waiting,
+    /// Animation is in progress.
+    Playing,
+    /// Animation reached the end of the [`Timeline`], or the beginning if playing in reverse, and
+    /// there are no more frames to animate. When playing forward, this state is only possible for
+    /// timelines whose [repeat](Timeline::repeat) is not
+    /// [Infinite](crate::timeline::Repeat::Infinite).
+    Ended,
+}
+
+impl PlayerState {
+    /// Determines the playback state at a `position` on a `timeline`, in the same units as the
+    /// timeline's duration.
+    ///
+    /// When playing forward, the animation is waiting until the end of the timeline's delay, and
+    /// ends at the end of its duration. When playing in `reverse`, it ends when it arrives back at
+    /// the end of the delay.
+    ///
+    /// This is the state reported by a [`TimelinePlayer`], and can also be used by integrations
+    /// that keep track of the position themselves.
+    pub fn at(timeline: &(impl Timeline + ?Sized), position: f32, reverse: bool) -> Self {
+        let delay = timeline.delay();
+        if reverse {
+            if position <= delay {
+                PlayerState::Ended
+            } else {
+                PlayerState::Playing
+            }
+        } else if position >= timeline.duration() {
+            PlayerState::Ended
+        } else if position < delay {
+            PlayerState::Waiting
+        } else {
+            PlayerState::Playing
+        }
+    }
+}
+
+/// Plays a single [`Timeline`] and keeps track of its position and current values.
+///
+/// Timelines themselves are stateless, and require the caller to keep track of the elapsed time.
+/// A `TimelinePlayer` owns both the timeline and the values that it animates, and only needs to be
+/// [advanced](Self::advance) by the time elapsed since the previous frame. It also supports typical
+/// media controls: pausing, seeking, changing speed and reversing.
+///
+/// For state-dependent animations with blending between timelines, use a
+/// [`StateAnimator`](crate::animator::StateAnimator) instead.
+pub struct TimelinePlayer<T: Timeline> {
+    crossed_markers: Vec<MarkerCrossing>,
+    is_paused: bool,
+    marker_position: f32,
+    position: f32,
+    reported_state: PlayerState,
+    speed: f32,
+    state: PlayerState,
+    timeline: T,
+    values: T::Target,
+}
+
+impl<T: Timeline> TimelinePlayer<T> {
+    /// Creates a [`TimelinePlayer`] that plays the specified `timeline` from the beginning.
+    ///
+    /// The player starts out playing, i.e. not paused, and the `values` are immediately updated to
+    /// the start of the timeline. Values of properties that the timeline does not animate are kept
+    /// as they are.
+    pub fn new(timeline: T, values: T::Target) -> Self {
+        let mut player = Self {
+            crossed_markers: Vec::new(),
+            is_paused: false,
+            marker_position: f32::NEG_INFINITY,
+            position: 0.0,
+            reported_state: PlayerState::Waiting,
+            speed: 1.0,
+            state: PlayerState::Waiting,
+            timeline,
+            values,
+        };
+        player.refresh();
+        player.reported_state = player.state;
+        player
+    }
+
+    /// Advances the player by `elapsed_seconds` (time since the most recent update), scaled by the
+    /// current [speed](Self::speed).
+    ///
+    /// If paused, the position does not change.
+    ///
+    /// # Returns
+    ///
+    /// The new [`PlayerState`] if it changed since the previous call to `advance`, including any
+    /// change caused by other methods such as [`seek`](Self::seek) or [`reverse`](Self::reverse),
+    /// or [`None`] if the state is the same as it was.
+    pub fn advance(&mut self, elapsed_seconds: f32) -> Option<PlayerState> {
+        if !self.is_paused {
+            self.position = self.clamp_position(self.position + elapsed_seconds * self.speed);
+            buffer_crossings(
+                &mut self.crossed_markers,
+                self.timeline
+                    .markers_between(self.marker_position, self.position),
+            );
+            self.marker_position = self.position;
+            self.refresh();
+        }
+        (self.state != self.reported_state).then(|| {
+            self.reported_state = self.state;
+            self.state
+        })
+    }
+
+    /// Checks whether the player is paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Pauses the player, so that [`advance`](Self::advance) has no effect until resumed with
+    /// [`play`](Self::play).
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+    }
+
+    /// Resumes playback after a [`pause`](Self::pause).
+    ///
+    /// This does not restart an animation that has [ended](PlayerState::Ended); to replay it, use
+    /// [`restart`](Self::restart).
+    pub fn play(&mut self) {
+        self.is_paused = false;
+    }
+
+    /// Gets the current position on the timeline, in the same units as the timeline's duration.
+    pub fn position(&self) -> f32 {
+        self.position
+    }
+
+    /// Starts playing the timeline again from the beginning, or from the end if the player is
+    /// playing in reverse, and resumes playback if paused.
+    ///
+    /// Timelines that repeat infinitely have no end, so they restart from the beginning regardless
+    /// of direction.
+    pub fn restart(&mut self) {
+        let duration = self.timeline.duration();
+        (self.position, self.marker_position) = if self.speed < 0.0 && duration.is_finite() {
+            (duration, f32::INFINITY)
+        } else {
+            (0.0, f32::NEG_INFINITY)
+        };
+        self.is_paused = false;
+        self.refresh();
+    }
+
+    /// Reverses the direction of playback, keeping the same magnitude of [speed](Self::speed).
+    ///
+    /// If the animation has already ended, then reversing it starts playing it back toward the
+    /// other end.
+    pub fn reverse(&mut self) {
+        self.set_speed(-self.speed);
+    }
+
+    /// Moves to a specific `position` on the timeline and updates the values to match.
+    ///
+    /// The position is limited to the range between `0.0` and the timeline's duration. Markers
+    /// between the previous and new position are not reported, since they were skipped rather than
+    /// crossed.
+    pub fn seek(&mut self, position: f32) {
+        self.position = self.clamp_position(position);
+        self.marker_position = self.position;
+        self.refresh();
+    }
+
+    /// Changes the playback rate, where `1.0` is the normal speed and negative values play the
+    /// timeline in reverse.
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+        self.refresh();
+    }
+
+    /// Gets the playback rate.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Gets the current state of the player.
+    pub fn state(&self) -> PlayerState {
+        self.state
+    }
+
+    /// Removes and returns all markers crossed since the previous call to `take_markers`.
+    ///
+    /// Crossings are accumulated by every call to [`advance`](Self::advance), in the order they
+    /// were crossed. After the player is created or [restarted](Self::restart), markers exactly at
+    /// the starting position are included in the next `advance`. At most
+    /// [`MAX_BUFFERED_CROSSINGS`](crate::marker::MAX_BUFFERED_CROSSINGS) crossings are buffered;
+    /// if more are crossed before they are taken, the oldest are discarded.
+    pub fn take_markers(&mut self) -> Vec<MarkerCrossing> {
+        std::mem::take(&mut self.crossed_markers)
+    }
+
+    /// Gets a reference to the timeline being played.
+    pub fn timeline(&self) -> &T {
+        &self.timeline
+    }
+
+    /// Gets a reference to the current values.
+    pub fn values(&self) -> &T::Target {
+        &self.values
+    }
+
+    fn clamp_position(&self, position: f32) -> f32 {
+        // Not `clamp`, which panics if the duration is NaN; the position is then only limited to
+        // the start.
+        position.min(self.timeline.duration()).max(0.0)
+    }
+
+    fn refresh(&mut self) {
+        self.timeline.update(&mut self.values, self.position);
+        self.state = PlayerState::at(&self.timeline, self.position, self.speed < 0.0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::marker::MAX_BUFFERED_CROSSINGS;
+    use crate::test_timelines::LinearTimeline;
+
+    fn player(delay: f32) -> TimelinePlayer<LinearTimeline> {
+        TimelinePlayer::new(LinearTimeline::new(delay).with_marker(delay + 0.5), -1.0)
+    }
+
+    #[test]
+    fn when_created_then_values_are_at_start() {
+        let player = player(0.0);
+
+        assert_eq!(*player.values(), 0.0);
+        assert_eq!(player.position(), 0.0);
+        assert_eq!(player.state(), PlayerState::Playing);
+        assert!(!player.is_paused());
+    }
+
+    #[test]
+    fn when_advanced_then_reports_state_transitions() {
+        let mut player = player(0.5);
+
+        assert_eq!(player.state(), PlayerState::Waiting);
+        assert_eq!(player.advance(0.25), None);
+        assert_eq!(player.advance(0.5), Some(PlayerState::Playing));
+        assert_eq!(*player.values(), 0.25);
+        assert_eq!(player.advance(0.5), None);
+        assert_eq!(player.advance(0.5), Some(PlayerState::Ended));
+        assert_eq!(*player.values(), 1.0);
+        assert_eq!(player.position(), 1.5);
+        assert_eq!(player.advance(0.5), None);
+    }
+
+    #[test]
+    fn when_paused_then_does_not_advance_until_played() {
+        let mut player = player(0.0);
+
+        player.advance(0.25);
+        player.pause();
+        player.advance(0.25);
+        let paused_value = *player.values();
+        player.play();
+        player.advance(0.25);
+
+        assert_eq!(paused_value, 0.25);
+        assert_eq!(*player.values(), 0.5);
+    }
+
+    #[test]
+    fn when_speed_changed_then_advances_at_scaled_rate() {
+        let mut player = player(0.0);
+
+        player.set_speed(0.5);
+        player.advance(0.5);
+
+        assert_eq!(player.speed(), 0.5);
+        assert_eq!(*player.values(), 0.25);
+    }
+
+    #[test]
+    fn when_reversed_then_plays_backward_and_ends_at_start() {
+        let mut player = player(0.0);
+
+        player.advance(2.0);
+        player.reverse();
+        assert_eq!(player.advance(0.0), Some(PlayerState::Playing));
+        player.advance(0.25);
+        assert_eq!(*player.values(), 0.75);
+        assert_eq!(player.advance(1.0), Some(PlayerState::Ended));
+        assert_eq!(*player.values(), 0.0);
+        assert_eq!(player.speed(), -1.0);
+    }
+
+    #[test]
+    fn when_seeking_then_updates_values_and_state() {
+        let mut player = player(0.5);
+
+        player.seek(1.0);
+        assert_eq!(*player.values(), 0.5);
+        assert_eq!(player.state(), PlayerState::Playing);
+        player.seek(5.0);
+        assert_eq!(player.position(), 1.5);
+        assert_eq!(player.advance(0.0), Some(PlayerState::Ended));
+        assert!(player.take_markers().is_empty());
+    }
+
+    #[test]
+    fn when_restarted_then_plays_from_start_of_current_direction() {
+        let mut player = player(0.0);
+
+        player.advance(2.0);
+        player.pause();
+        player.restart();
+        assert_eq!(player.position(), 0.0);
+        assert!(!player.is_paused());
+        player.reverse();
+        player.restart();
+        assert_eq!(player.position(), 1.0);
+        assert_eq!(player.state(), PlayerState::Playing);
+    }
+
+    #[test]
+    fn when_marker_crossed_then_buffers_marker() {
+        let timeline = LinearTimeline::new(0.0).with_marker(0.0);
+        let mut player = TimelinePlayer::new(timeline, -1.0);
+
+        player.advance(0.25);
+        let markers_first = player.take_markers();
+        player.advance(0.25);
+        let markers_second = player.take_markers();
+        player.restart();
+        player.advance(0.25);
+        let markers_restarted = player.take_markers();
+
+        assert_eq!(markers_first.len(), 1);
+        assert_eq!(markers_first[0].name, "marker");
+        assert!(markers_second.is_empty());
+        assert_eq!(markers_restarted.len(), 1);
+    }
+
+    #[test]
+    fn when_markers_not_taken_then_keeps_only_most_recent() {
+        let timeline = LinearTimeline::new(0.0).with_marker(0.0);
+        let mut player = TimelinePlayer::new(timeline, -1.0);
+
+        for _ in 0..MAX_BUFFERED_CROSSINGS + 10 {
+            player.restart();
+            player.advance(0.25);
+        }
+
+        assert_eq!(player.take_markers().len(), MAX_BUFFERED_CROSSINGS);
+    }
+
+    #[test]
+    fn when_duration_is_nan_then_position_is_only_limited_to_start() {
+        let mut player = TimelinePlayer::new(LinearTimeline::new(f32::NAN), -1.0);
+
+        player.advance(0.5);
+        assert_eq!(player.position(), 0.5);
+        player.seek(-1.0);
+        assert_eq!(player.position(), 0.0);
+    }
+
+    #[test]
+    fn when_state_at_position_then_depends_on_direction() {
+        let timeline = LinearTimeline::new(1.0);
+
+        assert_eq!(PlayerState::at(&timeline, 0.5, false), PlayerState::Waiting);
+        assert_eq!(PlayerState::at(&timeline, 1.5, false), PlayerState::Playing);
+        assert_eq!(PlayerState::at(&timeline, 2.0, false), PlayerState::Ended);
+        assert_eq!(PlayerState::at(&timeline, 1.5, true), PlayerState::Playing);
+        assert_eq!(PlayerState::at(&timeline, 1.0, true), PlayerState::Ended);
+    }
+}
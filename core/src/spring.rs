@@ -0,0 +1,397 @@
+//! Physically-based spring motion, usable as a [`Timeline`](crate::timeline::Timeline) easing.
+
+use crate::easing::EasingFunction;
+
+/// Longest time that will be considered when computing the [`Spring::settle_duration`]. Springs
+/// that would take longer than this to settle are cut off.
+const MAX_SETTLE_DURATION: f32 = 60.0;
+
+/// Time step used to search for the [`Spring::settle_duration`].
+const SETTLE_SEARCH_STEP: f32 = 1.0 / 240.0;
+
+/// Smallest value allowed for the stiffness, mass and settle threshold of a [`Spring`], which must
+/// all be positive for the motion to be defined.
+const MIN_POSITIVE_VALUE: f32 = 1e-6;
+
+/// Describes the physical properties of a damped spring.
+///
+/// Springs are an alternative to fixed-duration [easing](crate::easing::Easing) curves that tend to
+/// feel more natural in interactive interfaces. Instead of specifying a duration and a curve, the
+/// motion is determined by a simulated mass attached to a spring, which is released from the start
+/// value and oscillates (or creeps, if sufficiently damped) toward the end value. The duration is
+/// derived from the time it takes for the motion to _settle_, i.e. come close enough to rest at
+/// the end value that any further motion would not be noticeable.
+///
+/// All calculations are performed on normalized progress, where `0.0` is the start value and `1.0`
+/// is the end value, which allows springs to animate any [`Lerp`](crate::interpolation::Lerp)
+/// property. To use a spring in a timeline, pass it to
+/// [`TimelineConfigurationBuilder::spring`](crate::timeline::TimelineConfigurationBuilder::spring).
+///
+/// Spring timelines also preserve velocity when blended, e.g. when a
+/// [`StateAnimator`](crate::animator::StateAnimator) changes state while a previous animation is
+/// still moving; see
+/// [`Timeline::start_with_velocity`](crate::timeline::Timeline::start_with_velocity).
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spring {
+    damping: f32,
+    initial_velocity: f32,
+    mass: f32,
+    settle_threshold: f32,
+    stiffness: f32,
+}
+
+impl Default for Spring {
+    fn default() -> Self {
+        Self {
+            damping: 10.0,
+            initial_velocity: 0.0,
+            mass: 1.0,
+            settle_threshold: 0.001,
+            stiffness: 100.0,
+        }
+    }
+}
+
+impl Spring {
+    /// Creates a new [`Spring`] with the specified stiffness and damping, and default values for
+    /// all other properties.
+    ///
+    /// Out-of-range values are clamped in the same way as by the [`stiffness`](Self::stiffness)
+    /// and [`damping`](Self::damping) methods.
+    pub fn new(stiffness: f32, damping: f32) -> Self {
+        Self::default().stiffness(stiffness).damping(damping)
+    }
+
+    /// Configures the damping coefficient, i.e. the amount of friction slowing down the motion.
+    ///
+    /// Lower values cause the spring to oscillate (bounce) more, and for longer. A spring with a
+    /// damping of exactly `2 * sqrt(stiffness * mass)` is _critically damped_ and will reach its
+    /// end value as quickly as possible without overshooting; higher values than this will
+    /// approach the end value more slowly, also without overshooting.
+    ///
+    /// Negative damping would add energy to the spring, so negative values are treated as `0.0`.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping.max(0.0);
+        self
+    }
+
+    /// Configures the initial velocity, in normalized units per second, i.e. a value of `1.0`
+    /// would cover the entire distance from start to end in one second if the spring had no other
+    /// forces acting on it. Values that are not finite are treated as `0.0`.
+    pub fn initial_velocity(mut self, initial_velocity: f32) -> Self {
+        self.initial_velocity = if initial_velocity.is_finite() {
+            initial_velocity
+        } else {
+            0.0
+        };
+        self
+    }
+
+    /// Configures the mass attached to the spring. Higher masses have more inertia, and therefore
+    /// accelerate more slowly and oscillate for longer.
+    ///
+    /// The mass must be positive; zero, negative or NaN values are clamped to a small positive
+    /// value.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass.max(MIN_POSITIVE_VALUE);
+        self
+    }
+
+    /// Configures the threshold at which the spring is considered to be at rest, in normalized
+    /// units. The motion is settled when both the distance from the end value and the velocity
+    /// (per second) remain smaller than this threshold.
+    ///
+    /// The threshold must be positive; zero, negative or NaN values are clamped to a small positive
+    /// value.
+    pub fn settle_threshold(mut self, settle_threshold: f32) -> Self {
+        self.settle_threshold = settle_threshold.max(MIN_POSITIVE_VALUE);
+        self
+    }
+
+    /// Configures the stiffness of the spring. Stiffer springs move faster and settle sooner.
+    ///
+    /// The stiffness must be positive; zero, negative or NaN values are clamped to a small positive
+    /// value.
+    pub fn stiffness(mut self, stiffness: f32) -> Self {
+        self.stiffness = stiffness.max(MIN_POSITIVE_VALUE);
+        self
+    }
+
+    /// Creates a [`SpringEasing`] for this spring, which can be used as a timeline's easing.
+    pub fn easing(&self) -> SpringEasing {
+        SpringEasing {
+            spring: *self,
+            duration: self.settle_duration(),
+        }
+    }
+
+    /// Gets the normalized position of the spring at the given `time` (in seconds) after release.
+    ///
+    /// Starts at `0.0` and ends close to `1.0`, but may overshoot `1.0` or, with a negative initial
+    /// velocity, dip below `0.0`.
+    pub fn position(&self, time: f32) -> f32 {
+        1.0 + self.solve(-1.0, self.initial_velocity, time).0
+    }
+
+    /// Gets the time, in seconds, that it takes for the spring to come to rest, according to its
+    /// [`settle_threshold`](Self::settle_threshold).
+    pub fn settle_duration(&self) -> f32 {
+        let mut last_unsettled_time = 0.0;
+        let mut time = 0.0;
+        while time < MAX_SETTLE_DURATION {
+            if self.envelope(-1.0, self.initial_velocity, time) < self.settle_threshold {
+                break;
+            }
+            let (displacement, velocity) = self.solve(-1.0, self.initial_velocity, time);
+            if displacement.abs() >= self.settle_threshold
+                || velocity.abs() >= self.settle_threshold
+            {
+                last_unsettled_time = time + SETTLE_SEARCH_STEP;
+            }
+            time += SETTLE_SEARCH_STEP;
+        }
+        last_unsettled_time.min(MAX_SETTLE_DURATION)
+    }
+
+    /// Gets the normalized velocity of the spring, in units per second, at the given `time` (in
+    /// seconds) after release.
+    pub fn velocity(&self, time: f32) -> f32 {
+        self.solve(-1.0, self.initial_velocity, time).1
+    }
+
+    /// Gets the displacement, at the given `time`, caused by an additional initial velocity of
+    /// exactly `1.0` units per second, i.e. the amount by which the motion would change if the
+    /// spring were released with that extra velocity.
+    pub fn velocity_response(&self, time: f32) -> f32 {
+        self.solve(0.0, 1.0, time).0
+    }
+
+    fn damping_ratio(&self) -> f32 {
+        self.damping / (2.0 * (self.stiffness * self.mass).sqrt())
+    }
+
+    // Upper bound for the sum of the absolute displacement and velocity at any time after `time`.
+    fn envelope(&self, x0: f32, v0: f32, time: f32) -> f32 {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        if zeta < 1.0 {
+            let decay = zeta * omega;
+            let damped_omega = omega * (1.0 - zeta * zeta).sqrt();
+            let amplitude = x0.hypot((v0 + decay * x0) / damped_omega);
+            amplitude * (1.0 + omega) * (-decay * time).exp()
+        } else if zeta == 1.0 {
+            let c = v0 + omega * x0;
+            let linear = x0.abs() + c.abs() * time;
+            (linear * (1.0 + omega) + c.abs()) * (-omega * time).exp()
+        } else {
+            let (r1, r2, c1, c2) = self.overdamped_coefficients(x0, v0);
+            let scale = c1.abs() * (1.0 + r1.abs()) + c2.abs() * (1.0 + r2.abs());
+            scale * (r1 * time).exp()
+        }
+    }
+
+    fn natural_frequency(&self) -> f32 {
+        (self.stiffness / self.mass).sqrt()
+    }
+
+    fn overdamped_coefficients(&self, x0: f32, v0: f32) -> (f32, f32, f32, f32) {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        let root = (zeta * zeta - 1.0).sqrt();
+        // r1 is the slower (closer to zero) of the two decay rates.
+        let r1 = -omega * (zeta - root);
+        let r2 = -omega * (zeta + root);
+        let c2 = (v0 - r1 * x0) / (r2 - r1);
+        let c1 = x0 - c2;
+        (r1, r2, c1, c2)
+    }
+
+    // Solves the equation of motion for displacement from the rest position, given the initial
+    // displacement and velocity. Returns the displacement and velocity at the specified time.
+    fn solve(&self, x0: f32, v0: f32, time: f32) -> (f32, f32) {
+        let omega = self.natural_frequency();
+        let zeta = self.damping_ratio();
+        if zeta < 1.0 {
+            let decay = zeta * omega;
+            let damped_omega = omega * (1.0 - zeta * zeta).sqrt();
+            let a = x0;
+            let b = (v0 + decay * x0) / damped_omega;
+            let envelope = (-decay * time).exp();
+            let (sin, cos) = (damped_omega * time).sin_cos();
+            let displacement = envelope * (a * cos + b * sin);
+            let velocity = envelope
+                * ((b * damped_omega - a * decay) * cos - (a * damped_omega + b * decay) * sin);
+            (displacement, velocity)
+        } else if zeta == 1.0 {
+            let c = v0 + omega * x0;
+            let envelope = (-omega * time).exp();
+            let displacement = (x0 + c * time) * envelope;
+            let velocity = (c - omega * (x0 + c * time)) * envelope;
+            (displacement, velocity)
+        } else {
+            let (r1, r2, c1, c2) = self.overdamped_coefficients(x0, v0);
+            let (e1, e2) = ((r1 * time).exp(), (r2 * time).exp());
+            (c1 * e1 + c2 * e2, c1 * r1 * e1 + c2 * r2 * e2)
+        }
+    }
+}
+
+/// Easing function that follows the motion of a [`Spring`] over its
+/// [settle duration](Spring::settle_duration).
+///
+/// Normally created by [`Spring::easing`] or
+/// [`TimelineConfigurationBuilder::spring`](crate::timeline::TimelineConfigurationBuilder::spring).
+/// The easing assumes that the animation's duration is equal to the spring's settle duration; if
+/// used with a different duration, the spring motion will be sped up or slowed down accordingly.
+#[derive(Clone, Debug)]
+pub struct SpringEasing {
+    duration: f32,
+    spring: Spring,
+}
+
+impl SpringEasing {
+    /// Gets the duration, in seconds, over which the easing plays the spring's motion.
+    pub fn duration(&self) -> f32 {
+        self.duration
+    }
+
+    /// Gets the spring whose motion this easing follows.
+    pub fn spring(&self) -> &Spring {
+        &self.spring
+    }
+}
+
+impl EasingFunction for SpringEasing {
+    fn calc(&self, x: f32) -> f32 {
+        // A settled spring is only within the threshold of the end value. Snap to the exact value
+        // at the end, so that the last keyframe is actually reached.
+        if x >= 1.0 {
+            return 1.0;
+        }
+        self.spring.position(x * self.duration)
+    }
+
+    fn derivative(&self, x: f32) -> Option<f32> {
+        if x >= 1.0 {
+            return Some(0.0);
+        }
+        Some(self.spring.velocity(x * self.duration) * self.duration)
+    }
+
+    fn velocity_response(&self, x: f32) -> Option<f32> {
+        if x >= 1.0 {
+            return Some(0.0);
+        }
+        Some(self.spring.velocity_response(x * self.duration))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn position_starts_at_zero_and_settles_at_one() {
+        let spring = Spring::default();
+        let settle_duration = spring.settle_duration();
+
+        assert_eq!(spring.position(0.0), 0.0);
+        assert_relative_eq!(spring.position(settle_duration), 1.0, epsilon = 0.001);
+        assert_relative_eq!(spring.position(settle_duration * 2.0), 1.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn velocity_starts_at_initial_velocity() {
+        let spring = Spring::default().initial_velocity(3.0);
+
+        assert_relative_eq!(spring.velocity(0.0), 3.0, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn when_underdamped_then_overshoots() {
+        let spring = Spring::new(200.0, 5.0);
+        let max_position = (0..100)
+            .map(|i| spring.position(i as f32 * 0.01))
+            .fold(0.0, f32::max);
+
+        assert!(max_position > 1.1);
+    }
+
+    #[test]
+    fn when_critically_damped_then_does_not_overshoot() {
+        let spring = Spring::new(100.0, 20.0);
+        let settle_duration = spring.settle_duration();
+
+        for i in 0..=100 {
+            let position = spring.position(settle_duration * i as f32 * 0.01);
+            assert!(position <= 1.0, "Overshoot at step {i}: {position}");
+        }
+        assert_relative_eq!(spring.position(settle_duration), 1.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn when_overdamped_then_does_not_overshoot() {
+        let spring = Spring::new(100.0, 40.0);
+        let settle_duration = spring.settle_duration();
+
+        for i in 0..=100 {
+            let position = spring.position(settle_duration * i as f32 * 0.01);
+            assert!(position <= 1.0, "Overshoot at step {i}: {position}");
+        }
+        assert_relative_eq!(spring.position(settle_duration), 1.0, epsilon = 0.001);
+    }
+
+    #[test]
+    fn when_stiffer_then_settles_faster() {
+        let soft_spring = Spring::new(50.0, 10.0);
+        let stiff_spring = Spring::new(400.0, 30.0);
+
+        assert!(stiff_spring.settle_duration() < soft_spring.settle_duration());
+    }
+
+    #[test]
+    fn when_threshold_larger_then_settles_faster() {
+        let precise_spring = Spring::default().settle_threshold(0.0001);
+        let loose_spring = Spring::default().settle_threshold(0.01);
+
+        assert!(loose_spring.settle_duration() < precise_spring.settle_duration());
+    }
+
+    #[test]
+    fn velocity_response_starts_at_zero_with_unit_slope() {
+        let spring = Spring::default();
+
+        assert_eq!(spring.velocity_response(0.0), 0.0);
+        assert_relative_eq!(spring.velocity_response(0.001), 0.001, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn easing_follows_spring_over_settle_duration() {
+        let spring = Spring::default();
+        let easing = spring.easing();
+
+        assert_eq!(easing.duration(), spring.settle_duration());
+        assert_eq!(easing.calc(0.0), 0.0);
+        assert_eq!(easing.calc(0.25), spring.position(easing.duration() * 0.25));
+        assert_eq!(easing.calc(1.0), 1.0);
+    }
+
+    #[test]
+    fn when_properties_out_of_range_then_motion_is_finite() {
+        let springs = [
+            Spring::new(0.0, 10.0),
+            Spring::new(-100.0, -10.0),
+            Spring::default().mass(0.0),
+            Spring::default().mass(f32::NAN),
+            Spring::default().initial_velocity(f32::INFINITY),
+            Spring::default().settle_threshold(0.0),
+        ];
+
+        for spring in springs {
+            assert!(spring.position(0.5).is_finite(), "{spring:?}");
+            assert!(spring.velocity(0.5).is_finite(), "{spring:?}");
+            assert!(spring.settle_duration().is_finite(), "{spring:?}");
+        }
+    }
+}
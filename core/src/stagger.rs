@@ -0,0 +1,280 @@
+//! Helpers for playing a single [`Timeline`] on many targets with staggered start times.
+
+use crate::easing::{Easing, EasingFunction};
+use crate::timeline::Timeline;
+
+/// Configures the start time offsets for a [`StaggeredTimeline`].
+///
+/// Staggering is a common technique for animating lists, grids or particles, where every item
+/// plays the same animation, but each one starts slightly later than the one before it. Instead of
+/// building many timelines with different [delays](crate::timeline::Timeline::delay), a stagger
+/// computes an offset for each target index, and plays the same timeline for every target with
+/// that offset subtracted from the elapsed time. Offset time is treated exactly the same as the
+/// timeline's own delay, i.e. the target holds the values of the first keyframe until its offset
+/// has elapsed.
+///
+/// Offsets are based on each index's distance from the [origin](Self::from). With the default
+/// [`Easing::Linear`], each step in distance adds the same [interval](Self::new); other easings
+/// redistribute the offsets over the same total span, for example [`Easing::In`] will bunch up
+/// the items closest to the origin and spread out the ones farthest from it.
+#[derive(Clone, Debug)]
+pub struct Stagger {
+    easing: Easing,
+    interval: f32,
+    origin: StaggerOrigin,
+}
+
+impl Default for Stagger {
+    fn default() -> Self {
+        Self {
+            easing: Easing::default(),
+            interval: 0.1,
+            origin: StaggerOrigin::default(),
+        }
+    }
+}
+
+impl Stagger {
+    /// Creates a new [`Stagger`] with a given interval between consecutive targets.
+    ///
+    /// # Arguments
+    ///
+    /// * `interval` - Time between the start of each target and the next, in the same units as the
+    ///   timeline's duration (generally seconds). When using an [easing](Self::easing), this is the
+    ///   _average_ time between consecutive targets.
+    pub fn new(interval: f32) -> Self {
+        Self {
+            interval,
+            ..Default::default()
+        }
+    }
+
+    /// Configures the easing used to distribute offsets over the total stagger duration.
+    pub fn easing(mut self, easing: Easing) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Configures the index, or indices, from which the stagger originates, i.e. which targets
+    /// start first.
+    pub fn from(mut self, origin: StaggerOrigin) -> Self {
+        self.origin = origin;
+        self
+    }
+
+    /// Computes the start time offsets for a given number of targets.
+    ///
+    /// The result has exactly `count` elements, where the element at any given index is the offset
+    /// for the target at the same index.
+    pub fn offsets(&self, count: usize) -> Vec<f32> {
+        let distances = (0..count)
+            .map(|index| self.origin.distance(index, count))
+            .collect::<Vec<_>>();
+        let max_distance = distances.iter().copied().fold(0.0, f32::max);
+        if max_distance == 0.0 {
+            return vec![0.0; count];
+        }
+        let total_span = max_distance * self.interval;
+        distances
+            .into_iter()
+            .map(|distance| self.easing.calc(distance / max_distance) * total_span)
+            .collect()
+    }
+
+    /// Creates a [`StaggeredTimeline`] that plays the specified `timeline` for `count` targets.
+    pub fn apply<T: Timeline>(&self, timeline: T, count: usize) -> StaggeredTimeline<T> {
+        StaggeredTimeline {
+            offsets: self.offsets(count),
+            timeline,
+        }
+    }
+}
+
+/// Specifies the starting point of a [`Stagger`].
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum StaggerOrigin {
+    /// The first target starts first, followed by the second, and so on, until the last target.
+    #[default]
+    Start,
+    /// The last target starts first, followed by the second-to-last, and so on, until the first
+    /// target.
+    End,
+    /// Starts from the middle target, or two middle targets if the number of targets is even, and
+    /// proceeds outward to the first and last targets at the same time.
+    Center,
+    /// Starts from the first and last targets at the same time, and proceeds inward to the middle.
+    Edges,
+    /// Starts from the target at a specific index and proceeds outward in both directions.
+    Index(usize),
+}
+
+impl StaggerOrigin {
+    fn distance(&self, index: usize, count: usize) -> f32 {
+        let index = index as f32;
+        let last_index = count.max(1) as f32 - 1.0;
+        let center = last_index / 2.0;
+        match self {
+            Self::Start => index,
+            Self::End => last_index - index,
+            Self::Center => (index - center).abs(),
+            Self::Edges => center - (index - center).abs(),
+            Self::Index(origin) => (index - *origin as f32).abs(),
+        }
+    }
+}
+
+/// Plays a single [`Timeline`] on multiple targets, with a different start time for each target.
+///
+/// Created by [`Stagger::apply`].
+#[derive(Clone, Debug)]
+pub struct StaggeredTimeline<T: Timeline> {
+    offsets: Vec<f32>,
+    timeline: T,
+}
+
+impl<T: Timeline> StaggeredTimeline<T> {
+    /// Gets the combined duration of the staggered animation, from the start of the earliest target
+    /// to the end of the latest target, including the inner timeline's delay and repetitions.
+    pub fn duration(&self) -> f32 {
+        let max_offset = self.offsets.iter().copied().fold(0.0, f32::max);
+        self.timeline.duration() + max_offset
+    }
+
+    /// Returns `true` if there are no staggered targets.
+    pub fn is_empty(&self) -> bool {
+        self.offsets.is_empty()
+    }
+
+    /// Gets the number of staggered targets.
+    pub fn len(&self) -> usize {
+        self.offsets.len()
+    }
+
+    /// Gets the start time offset of the target at the specified `index`, or [`None`] if the index
+    /// is out of range.
+    pub fn offset(&self, index: usize) -> Option<f32> {
+        self.offsets.get(index).copied()
+    }
+
+    /// Gets a reference to the timeline that plays for each target.
+    pub fn timeline(&self) -> &T {
+        &self.timeline
+    }
+
+    /// Updates the values of a single target to represent the timeline at a given `time`, taking
+    /// into account the offset for that target's `index`.
+    ///
+    /// Does nothing if the `index` is out of range.
+    pub fn update(&self, index: usize, values: &mut T::Target, time: f32) {
+        if let Some(offset) = self.offset(index) {
+            self.timeline.update(values, time - offset);
+        }
+    }
+
+    /// Updates all targets to represent the timeline at a given `time`.
+    ///
+    /// Targets are matched to offsets by position; if there are more targets than offsets, the
+    /// remaining targets are not updated.
+    pub fn update_all<'a>(&self, targets: impl IntoIterator<Item = &'a mut T::Target>, time: f32)
+    where
+        T::Target: 'a,
+    {
+        for (values, offset) in targets.into_iter().zip(&self.offsets) {
+            self.timeline.update(values, time - offset);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_timelines::LinearTimeline;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn when_from_start_then_offsets_increase_by_interval() {
+        let offsets = Stagger::new(0.5).offsets(4);
+
+        assert_eq!(offsets, vec![0.0, 0.5, 1.0, 1.5]);
+    }
+
+    #[test]
+    fn when_from_end_then_offsets_decrease_by_interval() {
+        let offsets = Stagger::new(0.5).from(StaggerOrigin::End).offsets(4);
+
+        assert_eq!(offsets, vec![1.5, 1.0, 0.5, 0.0]);
+    }
+
+    #[test]
+    fn when_from_center_then_offsets_increase_outward() {
+        let odd_offsets = Stagger::new(1.0).from(StaggerOrigin::Center).offsets(5);
+        let even_offsets = Stagger::new(1.0).from(StaggerOrigin::Center).offsets(4);
+
+        assert_eq!(odd_offsets, vec![2.0, 1.0, 0.0, 1.0, 2.0]);
+        assert_eq!(even_offsets, vec![1.5, 0.5, 0.5, 1.5]);
+    }
+
+    #[test]
+    fn when_from_edges_then_offsets_increase_inward() {
+        let offsets = Stagger::new(1.0).from(StaggerOrigin::Edges).offsets(5);
+
+        assert_eq!(offsets, vec![0.0, 1.0, 2.0, 1.0, 0.0]);
+    }
+
+    #[test]
+    fn when_from_index_then_offsets_increase_from_index() {
+        let offsets = Stagger::new(1.0).from(StaggerOrigin::Index(1)).offsets(5);
+
+        assert_eq!(offsets, vec![1.0, 0.0, 1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn when_eased_then_distributes_offsets_over_same_span() {
+        let offsets = Stagger::new(1.0).easing(Easing::InQuad).offsets(5);
+
+        assert_eq!(offsets.len(), 5);
+        assert_eq!(offsets[0], 0.0);
+        assert_relative_eq!(offsets[4], 4.0, epsilon = 0.0001);
+        assert!(offsets[1] < 1.0);
+        assert!(offsets[2] < 2.0);
+        assert!(offsets[3] < 3.0);
+    }
+
+    #[test]
+    fn when_single_target_then_no_offset() {
+        let offsets = Stagger::new(1.0).from(StaggerOrigin::Center).offsets(1);
+
+        assert_eq!(offsets, vec![0.0]);
+    }
+
+    #[test]
+    fn duration_includes_timeline_duration_and_max_offset() {
+        let staggered = Stagger::new(0.25).apply(LinearTimeline::new(0.5), 5);
+
+        assert_eq!(staggered.len(), 5);
+        assert_eq!(staggered.duration(), 2.5);
+    }
+
+    #[test]
+    fn update_all_offsets_time_per_target() {
+        let staggered = Stagger::new(0.25).apply(LinearTimeline::new(0.5), 4);
+        let mut values = [0.0f32; 4];
+
+        staggered.update_all(&mut values, 0.5);
+        assert_eq!(values, [0.0, 0.0, 0.0, 0.0]);
+        staggered.update_all(&mut values, 1.0);
+        assert_eq!(values, [0.5, 0.25, 0.0, 0.0]);
+        staggered.update_all(&mut values, 2.0);
+        assert_eq!(values, [1.0, 1.0, 1.0, 0.75]);
+    }
+
+    #[test]
+    fn update_offsets_time_for_single_target() {
+        let staggered = Stagger::new(0.25).apply(LinearTimeline::new(0.0), 4);
+        let mut value = 0.0;
+
+        staggered.update(2, &mut value, 0.75);
+
+        assert_eq!(value, 0.25);
+    }
+}
@@ -0,0 +1,71 @@
+//! Simple [`Timeline`] implementations shared by the unit tests of several modules.
+
+use crate::marker::MarkerCrossing;
+use crate::timeline::{Repeat, Timeline};
+
+/// Timeline that goes from 0.0 to 1.0 in one time unit, after a delay, with an optional marker.
+pub(crate) struct LinearTimeline {
+    delay: f32,
+    marker_time: Option<f32>,
+}
+
+impl LinearTimeline {
+    pub(crate) fn new(delay: f32) -> Self {
+        Self {
+            delay,
+            marker_time: None,
+        }
+    }
+
+    /// Adds a marker named `marker` at the specified time, including the delay.
+    pub(crate) fn with_marker(mut self, marker_time: f32) -> Self {
+        self.marker_time = Some(marker_time);
+        self
+    }
+}
+
+impl Timeline for LinearTimeline {
+    type Target = f32;
+
+    fn cycle_duration(&self) -> Option<f32> {
+        Some(1.0)
+    }
+
+    fn delay(&self) -> f32 {
+        self.delay
+    }
+
+    fn duration(&self) -> f32 {
+        self.delay + 1.0
+    }
+
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        let Some(marker_time) = self.marker_time else {
+            return Vec::new();
+        };
+        let is_crossed = if time < prev_time {
+            marker_time >= time && marker_time < prev_time
+        } else {
+            marker_time > prev_time && marker_time <= time
+        };
+        if is_crossed {
+            vec![MarkerCrossing {
+                name: "marker".to_string(),
+                time: marker_time,
+                cycle: 0,
+            }]
+        } else {
+            Vec::new()
+        }
+    }
+
+    fn repeat(&self) -> Repeat {
+        Repeat::None
+    }
+
+    fn start_with(&mut self, _values: &Self::Target) {}
+
+    fn update(&self, values: &mut Self::Target, time: f32) {
+        *values = (time - self.delay).clamp(0.0, 1.0);
+    }
+}
@@ -1,6 +1,7 @@
 //! Internal helper module for relations between real time units and normalized timelines.
 
-use crate::timeline::Repeat;
+use crate::marker::{Marker, MarkerCrossing, MarkerPosition, MAX_BUFFERED_CROSSINGS};
+use crate::timeline::{FillMode, PlaybackDirection, Repeat};
 
 /// Describes the time scale of a [Timeline](crate::timeline::Timeline).
 ///
@@ -12,8 +13,13 @@ use crate::timeline::Repeat;
 #[derive(Clone, Debug)]
 pub struct TimeScale {
     delay: f32,
+    direction: PlaybackDirection,
     duration: f32,
+    fill_mode: FillMode,
+    iteration_start: f32,
+    markers: Vec<Marker>,
     repeat: Repeat,
+    repeat_delay: f32,
     reverse: bool,
 }
 
@@ -21,8 +27,13 @@ impl Default for TimeScale {
     fn default() -> Self {
         Self {
             delay: 0.0,
+            direction: PlaybackDirection::default(),
             duration: 1.0,
+            fill_mode: FillMode::default(),
+            iteration_start: 0.0,
+            markers: Vec::new(),
             repeat: Repeat::None,
+            repeat_delay: 0.0,
             reverse: false,
         }
     }
@@ -47,12 +58,61 @@ impl TimeScale {
         Self {
             duration,
             delay,
+            direction: PlaybackDirection::default(),
+            fill_mode: FillMode::default(),
+            iteration_start: 0.0,
+            markers: Vec::new(),
             repeat,
+            repeat_delay: 0.0,
             reverse,
         }
     }
 
+    /// Changes the [PlaybackDirection] of this timescale, which determines whether each cycle plays
+    /// forward or backward. The default is [`PlaybackDirection::Normal`].
+    pub fn with_direction(mut self, direction: PlaybackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Changes the [FillMode] of this timescale, which determines whether the timeline applies its
+    /// values before it starts and after it ends. The default is [`FillMode::Both`].
+    pub fn with_fill_mode(mut self, fill_mode: FillMode) -> Self {
+        self.fill_mode = fill_mode;
+        self
+    }
+
+    /// Changes the position, in cycles, at which the animation starts. The default is `0.0`.
+    ///
+    /// This is equivalent to the CSS `iteration-start` property: the animation begins partway
+    /// through a cycle, e.g. at 50% of the first cycle if `iteration_start` is `0.5`, but still
+    /// plays for the same total duration, i.e. it ends partway through a later cycle.
+    pub fn with_iteration_start(mut self, iteration_start: f32) -> Self {
+        self.iteration_start = iteration_start.max(0.0);
+        self
+    }
+
+    /// Changes the [Marker]s that are reported by [`markers_between`](Self::markers_between). The
+    /// default is no markers.
+    pub fn with_markers(mut self, markers: Vec<Marker>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Changes the time to wait between the end of each cycle and the start of the next, in the
+    /// same units as the duration. The default is `0.0`.
+    ///
+    /// Unlike the initial delay, the repeat delay applies between every pair of cycles, but not
+    /// before the first cycle or after the last one. During the repeat delay, the timeline holds
+    /// the values at the end of the previous cycle.
+    pub fn with_repeat_delay(mut self, repeat_delay: f32) -> Self {
+        self.repeat_delay = repeat_delay.max(0.0);
+        self
+    }
+
     /// Gets the duration of a single cycle, irrespective of [Repeat] setting.
+    ///
+    /// This does not include the [repeat delay](Self::with_repeat_delay) between cycles.
     pub fn get_cycle_duration(&self) -> f32 {
         self.duration
     }
@@ -66,14 +126,36 @@ impl TimeScale {
     ///
     /// # Returns
     ///
-    /// The sum of the initial delay and all cycle repetitions. If the animation repeats infinitely,
-    /// returns `[f32::INFINITY]`.
+    /// The sum of the initial delay, all cycle repetitions and the repeat delays between them,
+    /// including any partial cycle if the [Repeat] is [fractional](Repeat::Fractional). If the
+    /// animation repeats infinitely, returns `[f32::INFINITY]`.
     pub fn get_duration(&self) -> f32 {
-        if self.repeat == Repeat::Infinite {
-            f32::INFINITY
-        } else {
-            self.delay + self.duration * (self.repeat.as_ordinal() + 1) as f32
-        }
+        self.delay + self.get_active_duration()
+    }
+
+    /// Gets the playback direction configured for this timescale.
+    pub fn get_direction(&self) -> PlaybackDirection {
+        self.direction
+    }
+
+    /// Gets the position, in cycles, at which the animation starts.
+    pub fn get_iteration_start(&self) -> f32 {
+        self.iteration_start
+    }
+
+    /// Gets the fill mode configured for this timescale.
+    pub fn get_fill_mode(&self) -> FillMode {
+        self.fill_mode
+    }
+
+    /// Gets the time to wait between cycles.
+    pub fn get_repeat_delay(&self) -> f32 {
+        self.repeat_delay
+    }
+
+    /// Gets the markers configured for this timescale.
+    pub fn get_markers(&self) -> &[Marker] {
+        &self.markers
     }
 
     /// Gets the repetitions configured for this timescale.
@@ -81,6 +163,19 @@ impl TimeScale {
         self.repeat
     }
 
+    /// Gets the normalized time at the very start of the animation, i.e. the first position played
+    /// after the delay.
+    ///
+    /// This is `0.0` for most timescales, but may be different if the first cycle plays backward or
+    /// the [iteration start](Self::with_iteration_start) is not zero.
+    pub fn get_start_position(&self) -> f32 {
+        let (normalized_time, _) = self.normalize_cycle_time(
+            self.iteration_start.floor() as u32,
+            self.iteration_start.fract(),
+        );
+        normalized_time
+    }
+
     /// Computes the timescale-relative position (e.g. normalized time) for some real time.
     ///
     /// # Arguments
@@ -94,11 +189,11 @@ impl TimeScale {
     /// keyframe times, which are also between `0.0` (0%) and `1.0` (100%).
     ///
     /// * For example, if the animator is configured to reverse, then the last keyframe is reached
-    /// (result = `1.0`) when `time` is at 50% of the configured duration, and declines back to
-    /// `0.0` until 100% of the duration is reached.
+    ///   (result = `1.0`) when `time` is at 50% of the configured duration, and declines back to
+    ///   `0.0` until 100% of the duration is reached.
     /// * If not reversing, then the normalized time increases monotonically from `0.0` to `1.0`
-    /// until either the animation fully ends (remains at `1.0`) or the next loop begins (resets to
-    /// `0.0`).
+    ///   until either the animation fully ends (remains at `1.0`) or the next loop begins (resets
+    ///   to `0.0`).
     ///
     /// If the `time` is nowhere on the timeline, returns one of the other [`TimeScalePosition`]
     /// values indicating which extreme was reached.
@@ -107,49 +202,267 @@ impl TimeScale {
         if time < 0.0 {
             return TimeScalePosition::NotStarted;
         }
-        let (cycle_time, is_repeating) = match self.repeat {
-            Repeat::None if time > self.duration => return self.position_ended(),
-            Repeat::None => (time, false),
-            Repeat::Times(times) if time > self.duration * (times + 1) as f32 => {
-                return self.position_ended();
-            }
-            Repeat::Times(_) | Repeat::Infinite => {
-                // Doing the "simple" modulo arithmetic can produce some unintuitive results, since
-                // the normalized remainder can never be equal to 1.0 at the end of a cycle, it will
-                // always reset to 0.0. In a looping animation, this means we literally never hit
-                // the terminal value, which could be very noticeable for a reversing animation and
-                // especially one with a steep "ease-in" function.
-                //
-                // Instead, we hold the value at `duration` (normalized 1.0) as long as at least one
-                // full cycle has completed; this results in interpolating up to 1.0, then resetting
-                // or reversing back down to some very small but non-zero value.
-                //
-                // This might just have the opposite problem - never reaching the exact zero value,
-                // which could be noticeable with a steep ease-OUT function - but since animations
-                // are usually going to be blended with a state-dependent start value anyway, it
-                // makes somewhat more sense to focus on getting the end value correct.
-                let (quot, rem) = (time / self.duration, time % self.duration);
-                if rem == 0.0 && quot >= 1.0 {
-                    (self.duration, quot > 1.0)
+        if time > self.get_active_duration() {
+            return self.position_ended();
+        }
+        // Doing the "simple" modulo arithmetic can produce some unintuitive results, since the
+        // normalized remainder can never be equal to 1.0 at the end of a cycle, it will always
+        // reset to 0.0. In a looping animation, this means we literally never hit the terminal
+        // value, which could be very noticeable for a reversing animation and especially one with a
+        // steep "ease-in" function.
+        //
+        // Instead, we hold the value at `duration` (normalized 1.0) as long as at least one full
+        // cycle has completed; this results in interpolating up to 1.0, then resetting or reversing
+        // back down to some very small but non-zero value.
+        //
+        // This might just have the opposite problem - never reaching the exact zero value, which
+        // could be noticeable with a steep ease-OUT function - but since animations are usually
+        // going to be blended with a state-dependent start value anyway, it makes somewhat more
+        // sense to focus on getting the end value correct.
+        //
+        // Each cycle is followed by the repeat delay, so the period between the start of successive
+        // cycles may be longer than the cycle itself.
+        let period = self.get_period();
+        let offset_time = time + self.get_start_offset();
+        let (quot, rem) = (offset_time / period, offset_time % period);
+        let cycle_index = quot.floor() as u32;
+        let (cycle_index, cycle_time, is_in_repeat_delay) =
+            if rem == 0.0 && quot >= 1.0 && time > 0.0 {
+                (cycle_index - 1, self.duration, self.repeat_delay > 0.0)
+            } else if rem > self.duration {
+                (cycle_index, self.duration, true)
+            } else {
+                (cycle_index, rem, false)
+            };
+        let (normalized_time, is_reversing) =
+            self.normalize_cycle_time(cycle_index, cycle_time / self.duration);
+        // Cycles before the iteration start are skipped rather than played, so they don't count
+        // toward repetition.
+        let is_repeating = cycle_index > self.iteration_start.floor() as u32;
+        TimeScalePosition::Active(
+            normalized_time,
+            TimeScaleLoopState::new(is_repeating, is_reversing, is_in_repeat_delay),
+        )
+    }
+
+    /// Gets the rate at which the normalized position changes at the specified `time`, i.e. the
+    /// derivative of the normalized position given by [`get_position`](Self::get_position) with
+    /// respect to elapsed time.
+    ///
+    /// The rate is negative while the position is moving from the 100% keyframe back toward 0%,
+    /// and zero whenever the position is not moving at all, e.g. before the animation starts, after
+    /// it ends, or during a [repeat delay](Self::with_repeat_delay).
+    pub fn get_normalized_rate(&self, time: f32) -> f32 {
+        match self.get_position(time) {
+            TimeScalePosition::Active(_, loop_state) if !loop_state.is_in_repeat_delay => {
+                let rate = if self.reverse { 2.0 } else { 1.0 } / self.duration;
+                if loop_state.is_reversing {
+                    -rate
                 } else {
-                    (rem, quot >= 1.0)
+                    rate
                 }
             }
+            _ => 0.0,
+        }
+    }
+
+    /// Finds all [Marker]s crossed when moving from `prev_time` to `time`.
+    ///
+    /// Both times are elapsed times, as in [`get_position`](Self::get_position). Markers are
+    /// reported once for every cycle in which they are crossed, in the order that they are crossed,
+    /// so a single large step over several cycles of a repeating animation reports the same marker
+    /// several times. Markers during the initial delay, repeat delays or after the end of the
+    /// animation are never crossed.
+    ///
+    /// When moving forward, i.e. `time > prev_time`, a marker exactly at `time` is included and a
+    /// marker exactly at `prev_time` is not, so that consecutive queries never report the same
+    /// crossing twice. Moving backward (scrubbing) is also supported and reports crossings in
+    /// reverse chronological order, with the same rule, i.e. including `time` but not `prev_time`.
+    ///
+    /// At most [`MAX_BUFFERED_CROSSINGS`] crossings are reported; if more markers are crossed, only
+    /// the ones crossed last are included, since animators and players would discard the others.
+    pub fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        if self.markers.is_empty() || self.duration <= 0.0 || prev_time == time {
+            return Vec::new();
+        }
+        let is_backward = time < prev_time;
+        let (start_time, end_time) = if is_backward {
+            (time, prev_time)
+        } else {
+            (prev_time, time)
         };
-        let cycle_ratio = cycle_time / self.duration;
+        let period = self.get_period();
+        let start_offset = self.get_start_offset();
+        // All of the following are in "offset time", i.e. relative to the start of the first cycle
+        // (which may be before the actual start), with the delay removed.
+        let active_start = start_offset;
+        let active_end = start_offset + self.get_active_duration();
+        let query_start = start_time - self.delay + start_offset;
+        let query_end = end_time - self.delay + start_offset;
+        if query_end < active_start
+            || query_start > active_end
+            || !query_end.min(active_end).is_finite()
+        {
+            return Vec::new();
+        }
+        // The end of one cycle can coincide with the start of the next, so the cycle before the
+        // first one containing the query start must also be checked.
+        let mut first_cycle =
+            ((query_start.max(active_start) / period).floor() - 1.0).max(0.0) as u32;
+        let mut last_cycle =
+            ((query_end.min(active_end) / period).floor() as u32).min(self.get_last_cycle_index());
+        // Every complete cycle has (almost) the same number of crossings, so only enough cycles to
+        // produce the crossings that are kept need to be checked, plus the partial cycles at either
+        // end of the query. The number can differ by one between forward and backward cycles when
+        // reversing, if a marker is at the turning point of one but not the other.
+        let crossings_per_cycle = [0, 1]
+            .map(|cycle_index| {
+                self.markers
+                    .iter()
+                    .map(|marker| self.get_marker_ratios(cycle_index, marker.position()).len())
+                    .sum::<usize>()
+            })
+            .into_iter()
+            .min()
+            .unwrap_or(0);
+        if crossings_per_cycle == 0 {
+            return Vec::new();
+        }
+        let max_cycle_count = (MAX_BUFFERED_CROSSINGS.div_ceil(crossings_per_cycle) + 2) as u32;
+        if last_cycle.saturating_sub(first_cycle) > max_cycle_count {
+            if is_backward {
+                last_cycle = first_cycle + max_cycle_count;
+            } else {
+                first_cycle = last_cycle - max_cycle_count;
+            }
+        }
+        let mut crossings = Vec::new();
+        for cycle_index in first_cycle..=last_cycle {
+            let cycle_start = cycle_index as f32 * period;
+            let mut cycle_crossings = self
+                .markers
+                .iter()
+                .flat_map(|marker| {
+                    self.get_marker_ratios(cycle_index, marker.position())
+                        .into_iter()
+                        .map(move |ratio| (marker, cycle_start + ratio * self.duration))
+                })
+                .filter(|&(_, offset_time)| {
+                    offset_time >= active_start
+                        && offset_time <= active_end
+                        && (offset_time > query_start
+                            || (is_backward && offset_time == query_start))
+                        && (offset_time < query_end || (!is_backward && offset_time == query_end))
+                })
+                .map(|(marker, offset_time)| MarkerCrossing {
+                    name: marker.name().to_string(),
+                    time: offset_time - start_offset + self.delay,
+                    cycle: cycle_index,
+                })
+                .collect::<Vec<_>>();
+            cycle_crossings.sort_by(|a, b| a.time.total_cmp(&b.time));
+            crossings.append(&mut cycle_crossings);
+        }
+        if is_backward {
+            crossings.reverse();
+        }
+        if let Some(excess) = crossings.len().checked_sub(MAX_BUFFERED_CROSSINGS) {
+            crossings.drain(..excess);
+        }
+        crossings
+    }
+
+    // Duration of all cycles and the repeat delays between them, excluding the initial delay.
+    fn get_active_duration(&self) -> f32 {
+        let iteration_count = self.get_iteration_count();
+        if !iteration_count.is_finite() {
+            return f32::INFINITY;
+        }
+        let end = self.iteration_start + iteration_count;
+        // A trailing repeat delay is never included when the last cycle is complete.
+        let end_time = if end > 0.0 && end.fract() == 0.0 {
+            (end - 1.0) * self.get_period() + self.duration
+        } else {
+            end.floor() * self.get_period() + end.fract() * self.duration
+        };
+        (end_time - self.get_start_offset()).max(0.0)
+    }
+
+    // Time between the start of successive cycles.
+    fn get_period(&self) -> f32 {
+        self.duration + self.repeat_delay
+    }
+
+    // Time from the start of the first cycle (which is not played if the iteration start is not
+    // zero) to the position at which the animation actually starts.
+    fn get_start_offset(&self) -> f32 {
+        self.iteration_start.floor() * self.get_period()
+            + self.iteration_start.fract() * self.duration
+    }
+
+    // Total number of cycles that will play, including the first.
+    fn get_iteration_count(&self) -> f32 {
+        (self.repeat.repetitions() + 1.0).max(0.0)
+    }
+
+    // Converts the ratio of elapsed time within a cycle to the normalized (keyframe) time, taking
+    // into account both the in-cycle reversal and the direction of the cycle as a whole.
+    fn normalize_cycle_time(&self, cycle_index: u32, cycle_ratio: f32) -> (f32, bool) {
         let (normalized_time, is_reversing) = match self.reverse {
             true if cycle_ratio > 0.5 => ((1.0 - cycle_ratio) * 2.0, true),
             true => (cycle_ratio * 2.0, false),
             false => (cycle_ratio, false),
         };
-        TimeScalePosition::Active(
-            normalized_time,
-            TimeScaleLoopState::new(is_repeating, is_reversing),
-        )
+        if self.direction.is_cycle_reversed(cycle_index) {
+            (1.0 - normalized_time, !is_reversing)
+        } else {
+            (normalized_time, is_reversing)
+        }
+    }
+
+    // Ratios of elapsed time within a cycle at which a marker is crossed. This is the inverse of
+    // `normalize_cycle_time`, except that there can be two results if the cycle reverses.
+    fn get_marker_ratios(&self, cycle_index: u32, position: MarkerPosition) -> Vec<f32> {
+        match position {
+            MarkerPosition::Seconds(seconds) if (0.0..=self.duration).contains(&seconds) => {
+                vec![seconds / self.duration]
+            }
+            MarkerPosition::Normalized(normalized_time)
+                if (0.0..=1.0).contains(&normalized_time) =>
+            {
+                let normalized_time = if self.direction.is_cycle_reversed(cycle_index) {
+                    1.0 - normalized_time
+                } else {
+                    normalized_time
+                };
+                match self.reverse {
+                    true if normalized_time == 1.0 => vec![0.5],
+                    true => vec![normalized_time / 2.0, 1.0 - normalized_time / 2.0],
+                    false => vec![normalized_time],
+                }
+            }
+            _ => Vec::new(),
+        }
+    }
+
+    // Index of the cycle in which the animation ends, which may be an incomplete cycle.
+    fn get_last_cycle_index(&self) -> u32 {
+        self.get_end_cycle_position().0
+    }
+
+    // Cycle index and ratio of elapsed time within that cycle at which the animation ends.
+    fn get_end_cycle_position(&self) -> (u32, f32) {
+        let end = self.iteration_start + self.get_iteration_count();
+        if end > 0.0 && end.fract() == 0.0 {
+            (end as u32 - 1, 1.0)
+        } else {
+            (end.floor() as u32, end.fract())
+        }
     }
 
     fn position_ended(&self) -> TimeScalePosition {
-        let normalized_time = if self.reverse { 0.0 } else { 1.0 };
+        let (cycle_index, cycle_ratio) = self.get_end_cycle_position();
+        let (normalized_time, _) = self.normalize_cycle_time(cycle_index, cycle_ratio);
         TimeScalePosition::Ended(normalized_time)
     }
 }
@@ -160,35 +473,51 @@ impl TimeScale {
 pub enum TimeScalePosition {
     /// The timeline has not started at the specified time, either because the time was negative or
     /// because it is within the configured delay period. When determining animator values, this can
-    /// be considered equivalent to a normalized time of `0.0`.
+    /// be considered equivalent to the normalized time given by [`TimeScale::get_start_position`],
+    /// which is usually `0.0`.
     NotStarted,
     /// The timeline is in progress at the specified time, corresponding to the normalized position
     /// (from `0.0` to `1.0`), and with the given loop info.
     Active(f32, TimeScaleLoopState),
     /// The timeline has already ended at the specified time, i.e. it does not loop infinitely and
     /// the specified time is after the last loop ends. Holds a value indicating the normalized time
-    /// reached at the end, which is either `0.0` if the timeline reverses or `1.0` if it does not.
+    /// reached at the end. This is normally either `0.0` if the last cycle ends at the 0% keyframe
+    /// (because it reverses or plays backward) or `1.0` if it ends at the 100% keyframe, but can be
+    /// any value in between if the timeline ends partway through a cycle due to a
+    /// [fractional](Repeat::Fractional) repeat or an iteration start offset.
     Ended(f32),
 }
 
 /// Provides additional information about the relationship between a real time and a normalized
 /// position, taking into account repeat and/or reverse behavior.
+///
+/// More fields may be added as the timescale gains new behaviors, so the state can only be
+/// created by a [`TimeScale`], or as the [`Default`] state.
 #[derive(Debug, Default, Eq, PartialEq)]
+#[non_exhaustive]
 pub struct TimeScaleLoopState {
     /// Whether or not the position tagged with this state is considered a repetition, i.e. the
-    /// timeline has completed at least one entire cycle before reaching it.
+    /// timeline has moved past the cycle in which it started before reaching it. Cycles skipped
+    /// due to the [iteration start](TimeScale::with_iteration_start) are not counted.
     pub is_repeating: bool,
 
     /// Whether or not the position tagged with this state is on the reverse pass of any cycle,
-    /// including the first cycle.
+    /// including the first cycle, or on a cycle that plays backward due to its
+    /// [PlaybackDirection].
     pub is_reversing: bool,
+
+    /// Whether or not the position tagged with this state is during the
+    /// [repeat delay](TimeScale::with_repeat_delay) after a cycle, in which case the normalized
+    /// position is held at the end of that cycle.
+    pub is_in_repeat_delay: bool,
 }
 
 impl TimeScaleLoopState {
-    fn new(is_repeating: bool, is_reversing: bool) -> TimeScaleLoopState {
+    fn new(is_repeating: bool, is_reversing: bool, is_in_repeat_delay: bool) -> TimeScaleLoopState {
         Self {
             is_repeating,
             is_reversing,
+            is_in_repeat_delay,
         }
     }
 
@@ -205,6 +534,15 @@ impl TimeScaleLoopState {
         Self {
             is_repeating: true,
             is_reversing: true,
+            ..Default::default()
+        }
+    }
+
+    #[cfg(test)]
+    fn in_repeat_delay() -> Self {
+        Self {
+            is_in_repeat_delay: true,
+            ..Default::default()
         }
     }
 
@@ -429,6 +767,283 @@ mod tests {
         assert_eq!(timescale.get_position(25.0), TimeScalePosition::Ended(0.0));
     }
 
+    #[test]
+    fn when_direction_reverse_then_every_cycle_plays_backward() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Times(1), false)
+            .with_direction(PlaybackDirection::Reverse);
+
+        assert_eq!(
+            timescale.get_position(0.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(20.0),
+            TimeScalePosition::Active(0.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(25.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::repeating_and_reversing())
+        );
+        assert_eq!(timescale.get_position(41.0), TimeScalePosition::Ended(0.0));
+    }
+
+    #[test]
+    fn when_direction_alternate_then_odd_cycles_play_backward() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Times(2), false)
+            .with_direction(PlaybackDirection::Alternate);
+
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(20.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(25.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::repeating_and_reversing())
+        );
+        assert_eq!(
+            timescale.get_position(40.0),
+            TimeScalePosition::Active(0.0, TimeScaleLoopState::repeating_and_reversing())
+        );
+        assert_eq!(
+            timescale.get_position(45.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_position(61.0), TimeScalePosition::Ended(1.0));
+    }
+
+    #[test]
+    fn when_direction_alternate_reverse_then_even_cycles_play_backward() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Times(1), false)
+            .with_direction(PlaybackDirection::AlternateReverse);
+
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(20.0),
+            TimeScalePosition::Active(0.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(25.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_position(41.0), TimeScalePosition::Ended(1.0));
+    }
+
+    #[test]
+    fn when_direction_reverse_and_auto_reverse_then_cycle_peaks_at_start_keyframe() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::None, true)
+            .with_direction(PlaybackDirection::Reverse);
+
+        assert_eq!(
+            timescale.get_position(0.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(10.0),
+            TimeScalePosition::Active(0.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(15.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::default())
+        );
+        assert_eq!(timescale.get_position(21.0), TimeScalePosition::Ended(1.0));
+    }
+
+    #[test]
+    fn when_repeat_fractional_then_ends_partway_through_last_cycle() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Fractional(1.5), false);
+
+        assert_eq!(
+            timescale.get_position(20.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(45.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(
+            timescale.get_position(50.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_position(51.0), TimeScalePosition::Ended(0.5));
+    }
+
+    #[test]
+    fn when_repeat_fractional_and_alternating_then_ends_on_reversed_cycle() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Fractional(0.25), false)
+            .with_direction(PlaybackDirection::Alternate);
+
+        assert_eq!(timescale.get_position(30.0), TimeScalePosition::Ended(0.75));
+    }
+
+    #[test]
+    fn when_repeat_fractional_less_than_one_cycle_then_ends_in_first_cycle() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Fractional(-0.5), false);
+
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::default())
+        );
+        assert_eq!(timescale.get_position(11.0), TimeScalePosition::Ended(0.5));
+        assert_eq!(timescale.get_duration(), 10.0);
+    }
+
+    #[test]
+    fn when_iteration_start_offset_then_starts_partway_through_cycle() {
+        let timescale = TimeScale::new(20.0, 2.0, Repeat::None, false).with_iteration_start(0.5);
+
+        assert_eq!(timescale.get_start_position(), 0.5);
+        assert_eq!(
+            timescale.get_position(2.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(7.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(12.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(17.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(
+            timescale.get_position(22.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_position(23.0), TimeScalePosition::Ended(0.5));
+        assert_eq!(timescale.get_duration(), 22.0);
+    }
+
+    #[test]
+    fn when_iteration_start_on_reversed_cycle_then_start_position_is_reversed() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Infinite, false)
+            .with_direction(PlaybackDirection::Alternate)
+            .with_iteration_start(1.25);
+
+        assert_eq!(timescale.get_start_position(), 0.75);
+        assert_eq!(
+            timescale.get_position(0.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(15.0),
+            TimeScalePosition::Active(0.0, TimeScaleLoopState::reversing())
+        );
+        assert_eq!(
+            timescale.get_position(20.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+    }
+
+    #[test]
+    fn when_repeat_delay_then_holds_end_of_cycle_between_cycles() {
+        let timescale = TimeScale::new(20.0, 2.0, Repeat::Times(1), false).with_repeat_delay(10.0);
+
+        assert_eq!(
+            timescale.get_position(12.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(22.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(27.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::in_repeat_delay())
+        );
+        assert_eq!(
+            timescale.get_position(37.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(
+            timescale.get_position(52.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_position(53.0), TimeScalePosition::Ended(1.0));
+    }
+
+    #[test]
+    fn when_repeat_delay_and_reversing_then_holds_end_of_reversed_cycle() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Infinite, false)
+            .with_direction(PlaybackDirection::Alternate)
+            .with_repeat_delay(5.0);
+
+        assert_eq!(
+            timescale.get_position(22.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::in_repeat_delay())
+        );
+        assert_eq!(
+            timescale.get_position(30.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::repeating_and_reversing())
+        );
+        assert_eq!(
+            timescale.get_position(47.0),
+            TimeScalePosition::Active(
+                0.0,
+                TimeScaleLoopState {
+                    is_repeating: true,
+                    is_reversing: true,
+                    is_in_repeat_delay: true,
+                }
+            )
+        );
+        assert_eq!(
+            timescale.get_position(55.0),
+            TimeScalePosition::Active(0.25, TimeScaleLoopState::repeating())
+        );
+    }
+
+    #[test]
+    fn when_repeat_delay_and_iteration_start_then_offsets_within_first_cycle() {
+        let timescale = TimeScale::new(20.0, 0.0, Repeat::Fractional(0.5), false)
+            .with_iteration_start(0.5)
+            .with_repeat_delay(10.0);
+
+        assert_eq!(
+            timescale.get_position(5.0),
+            TimeScalePosition::Active(0.75, TimeScaleLoopState::default())
+        );
+        assert_eq!(
+            timescale.get_position(15.0),
+            TimeScalePosition::Active(1.0, TimeScaleLoopState::in_repeat_delay())
+        );
+        assert_eq!(
+            timescale.get_position(30.0),
+            TimeScalePosition::Active(0.5, TimeScaleLoopState::repeating())
+        );
+        assert_eq!(timescale.get_duration(), 40.0);
+    }
+
+    #[test]
+    fn when_first_cycle_plays_backward_then_start_position_is_end() {
+        let normal_timescale = TimeScale::new(20.0, 2.0, Repeat::Infinite, true);
+        let reverse_timescale = TimeScale::new(20.0, 2.0, Repeat::None, false)
+            .with_direction(PlaybackDirection::Reverse);
+        let alternate_reverse_timescale = TimeScale::new(20.0, 2.0, Repeat::Infinite, false)
+            .with_direction(PlaybackDirection::AlternateReverse);
+
+        assert_eq!(normal_timescale.get_start_position(), 0.0);
+        assert_eq!(reverse_timescale.get_start_position(), 1.0);
+        assert_eq!(alternate_reverse_timescale.get_start_position(), 1.0);
+    }
+
     #[test]
     fn get_cycle_duration_ignores_delay_and_repetitions() {
         let single_timescale = TimeScale::new(20.0, 3.0, Repeat::None, false);
@@ -453,9 +1068,18 @@ mod tests {
         let repeating_timescale = TimeScale::new(20.0, 3.0, Repeat::Times(5), true);
         let infinite_timescale = TimeScale::new(20.0, 3.0, Repeat::Infinite, false);
 
+        let fractional_timescale = TimeScale::new(20.0, 3.0, Repeat::Fractional(1.5), false);
+        let delayed_timescale =
+            TimeScale::new(20.0, 3.0, Repeat::Times(2), false).with_repeat_delay(4.0);
+        let delayed_fractional_timescale =
+            TimeScale::new(20.0, 3.0, Repeat::Fractional(1.5), false).with_repeat_delay(4.0);
+
         assert_eq!(single_timescale.get_duration(), 23.0);
         assert_eq!(repeating_timescale.get_duration(), 123.0);
         assert_eq!(infinite_timescale.get_duration(), f32::INFINITY);
+        assert_eq!(fractional_timescale.get_duration(), 53.0);
+        assert_eq!(delayed_timescale.get_duration(), 71.0);
+        assert_eq!(delayed_fractional_timescale.get_duration(), 61.0);
     }
 
     #[test]
@@ -468,4 +1092,212 @@ mod tests {
         assert_eq!(repeating_timescale.get_repeat(), Repeat::Times(5));
         assert_eq!(infinite_timescale.get_repeat(), Repeat::Infinite);
     }
+
+    #[test]
+    fn get_fill_mode_returns_fill_mode() {
+        let default_timescale = TimeScale::new(20.0, 3.0, Repeat::None, false);
+        let forwards_timescale =
+            TimeScale::new(20.0, 3.0, Repeat::None, false).with_fill_mode(FillMode::Forwards);
+
+        assert_eq!(default_timescale.get_fill_mode(), FillMode::Both);
+        assert_eq!(forwards_timescale.get_fill_mode(), FillMode::Forwards);
+    }
+
+    #[test]
+    fn get_direction_returns_direction() {
+        let default_timescale = TimeScale::new(20.0, 3.0, Repeat::None, false);
+        let alternate_timescale = TimeScale::new(20.0, 3.0, Repeat::None, false)
+            .with_direction(PlaybackDirection::Alternate);
+
+        assert_eq!(default_timescale.get_direction(), PlaybackDirection::Normal);
+        assert_eq!(
+            alternate_timescale.get_direction(),
+            PlaybackDirection::Alternate
+        );
+    }
+
+    #[test]
+    fn get_normalized_rate_follows_direction_of_position() {
+        let timescale = TimeScale::new(4.0, 1.0, Repeat::Times(1), true).with_repeat_delay(2.0);
+
+        assert_eq!(timescale.get_normalized_rate(0.5), 0.0);
+        assert_eq!(timescale.get_normalized_rate(2.0), 0.5);
+        assert_eq!(timescale.get_normalized_rate(4.0), -0.5);
+        assert_eq!(timescale.get_normalized_rate(6.0), 0.0);
+        assert_eq!(timescale.get_normalized_rate(8.0), 0.5);
+        assert_eq!(timescale.get_normalized_rate(12.0), 0.0);
+    }
+
+    #[test]
+    fn when_marker_normalized_then_crossed_at_keyframe_time() {
+        let timescale = TimeScale::new(2.0, 1.0, Repeat::None, false)
+            .with_markers(vec![Marker::at("half", 0.5)]);
+
+        assert_eq!(timescale.markers_between(0.0, 1.9), &[]);
+        assert_eq!(
+            timescale.markers_between(1.9, 2.0),
+            &[crossing("half", 2.0, 0)]
+        );
+        assert_eq!(timescale.markers_between(2.0, 5.0), &[]);
+    }
+
+    #[test]
+    fn when_marker_in_seconds_then_crossed_at_cycle_time() {
+        let timescale = TimeScale::new(4.0, 1.0, Repeat::Times(1), false)
+            .with_direction(PlaybackDirection::Reverse)
+            .with_markers(vec![
+                Marker::at_seconds("first", 1.0),
+                Marker::at_seconds("outside", 5.0),
+            ]);
+
+        assert_eq!(
+            timescale.markers_between(0.0, 10.0),
+            &[crossing("first", 2.0, 0), crossing("first", 6.0, 1)]
+        );
+    }
+
+    #[test]
+    fn when_marker_at_start_then_crossed_only_from_negative_time() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::None, false)
+            .with_markers(vec![Marker::at("start", 0.0)]);
+
+        assert_eq!(
+            timescale.markers_between(f32::NEG_INFINITY, 0.5),
+            &[crossing("start", 0.0, 0)]
+        );
+        assert_eq!(timescale.markers_between(0.0, 0.5), &[]);
+    }
+
+    #[test]
+    fn when_many_cycles_elapse_then_reports_marker_for_each_cycle() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Infinite, false)
+            .with_markers(vec![Marker::at("a", 0.25), Marker::at("b", 0.75)]);
+
+        assert_eq!(
+            timescale.markers_between(0.5, 3.5),
+            &[
+                crossing("b", 0.75, 0),
+                crossing("a", 1.25, 1),
+                crossing("b", 1.75, 1),
+                crossing("a", 2.25, 2),
+                crossing("b", 2.75, 2),
+                crossing("a", 3.25, 3),
+            ]
+        );
+    }
+
+    #[test]
+    fn when_cycles_are_contiguous_then_reports_end_and_start_markers() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Times(1), false)
+            .with_markers(vec![Marker::at("end", 1.0), Marker::at("start", 0.0)]);
+
+        assert_eq!(
+            timescale.markers_between(0.5, 1.0),
+            &[crossing("end", 1.0, 0), crossing("start", 1.0, 1)]
+        );
+        assert_eq!(
+            timescale.markers_between(1.0, 5.0),
+            &[crossing("end", 2.0, 1)]
+        );
+    }
+
+    #[test]
+    fn when_reverse_then_marker_crossed_in_both_halves_of_cycle() {
+        let timescale = TimeScale::new(2.0, 0.0, Repeat::None, true)
+            .with_markers(vec![Marker::at("half", 0.5), Marker::at("end", 1.0)]);
+
+        assert_eq!(
+            timescale.markers_between(0.0, 2.0),
+            &[
+                crossing("half", 0.5, 0),
+                crossing("end", 1.0, 0),
+                crossing("half", 1.5, 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn when_direction_alternate_then_marker_mirrored_on_odd_cycles() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Times(1), false)
+            .with_direction(PlaybackDirection::Alternate)
+            .with_markers(vec![Marker::at("quarter", 0.25)]);
+
+        assert_eq!(
+            timescale.markers_between(0.0, 2.0),
+            &[crossing("quarter", 0.25, 0), crossing("quarter", 1.75, 1)]
+        );
+    }
+
+    #[test]
+    fn when_repeat_delay_then_marker_times_include_delay() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Times(1), false)
+            .with_repeat_delay(0.5)
+            .with_markers(vec![Marker::at("end", 1.0)]);
+
+        assert_eq!(
+            timescale.markers_between(0.0, 5.0),
+            &[crossing("end", 1.0, 0), crossing("end", 2.5, 1)]
+        );
+    }
+
+    #[test]
+    fn when_ended_partway_through_cycle_then_later_markers_not_crossed() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Fractional(0.5), false)
+            .with_markers(vec![Marker::at("late", 0.75)]);
+
+        assert_eq!(
+            timescale.markers_between(0.0, 5.0),
+            &[crossing("late", 0.75, 0)]
+        );
+    }
+
+    #[test]
+    fn when_iteration_start_offset_then_skips_markers_before_start() {
+        let timescale = TimeScale::new(2.0, 1.0, Repeat::Times(1), false)
+            .with_iteration_start(0.5)
+            .with_markers(vec![Marker::at("quarter", 0.25)]);
+
+        assert_eq!(
+            timescale.markers_between(f32::NEG_INFINITY, 10.0),
+            &[crossing("quarter", 2.5, 1), crossing("quarter", 4.5, 2)]
+        );
+    }
+
+    #[test]
+    fn when_moving_backward_then_reports_markers_in_reverse_order() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Infinite, false)
+            .with_markers(vec![Marker::at("a", 0.25)]);
+
+        assert_eq!(
+            timescale.markers_between(2.25, 0.25),
+            &[crossing("a", 1.25, 1), crossing("a", 0.25, 0)]
+        );
+    }
+
+    #[test]
+    fn when_more_than_max_crossings_then_reports_only_last_crossed() {
+        let timescale = TimeScale::new(1.0, 0.0, Repeat::Infinite, false)
+            .with_markers(vec![Marker::at("a", 0.25), Marker::at("b", 0.75)]);
+
+        let forward_crossings = timescale.markers_between(0.5, 1_000_000.5);
+        let backward_crossings = timescale.markers_between(1_000_000.5, 0.5);
+
+        assert_eq!(forward_crossings.len(), MAX_BUFFERED_CROSSINGS);
+        assert_eq!(forward_crossings[0], crossing("b", 999_872.75, 999_872));
+        assert_eq!(
+            forward_crossings.last(),
+            Some(&crossing("a", 1_000_000.25, 1_000_000))
+        );
+        assert_eq!(backward_crossings.len(), MAX_BUFFERED_CROSSINGS);
+        assert_eq!(backward_crossings[0], crossing("a", 128.25, 128));
+        assert_eq!(backward_crossings.last(), Some(&crossing("b", 0.75, 0)));
+    }
+
+    fn crossing(name: &str, time: f32, cycle: u32) -> MarkerCrossing {
+        MarkerCrossing {
+            name: name.to_string(),
+            time,
+            cycle,
+        }
+    }
 }
@@ -1,6 +1,10 @@
 //! Creation and consumption of [`Timeline`] instances.
 
+use crate::bake::{BakeProperties, BakedTimeline};
 use crate::easing::Easing;
+use crate::interpolation::Lerp;
+use crate::marker::{Marker, MarkerCrossing};
+use crate::spring::Spring;
 use crate::time_scale::{TimeScale, TimeScalePosition};
 use std::cmp::Ordering;
 use std::fmt::Debug;
@@ -13,7 +17,8 @@ pub trait Timeline {
 
     /// Gets the duration of a single cycle of the timeline. For repeating animations, this is the
     /// time that will elapse between when the animation first begins (after [Self::delay] elapses)
-    /// and when the first repetition begins.
+    /// and when the first cycle ends, which is also when the first repetition begins unless there
+    /// is a [repeat delay](TimelineConfigurationBuilder::repeat_delay_seconds).
     ///
     /// This value has no inherent units, but if [TimelineConfiguration] was used to create this
     /// instance, then it will be in seconds, since it is the same value originally passed to
@@ -47,6 +52,32 @@ pub trait Timeline {
     /// of all animations.
     fn duration(&self) -> f32;
 
+    /// Finds all [`Marker`]s crossed between two successive update times.
+    ///
+    /// Markers are reported in the order that they are crossed, once for every cycle in which they
+    /// are crossed, so that a single large step over several cycles of a repeating timeline
+    /// reports the same marker several times. A marker exactly at `time` is included, but a marker
+    /// exactly at `prev_time` is not, since it would have been reported by the previous query. To
+    /// include markers at the very beginning of the timeline, use a negative `prev_time`.
+    ///
+    /// If `time` is less than `prev_time`, i.e. the timeline is being scrubbed backward, then the
+    /// crossings are reported in reverse order.
+    ///
+    /// Implementations may limit the number of crossings reported by a single query to the last
+    /// [`MAX_BUFFERED_CROSSINGS`](crate::marker::MAX_BUFFERED_CROSSINGS) crossed, since that is all
+    /// that a [`StateAnimator`](crate::animator::StateAnimator) or
+    /// [`TimelinePlayer`](crate::player::TimelinePlayer) keeps.
+    ///
+    /// The default implementation never reports any markers.
+    ///
+    /// # Arguments
+    ///
+    /// * `prev_time` - Time of the previous update, in the same units as the timeline's duration.
+    /// * `time` - Time of the current update.
+    fn markers_between(&self, _prev_time: f32, _time: f32) -> Vec<MarkerCrossing> {
+        Vec::new()
+    }
+
     /// Gets the repetitions of this timeline.
     ///
     /// If the timeline includes multiple animations with different
@@ -68,6 +99,43 @@ pub trait Timeline {
     /// * `values` - New values where the timeline should start, replacing previous defaults.
     fn start_with(&mut self, values: &Self::Target);
 
+    /// Changes this timeline to start with a different set of values and an initial velocity.
+    ///
+    /// This is the momentum-preserving version of [Self::start_with]. Since animation properties
+    /// are only required to implement [Lerp], the velocity is given as a second set of values,
+    /// `next_values`, that the properties would reach after `interval` if they kept moving at
+    /// their current rate, e.g. the values of the interrupted timeline at a slightly later time.
+    ///
+    /// Only timelines with physically-based easings, such as a [Spring], respond to velocity. The
+    /// default implementation ignores the velocity and is equivalent to calling
+    /// [Self::start_with].
+    ///
+    /// # Arguments
+    ///
+    /// * `values` - New values where the timeline should start, replacing previous defaults.
+    /// * `next_values` - Values that the properties would have after the `interval`.
+    /// * `interval` - Time between `values` and `next_values`, in the same units as the timeline's
+    ///   duration (generally seconds).
+    fn start_with_velocity(
+        &mut self,
+        values: &Self::Target,
+        _next_values: &Self::Target,
+        _interval: f32,
+    ) {
+        self.start_with(values);
+    }
+
+    /// Checks whether this timeline responds to the velocity given to
+    /// [`start_with_velocity`](Self::start_with_velocity), i.e. whether the easing at its start has
+    /// a [velocity response](crate::easing::EasingFunction::velocity_response).
+    ///
+    /// Callers can use this to avoid the cost of computing a velocity that would be ignored. The
+    /// default implementation returns `true`, so that velocity is always provided to timelines that
+    /// do not implement this method.
+    fn responds_to_velocity(&self) -> bool {
+        true
+    }
+
     /// Updates a set of animator values to represent the timeline at a given `time`.
     ///
     /// Properties that are not included in the timeline will not be updated.
@@ -77,6 +145,47 @@ pub trait Timeline {
     /// * `target` - Target containing animatable values to update.
     /// * `time` - Time in the same unit scale as the timeline's duration, generally seconds.
     fn update(&self, values: &mut Self::Target, time: f32);
+
+    /// Updates a set of values to represent the velocity of each animated property at a given
+    /// `time`, i.e. the rate at which the property is changing, per unit of time.
+    ///
+    /// Velocities are stored in the same type as the values themselves; for example, an `x: f32`
+    /// property moving 100 units per second to the left has a velocity of `x: -100.0`. Properties
+    /// that are not animated at the specified time, e.g. before the timeline starts or after it
+    /// ends, have a velocity of zero, and properties that are not included in the timeline are not
+    /// updated at all.
+    ///
+    /// Only properties whose type implements [`VectorSpace`](crate::interpolation::VectorSpace)
+    /// can have a velocity. Timelines generated by `derive(Animate)` update the velocities of the
+    /// fields marked with `#[animate(velocity)]`, and leave all other fields unchanged.
+    ///
+    /// This is equivalent to [`update_velocity_scaled`](Self::update_velocity_scaled) with a
+    /// `scale` of `1.0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocities` - Target containing velocities to update.
+    /// * `time` - Time in the same unit scale as the timeline's duration, generally seconds.
+    fn update_velocity(&self, velocities: &mut Self::Target, time: f32) {
+        self.update_velocity_scaled(velocities, time, 1.0);
+    }
+
+    /// Updates a set of values to represent the velocity of each animated property at a given
+    /// `time`, multiplied by a constant `scale`.
+    ///
+    /// Timelines that support velocities should implement this method rather than
+    /// [`update_velocity`](Self::update_velocity). The scale allows timelines that wrap other
+    /// timelines, such as a [`ScaledTimeline`], to account for the difference in speed without
+    /// having to perform any arithmetic on the target values.
+    ///
+    /// The default implementation does not update any velocities.
+    ///
+    /// # Arguments
+    ///
+    /// * `velocities` - Target containing velocities to update.
+    /// * `time` - Time in the same unit scale as the timeline's duration, generally seconds.
+    /// * `scale` - Factor by which to multiply all velocities.
+    fn update_velocity_scaled(&self, _velocities: &mut Self::Target, _time: f32, _scale: f32) {}
 }
 
 /// Trait for a type that can create an animation [`Timeline`] via the [`TimelineConfiguration`] and
@@ -166,7 +275,12 @@ pub struct TimelineBuilderArguments<Data: Clone + Debug> {
     /// Timing information derived from the various [`TimelineConfiguration`] properties including
     /// [`duration_seconds`](TimelineConfiguration::duration_seconds),
     /// [`delay_seconds`](TimelineConfiguration::delay_seconds),
-    /// [`repeat`](TimelineConfiguration::repeat) and [`reverse`](TimelineConfiguration::reverse).
+    /// [`direction`](TimelineConfiguration::direction),
+    /// [`fill_mode`](TimelineConfiguration::fill_mode),
+    /// [`iteration_start`](TimelineConfiguration::iteration_start),
+    /// [`repeat`](TimelineConfiguration::repeat),
+    /// [`repeat_delay_seconds`](TimelineConfiguration::repeat_delay_seconds) and
+    /// [`reverse`](TimelineConfiguration::reverse).
     pub timescale: TimeScale,
 }
 
@@ -193,6 +307,18 @@ impl<Data: Clone + Debug> From<TimelineConfiguration<Data>> for TimelineBuilderA
 /// corresponds to the specific timeline being created.
 ///
 /// Refer to the `macroless_timeline` example for details on how the two are connected.
+///
+/// # Optional settings
+///
+/// [`fill_mode`](Self::fill_mode), [`marker`](Self::marker),
+/// [`iteration_start`](Self::iteration_start),
+/// [`repeat_delay_seconds`](Self::repeat_delay_seconds) and [`direction`](Self::direction) have
+/// default implementations, so that builders written before these settings existed continue to
+/// compile. The default implementations **ignore** the setting: a builder that does not override
+/// them silently produces timelines that fill both ways, have no markers, start at the beginning
+/// of the first cycle, have no repeat delay and play every cycle forward, regardless of what was
+/// configured. Custom builders should override every setting that their timelines support.
+/// [`TimelineConfiguration`] overrides all of them.
 pub trait TimelineConfigurationBuilder {
     /// Data type used for keyframes in this timeline.
     ///
@@ -215,6 +341,20 @@ pub trait TimelineConfigurationBuilder {
     /// first if a [`repeat`](Self::repeat) setting is specified.
     fn delay_seconds(self, delay_seconds: f32) -> Self;
 
+    /// Configures whether the animation applies its start values during the
+    /// [delay](Self::delay_seconds) and keeps its end values after it ends.
+    ///
+    /// The default is [`FillMode::Both`].
+    ///
+    /// Builders that do not support fill modes can rely on the default implementation, which
+    /// ignores the setting.
+    fn fill_mode(self, _fill_mode: FillMode) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Configures the animation duration, in seconds.
     ///
     /// If the animation [repeats](Self::repeat), this is the duration of each cycle. If the
@@ -227,14 +367,90 @@ pub trait TimelineConfigurationBuilder {
     /// keyframe along with its specific typed data.
     fn keyframe(self, builder: impl KeyframeBuilder<Data = Self::KeyframeData>) -> Self;
 
+    /// Adds a named [`Marker`] to the animation, which is reported by
+    /// [`Timeline::markers_between`] whenever it is crossed.
+    ///
+    /// Markers have no effect on the animated values. Builders that do not support markers can rely
+    /// on the default implementation, which ignores the marker.
+    fn marker(self, _marker: Marker) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Configures the position, in cycles, at which the animation starts.
+    ///
+    /// This is equivalent to the CSS `iteration-start` property. For example, an iteration start of
+    /// `0.5` starts the animation halfway through the first cycle. The total duration is not
+    /// affected, so the animation also ends halfway through a cycle. The default is `0.0`.
+    ///
+    /// Builders that do not support an iteration start can rely on the default implementation,
+    /// which ignores the setting.
+    fn iteration_start(self, _iteration_start: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Configures the number of repetitions (cycles).
     fn repeat(self, repeat: Repeat) -> Self;
 
+    /// Configures the time to wait, in seconds, between the end of each cycle and the start of the
+    /// next one.
+    ///
+    /// This only has an effect if the animation [repeats](Self::repeat). The repeat delay is not
+    /// included in the [`duration_seconds`](Self::duration_seconds); the values at the end of each
+    /// cycle are held until the delay has elapsed. The default is `0.0`.
+    ///
+    /// Builders that do not support repeat delays can rely on the default implementation, which
+    /// ignores the setting.
+    fn repeat_delay_seconds(self, _repeat_delay_seconds: f32) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
     /// Configures whether or not the animation should automatically reverse.
     ///
     /// Reversing takes up the second half of any given cycle and uses the same keyframes, easing
-    /// and other timing properties as the normal forward animation.
+    /// and other timing properties as the normal forward animation. To play entire cycles
+    /// backward, or alternate between forward and backward cycles, use
+    /// [`direction`](Self::direction) instead.
     fn reverse(self, reverse: bool) -> Self;
+
+    /// Configures the direction in which each cycle of the animation plays.
+    ///
+    /// The default is [`PlaybackDirection::Normal`].
+    ///
+    /// Builders that do not support playback directions can rely on the default implementation,
+    /// which ignores the setting.
+    fn direction(self, _direction: PlaybackDirection) -> Self
+    where
+        Self: Sized,
+    {
+        self
+    }
+
+    /// Configures the animation to follow the motion of a [`Spring`], instead of a fixed duration
+    /// and easing curve.
+    ///
+    /// This is shorthand for setting the [`default_easing`](Self::default_easing) to the spring's
+    /// [easing](Spring::easing) and the [`duration_seconds`](Self::duration_seconds) to its
+    /// [settle duration](Spring::settle_duration). Springs are intended for timelines with a single
+    /// segment, i.e. only a start and end keyframe; with more keyframes, each segment plays the
+    /// entire spring motion in a fraction of the settle duration.
+    fn spring(self, spring: Spring) -> Self
+    where
+        Self: Sized,
+    {
+        let easing = spring.easing();
+        let duration_seconds = easing.duration();
+        self.default_easing(Easing::Custom(Box::new(easing)))
+            .duration_seconds(duration_seconds)
+    }
 }
 
 /// Standard [`TimelineConfigurationBuilder`] implementation using backing fields.
@@ -245,9 +461,14 @@ pub trait TimelineConfigurationBuilder {
 pub struct TimelineConfiguration<Data: Clone + Debug> {
     default_easing: Easing,
     delay_seconds: f32,
+    direction: PlaybackDirection,
     duration_seconds: f32,
+    fill_mode: FillMode,
+    iteration_start: f32,
     keyframes: Vec<Keyframe<Data>>,
+    markers: Vec<Marker>,
     repeat: Repeat,
+    repeat_delay_seconds: f32,
     reverse: bool,
 }
 
@@ -256,9 +477,14 @@ impl<Data: Clone + Debug> Default for TimelineConfiguration<Data> {
         Self {
             default_easing: Easing::default(),
             delay_seconds: 0.0,
+            direction: PlaybackDirection::default(),
             duration_seconds: 1.0,
+            fill_mode: FillMode::default(),
+            iteration_start: 0.0,
             keyframes: Vec::new(),
+            markers: Vec::new(),
             repeat: Repeat::None,
+            repeat_delay_seconds: 0.0,
             reverse: false,
         }
     }
@@ -277,21 +503,46 @@ impl<Data: Clone + Debug> TimelineConfigurationBuilder for TimelineConfiguration
         self
     }
 
+    fn direction(mut self, direction: PlaybackDirection) -> Self {
+        self.direction = direction;
+        self
+    }
+
     fn duration_seconds(mut self, duration_seconds: f32) -> Self {
         self.duration_seconds = duration_seconds;
         self
     }
 
+    fn fill_mode(mut self, fill_mode: FillMode) -> Self {
+        self.fill_mode = fill_mode;
+        self
+    }
+
+    fn iteration_start(mut self, iteration_start: f32) -> Self {
+        self.iteration_start = iteration_start;
+        self
+    }
+
     fn keyframe(mut self, builder: impl KeyframeBuilder<Data = Self::KeyframeData>) -> Self {
         self.keyframes.push(builder.build());
         self
     }
 
+    fn marker(mut self, marker: Marker) -> Self {
+        self.markers.push(marker);
+        self
+    }
+
     fn repeat(mut self, repeat: Repeat) -> Self {
         self.repeat = repeat;
         self
     }
 
+    fn repeat_delay_seconds(mut self, repeat_delay_seconds: f32) -> Self {
+        self.repeat_delay_seconds = repeat_delay_seconds;
+        self
+    }
+
     fn reverse(mut self, reverse: bool) -> Self {
         self.reverse = reverse;
         self
@@ -300,7 +551,17 @@ impl<Data: Clone + Debug> TimelineConfigurationBuilder for TimelineConfiguration
 
 impl<Data: Clone + Debug> TimelineConfiguration<Data> {
     fn create_timescale(&self) -> TimeScale {
-        TimeScale::new(self.duration_seconds, self.delay_seconds, self.repeat, self.reverse)
+        TimeScale::new(
+            self.duration_seconds,
+            self.delay_seconds,
+            self.repeat,
+            self.reverse,
+        )
+        .with_direction(self.direction)
+        .with_fill_mode(self.fill_mode)
+        .with_iteration_start(self.iteration_start)
+        .with_markers(self.markers.clone())
+        .with_repeat_delay(self.repeat_delay_seconds)
     }
 
     fn get_boundary_times(&self) -> Vec<f32> {
@@ -407,6 +668,16 @@ impl<T: Timeline + Clone> Clone for MergedTimeline<T> {
     }
 }
 
+impl<T: BakeProperties> BakeProperties for MergedTimeline<T> {
+    fn bake_properties(&self, sample_rate: f32) -> Self {
+        MergedTimeline::of(
+            self.timelines
+                .iter()
+                .map(|t| t.bake_properties(sample_rate)),
+        )
+    }
+}
+
 impl<T: Timeline> From<T> for MergedTimeline<T> {
     fn from(value: T) -> Self {
         MergedTimeline::of([value])
@@ -440,11 +711,21 @@ impl<T: Timeline> Timeline for MergedTimeline<T> {
             .unwrap_or(0.)
     }
 
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        let mut crossings = self
+            .timelines
+            .iter()
+            .flat_map(|t| t.markers_between(prev_time, time))
+            .collect::<Vec<_>>();
+        sort_crossings(&mut crossings, time < prev_time);
+        crossings
+    }
+
     fn repeat(&self) -> Repeat {
         self.timelines
             .iter()
             .map(|t| t.repeat())
-            .max()
+            .max_by(Repeat::cmp_repetitions)
             .unwrap_or(Repeat::None)
     }
 
@@ -454,181 +735,1172 @@ impl<T: Timeline> Timeline for MergedTimeline<T> {
         }
     }
 
+    fn start_with_velocity(
+        &mut self,
+        values: &Self::Target,
+        next_values: &Self::Target,
+        interval: f32,
+    ) {
+        for timeline in self.timelines.iter_mut() {
+            timeline.start_with_velocity(values, next_values, interval);
+        }
+    }
+
+    fn responds_to_velocity(&self) -> bool {
+        self.timelines.iter().any(|t| t.responds_to_velocity())
+    }
+
     fn update(&self, values: &mut Self::Target, time: f32) {
         for timeline in &self.timelines {
             timeline.update(values, time);
         }
     }
+
+    fn update_velocity_scaled(&self, velocities: &mut Self::Target, time: f32, scale: f32) {
+        for timeline in &self.timelines {
+            timeline.update_velocity_scaled(velocities, time, scale);
+        }
+    }
 }
 
-/// Describes the looping behavior of an animation timeline.
-#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
-pub enum Repeat {
-    /// Animation does not repeat; it plays once and then ends.
-    #[default]
-    None,
-    /// Animation repeats for a given number of cycles, looping or reversing back to the beginning
-    /// each time. Ends after the last cycle is completed.
-    Times(u32),
-    /// Animation repeats infinitely and never ends, looping or reversing back to the beginning each
-    /// time it repeats.
-    Infinite,
+/// A [Timeline] that plays multiple inner timelines one after another.
+///
+/// Where a [`MergedTimeline`] runs all of its timelines in parallel with a shared start time, a
+/// sequence starts each timeline only after the previous one has ended, i.e. after its entire
+/// [`duration`](Timeline::duration) including any delay and repetitions. This is useful for
+/// multi-stage effects such as "slide in, then pulse, then fade out" that would otherwise require
+/// very carefully-timed keyframes or delays.
+///
+/// Each timeline after the first is [blended](Timeline::start_with) from the values at which the
+/// previous timelines ended, so that properties shared by consecutive stages animate smoothly
+/// instead of jumping to the next stage's own 0% keyframe. Properties that are not animated by any
+/// earlier stage keep their originally-configured start values.
+///
+/// The sequence as a whole can also [repeat](SequenceTimelineBuilder::repeat). Similar to a normal
+/// timeline, the [`delay`](Timeline::delay) of the first stage is only applied once, and every
+/// subsequent cycle replays the stages from the beginning of the first stage's animation.
+///
+/// Sequences can be used anywhere a [`TimelineOrBuilder`] is accepted, including
+/// [`StateAnimatorBuilder::on`](crate::animator::StateAnimatorBuilder::on); to use regular
+/// timelines for other states of the same animator, convert them with [`SequenceTimeline::from`].
+pub struct SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    timelines: Vec<MergedTimeline<T>>,
+    repeat_timelines: Vec<MergedTimeline<T>>,
+    start_values: Vec<T::Target>,
+    repeat: Repeat,
 }
 
-impl PartialOrd<Self> for Repeat {
-    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        Some(self.cmp(other))
+impl<T> SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    /// Creates a non-repeating [`SequenceTimeline`] from a series of timelines or timeline
+    /// builders, which will be played in the same order.
+    ///
+    /// To configure a repeating sequence, use the [`SequenceTimelineBuilder`].
+    pub fn of(timelines: impl IntoIterator<Item = impl TimelineOrBuilder<T>>) -> Self {
+        Self::new(
+            timelines.into_iter().map(|t| t.build()).collect(),
+            Repeat::None,
+        )
+    }
+
+    fn new(timelines: Vec<MergedTimeline<T>>, repeat: Repeat) -> Self {
+        // Start values must be captured before any linking takes place, since linking applies the
+        // start overrides that we need to avoid here.
+        let start_values = timelines
+            .iter()
+            .map(|timeline| {
+                let mut values = T::Target::default();
+                timeline.update(&mut values, 0.0);
+                values
+            })
+            .collect::<Vec<_>>();
+        let mut sequence = Self {
+            repeat_timelines: timelines.clone(),
+            timelines,
+            start_values,
+            repeat,
+        };
+        link_sequence(&mut sequence.timelines, &sequence.start_values);
+        link_sequence(&mut sequence.repeat_timelines, &sequence.start_values);
+        sequence
+    }
+
+    fn get_cycle_position(&self, time: f32) -> (&[MergedTimeline<T>], f32) {
+        let delay = self.delay();
+        let cycle_duration = self.sequence_duration() - delay;
+        if self.repeat == Repeat::None
+            || time <= delay
+            || cycle_duration <= 0.0
+            || !cycle_duration.is_finite()
+        {
+            return (&self.timelines, time);
+        }
+        // Past the end of the last cycle, the sequence holds its position at the end time, which
+        // may be partway through a cycle if the repeat is fractional.
+        let time = (time - delay).min(cycle_duration * (self.repeat.repetitions() + 1.0));
+        // Same end-of-cycle adjustment as the TimeScale uses, so that the final values of the last
+        // stage are actually reached at the end of every cycle.
+        let (quot, rem) = (time / cycle_duration, time % cycle_duration);
+        let (cycle_time, is_repeating) = if rem == 0.0 && quot >= 1.0 {
+            (cycle_duration, quot > 1.0)
+        } else {
+            (rem, quot >= 1.0)
+        };
+        let timelines = if is_repeating {
+            &self.repeat_timelines
+        } else {
+            &self.timelines
+        };
+        (timelines, delay + cycle_time)
     }
-}
 
-impl Ord for Repeat {
-    fn cmp(&self, other: &Self) -> Ordering {
-        self.as_ordinal().cmp(&other.as_ordinal())
+    fn sequence_duration(&self) -> f32 {
+        self.timelines.iter().map(|t| t.duration()).sum()
     }
 }
 
-impl Repeat {
-    pub(super) fn as_ordinal(&self) -> u32 {
-        match self {
-            Repeat::None => 0,
-            Repeat::Times(value) => *value,
-            Repeat::Infinite => u32::MAX,
+impl<T> Clone for SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    fn clone(&self) -> Self {
+        Self {
+            timelines: self.timelines.clone(),
+            repeat_timelines: self.repeat_timelines.clone(),
+            start_values: self.start_values.clone(),
+            repeat: self.repeat,
         }
     }
 }
 
-/// Helper function typically used by [`Timeline`] implementations at the beginning of their
-/// [`update`](Timeline::update) method, which performs lookup tasks common to all timelines,
-/// including converting real time to normalized time and finding the closest frame.
-///
-/// Encapsulates all of the generic logic that does _not_ require knowing the specific
-/// [SubTimeline](crate::timeline_helpers::SubTimeline) fields and types.
-pub fn prepare_frame(
-    time: f32,
-    boundary_times: &[f32],
-    timescale: &TimeScale,
-) -> Option<(f32, usize, bool)> {
-    if boundary_times.is_empty() {
-        return None;
+impl<T> From<T> for SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    fn from(value: T) -> Self {
+        SequenceTimeline::of([MergedTimeline::from(value)])
     }
-    let (normalized_time, enable_start_override) = match timescale.get_position(time) {
-        TimeScalePosition::Active(t, loop_state) =>
-            (t, !loop_state.is_repeating && !loop_state.is_reversing),
-        TimeScalePosition::NotStarted => (0.0, true),
-        TimeScalePosition::Ended(t) => (t, false),
-    };
-    let frame_index = match boundary_times.binary_search_by(|t| t.total_cmp(&normalized_time)) {
-        Ok(index) => index,
-        Err(next_index) => next_index.max(1) - 1,
-    };
-    Some((normalized_time, frame_index, enable_start_override))
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use ordered_float::OrderedFloat;
-    use std::collections::HashMap;
+impl<T> Timeline for SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    type Target = T::Target;
 
-    #[derive(Debug, Default, PartialEq)]
-    struct TestValues {
-        foo: u8,
-        bar: u32,
-        baz: f32,
+    fn cycle_duration(&self) -> Option<f32> {
+        let sequence_duration = self.sequence_duration();
+        sequence_duration
+            .is_finite()
+            .then(|| sequence_duration - self.delay())
     }
 
-    // Setting up a timeline without proc macros requires a lot of boilerplate, so for the purposes
-    // of testing merged timelines, we instead use fake timelines here. The stub is only capable of
-    // producing exact matches, i.e. does not interpolate between times.
-    #[derive(Clone)]
-    struct StubTimeline {
-        cycle_duration: Option<f32>,
-        delay: f32,
-        duration: f32,
-        repeat: Repeat,
-        frames: HashMap<OrderedFloat<f32>, StubFrame>,
+    fn delay(&self) -> f32 {
+        self.timelines.first().map(|t| t.delay()).unwrap_or(0.)
     }
 
-    impl StubTimeline {
-        fn new() -> Self {
-            Self {
-                cycle_duration: None,
-                delay: 0.,
-                duration: 0.,
-                repeat: Repeat::None,
-                frames: HashMap::new(),
-            }
+    fn duration(&self) -> f32 {
+        let sequence_duration = self.sequence_duration();
+        let delay = self.delay();
+        match self.repeat {
+            Repeat::None => sequence_duration,
+            repeat => delay + (sequence_duration - delay) * (repeat.repetitions() + 1.0).max(0.0),
         }
+    }
 
-        fn add_frame(
-            mut self,
-            time: f32,
-            foo: Option<u8>,
-            bar: Option<u32>,
-            baz: Option<f32>,
-        ) -> Self {
-            self.frames
-                .insert(OrderedFloat(time), StubFrame { foo, bar, baz });
-            self
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        let delay = self.delay();
+        let cycle_duration = self.sequence_duration() - delay;
+        let (cycle_count, end_time) = match self.repeat {
+            _ if cycle_duration <= 0.0 || !cycle_duration.is_finite() => (1.0, f32::INFINITY),
+            Repeat::None => (1.0, self.duration()),
+            repeat => {
+                let iteration_count = (repeat.repetitions() + 1.0).max(0.0);
+                (iteration_count.ceil(), self.duration())
+            }
+        };
+        // Cycles after the first replay the stages from the start of the first stage's animation,
+        // so each cycle is just a copy of the first one, shifted by the cycle duration.
+        let cycle_index_at = |time: f32| {
+            let cycle_index = ((time - delay) / cycle_duration).floor().max(0.0);
+            cycle_index.min(cycle_count - 1.0)
+        };
+        let (first_cycle, last_cycle) = (
+            cycle_index_at(prev_time.min(time)),
+            cycle_index_at(prev_time.max(time)),
+        );
+        if !first_cycle.is_finite() || !last_cycle.is_finite() {
+            return Vec::new();
         }
-
-        fn set_cycle_duration(mut self, duration: f32) -> Self {
-            self.cycle_duration = Some(duration);
-            self
+        let mut crossings = Vec::new();
+        for cycle_index in first_cycle as u32..=last_cycle as u32 {
+            let cycle_offset = if cycle_count > 1.0 {
+                cycle_index as f32 * cycle_duration
+            } else {
+                0.0
+            };
+            let mut stage_offset = 0.0;
+            for timeline in &self.timelines {
+                let offset = cycle_offset + stage_offset;
+                crossings.extend(
+                    timeline
+                        .markers_between(prev_time - offset, time - offset)
+                        .into_iter()
+                        .map(|crossing| MarkerCrossing {
+                            time: crossing.time + offset,
+                            cycle: cycle_index,
+                            ..crossing
+                        })
+                        .filter(|crossing| crossing.time <= end_time),
+                );
+                stage_offset += timeline.duration();
+                if !stage_offset.is_finite() {
+                    break;
+                }
+            }
         }
+        sort_crossings(&mut crossings, time < prev_time);
+        crossings
+    }
 
-        fn set_delay(mut self, delay: f32) -> Self {
-            self.delay = delay;
-            self
-        }
+    fn repeat(&self) -> Repeat {
+        self.repeat
+    }
 
-        fn set_duration(mut self, duration: f32) -> Self {
-            self.duration = duration;
-            self
+    fn start_with(&mut self, values: &Self::Target) {
+        if let Some(first_timeline) = self.timelines.first_mut() {
+            first_timeline.start_with(values);
         }
+        link_sequence(&mut self.timelines, &self.start_values);
+    }
 
-        fn set_repeat(mut self, repeat: Repeat) -> Self {
-            self.repeat = repeat;
-            self
+    fn start_with_velocity(
+        &mut self,
+        values: &Self::Target,
+        next_values: &Self::Target,
+        interval: f32,
+    ) {
+        if let Some(first_timeline) = self.timelines.first_mut() {
+            first_timeline.start_with_velocity(values, next_values, interval);
         }
+        link_sequence(&mut self.timelines, &self.start_values);
     }
 
-    impl Timeline for StubTimeline {
-        type Target = TestValues;
+    fn responds_to_velocity(&self) -> bool {
+        self.timelines
+            .first()
+            .is_some_and(|timeline| timeline.responds_to_velocity())
+    }
 
-        fn cycle_duration(&self) -> Option<f32> {
-            self.cycle_duration
+    fn update(&self, values: &mut Self::Target, time: f32) {
+        let (timelines, cycle_time) = self.get_cycle_position(time);
+        let mut offset = 0.0;
+        for (index, timeline) in timelines.iter().enumerate() {
+            let duration = timeline.duration();
+            if cycle_time < offset + duration || index == timelines.len() - 1 {
+                timeline.update(values, cycle_time - offset);
+                return;
+            }
+            // Completed stages are always brought to their final values, even if the previous
+            // update was in the middle of that stage, so that large time steps cannot leave behind
+            // properties which are not animated by any later stage.
+            timeline.update(values, duration);
+            offset += duration;
         }
+    }
 
-        fn delay(&self) -> f32 {
-            self.delay
+    fn update_velocity_scaled(&self, velocities: &mut Self::Target, time: f32, scale: f32) {
+        let (timelines, cycle_time) = self.get_cycle_position(time);
+        let mut offset = 0.0;
+        for (index, timeline) in timelines.iter().enumerate() {
+            let duration = timeline.duration();
+            if cycle_time < offset + duration || index == timelines.len() - 1 {
+                timeline.update_velocity_scaled(velocities, cycle_time - offset, scale);
+                return;
+            }
+            // Properties of completed stages are no longer moving, which a zero scale expresses
+            // regardless of what the stage's own velocity would be at its end.
+            timeline.update_velocity_scaled(velocities, duration, 0.0);
+            offset += duration;
         }
+    }
+}
 
-        fn duration(&self) -> f32 {
-            self.duration
-        }
+impl<T> TimelineOrBuilder<SequenceTimeline<T>> for SequenceTimeline<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    fn build(self) -> MergedTimeline<SequenceTimeline<T>> {
+        MergedTimeline::of([self])
+    }
+}
 
-        fn repeat(&self) -> Repeat {
-            self.repeat
-        }
+/// Sorts marker crossings from multiple timelines into the order in which they are crossed.
+///
+/// The sort is stable, so that simultaneous crossings keep the order of their timelines, or the
+/// exact opposite order when moving backward.
+fn sort_crossings(crossings: &mut [MarkerCrossing], is_backward: bool) {
+    crossings.sort_by(|a, b| a.time.total_cmp(&b.time));
+    if is_backward {
+        crossings.reverse();
+    }
+}
 
-        fn start_with(&mut self, values: &Self::Target) {
-            if let Some(first_frame) = self.frames.get_mut(&OrderedFloat(0.0)) {
-                first_frame.foo = Some(values.foo);
-                first_frame.bar = Some(values.bar);
-                first_frame.baz = Some(values.baz);
+/// Blends every timeline in a sequence, after the first, from the values at which all previous
+/// timelines in the sequence end.
+fn link_sequence<T>(timelines: &mut [MergedTimeline<T>], start_values: &[T::Target])
+where
+    T: Timeline,
+    T::Target: Clone,
+{
+    for index in 1..timelines.len() {
+        let mut values = start_values[index].clone();
+        for previous_timeline in &timelines[..index] {
+            let duration = previous_timeline.duration();
+            if !duration.is_finite() {
+                // None of the remaining timelines can ever be reached.
+                return;
             }
+            previous_timeline.update(&mut values, duration);
         }
+        timelines[index].start_with(&values);
+    }
+}
 
-        fn update(&self, values: &mut Self::Target, time: f32) {
-            if let Some(frame) = self.frames.get(&OrderedFloat(time)) {
-                if let Some(foo) = frame.foo {
-                    values.foo = foo;
-                }
-                if let Some(bar) = frame.bar {
-                    values.bar = bar;
-                }
-                if let Some(baz) = frame.baz {
+/// Fluent builder for a [`SequenceTimeline`].
+pub struct SequenceTimelineBuilder<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    repeat: Repeat,
+    timelines: Vec<MergedTimeline<T>>,
+}
+
+impl<T> Default for SequenceTimelineBuilder<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    fn default() -> Self {
+        Self {
+            repeat: Repeat::None,
+            timelines: Vec::new(),
+        }
+    }
+}
+
+impl<T> SequenceTimelineBuilder<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    /// Creates a new [`SequenceTimelineBuilder`] with no timelines.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Builds the [`SequenceTimeline`], consuming self.
+    pub fn build(self) -> SequenceTimeline<T> {
+        SequenceTimeline::new(self.timelines, self.repeat)
+    }
+
+    /// Configures the number of repetitions (cycles) of the entire sequence.
+    ///
+    /// This is independent of the repetitions of any individual timelines in the sequence; a
+    /// timeline with [`Repeat::Times(2)`](Repeat::Times) in a sequence with
+    /// [`Repeat::Times(1)`](Repeat::Times) will play 6 times in total.
+    pub fn repeat(mut self, repeat: Repeat) -> Self {
+        self.repeat = repeat;
+        self
+    }
+
+    /// Appends a timeline to the end of the sequence, which will start after all previously-added
+    /// timelines have ended.
+    ///
+    /// The `timeline` can be a [`Timeline`] of the same type as all other timelines in the
+    /// sequence, or a builder for that type, or a [`MergedTimeline`].
+    pub fn then(mut self, timeline: impl TimelineOrBuilder<T>) -> Self {
+        self.timelines.push(timeline.build());
+        self
+    }
+}
+
+impl<T> TimelineOrBuilder<SequenceTimeline<T>> for SequenceTimelineBuilder<T>
+where
+    T: Timeline + Clone,
+    T::Target: Clone + Default,
+{
+    fn build(self) -> MergedTimeline<SequenceTimeline<T>> {
+        MergedTimeline::of([SequenceTimelineBuilder::build(self)])
+    }
+}
+
+/// Extension methods for adapting any [`Timeline`].
+pub trait TimelineExt: Timeline + Sized {
+    /// Samples this timeline at a fixed `sample_rate` into a table that can be played back more
+    /// efficiently.
+    ///
+    /// Refer to [`BakedTimeline`] for details. Timelines generated by `derive(Animate)` can instead
+    /// be baked one property at a time with [`BakeProperties::bake_properties`], which does not
+    /// require the target to implement [`Lerp`] or [`Default`].
+    fn bake(&self, sample_rate: f32) -> BakedTimeline<Self::Target>
+    where
+        Self::Target: Clone + Default + Lerp,
+    {
+        BakedTimeline::new(self, sample_rate)
+    }
+
+    /// Plays only the part of this timeline between the `start` and `end` times.
+    ///
+    /// Refer to [`ClippedTimeline`] for details.
+    fn clip(self, start: ClipTime, end: ClipTime) -> ClippedTimeline<Self> {
+        ClippedTimeline::new(self, start, end)
+    }
+
+    /// Changes the playback rate of this timeline, without rebuilding it.
+    ///
+    /// Refer to [`ScaledTimeline`] for details.
+    fn with_speed(self, speed: f32) -> ScaledTimeline<Self> {
+        ScaledTimeline::new(self, speed)
+    }
+}
+
+impl<T: Timeline> TimelineExt for T {}
+
+/// A [`Timeline`] that plays an inner timeline at a different rate.
+///
+/// A `speed` of `2.0` plays the inner timeline twice as fast, i.e. in half the time, and a `speed`
+/// of `0.5` plays it in slow motion, at half the normal rate. All timing properties, including the
+/// [`delay`](Timeline::delay), [`duration`](Timeline::duration) and
+/// [`cycle_duration`](Timeline::cycle_duration), are scaled accordingly, so that the scaled
+/// timeline behaves exactly as if it had been configured with the scaled times.
+///
+/// Negative speeds play the timeline in reverse, starting from the end of its last cycle and
+/// ending at the start of its first cycle. Since the timeline starts playing immediately, the delay
+/// of the inner timeline is played at the end instead, and the reported delay is zero. Timelines
+/// that repeat infinitely have no end, so they remain at their start values when reversed. A speed
+/// of zero holds the start values forever, so the scaled timeline has no delay and an infinite
+/// duration.
+///
+/// Scaled timelines can be used anywhere a [`TimelineOrBuilder`] is accepted, including
+/// [`StateAnimatorBuilder::on`](crate::animator::StateAnimatorBuilder::on); to use regular
+/// timelines for other states of the same animator, convert them with [`ScaledTimeline::from`].
+#[derive(Clone, Debug)]
+pub struct ScaledTimeline<T: Timeline> {
+    speed: f32,
+    timeline: T,
+}
+
+impl<T: Timeline> ScaledTimeline<T> {
+    /// Creates a [`ScaledTimeline`] that plays the specified `timeline` at a relative `speed`.
+    ///
+    /// A NaN `speed` is treated as zero, i.e. the timeline holds its start values.
+    pub fn new(timeline: T, speed: f32) -> Self {
+        let speed = if speed.is_nan() { 0.0 } else { speed };
+        Self { speed, timeline }
+    }
+
+    /// Gets the playback rate relative to the inner timeline.
+    pub fn speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Gets a reference to the inner timeline.
+    pub fn timeline(&self) -> &T {
+        &self.timeline
+    }
+
+    // Converts a time on the inner timeline to the equivalent time on the scaled timeline, without
+    // regard to direction.
+    fn scale_duration(&self, inner_duration: f32) -> f32 {
+        if inner_duration == 0.0 {
+            0.0
+        } else {
+            inner_duration / self.speed.abs()
+        }
+    }
+
+    fn to_inner_time(&self, time: f32) -> f32 {
+        if self.speed >= 0.0 {
+            return time * self.speed;
+        }
+        let inner_duration = self.timeline.duration();
+        if inner_duration.is_finite() {
+            inner_duration + time * self.speed
+        } else {
+            0.0
+        }
+    }
+
+    fn to_outer_time(&self, inner_time: f32) -> f32 {
+        if self.speed >= 0.0 {
+            self.scale_duration(inner_time)
+        } else {
+            self.scale_duration(self.timeline.duration() - inner_time)
+        }
+    }
+}
+
+impl<T: Timeline> From<T> for ScaledTimeline<T> {
+    fn from(value: T) -> Self {
+        ScaledTimeline::new(value, 1.0)
+    }
+}
+
+impl<T: Timeline> Timeline for ScaledTimeline<T> {
+    type Target = T::Target;
+
+    fn cycle_duration(&self) -> Option<f32> {
+        self.timeline
+            .cycle_duration()
+            .map(|cycle_duration| self.scale_duration(cycle_duration))
+    }
+
+    fn delay(&self) -> f32 {
+        if self.speed > 0.0 {
+            self.scale_duration(self.timeline.delay())
+        } else {
+            0.0
+        }
+    }
+
+    fn duration(&self) -> f32 {
+        self.scale_duration(self.timeline.duration())
+    }
+
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        if self.speed < 0.0 && !self.timeline.duration().is_finite() {
+            return Vec::new();
+        }
+        self.timeline
+            .markers_between(self.to_inner_time(prev_time), self.to_inner_time(time))
+            .into_iter()
+            .map(|crossing| MarkerCrossing {
+                time: self.to_outer_time(crossing.time),
+                ..crossing
+            })
+            .collect()
+    }
+
+    fn repeat(&self) -> Repeat {
+        self.timeline.repeat()
+    }
+
+    fn start_with(&mut self, values: &Self::Target) {
+        self.timeline.start_with(values);
+    }
+
+    fn start_with_velocity(
+        &mut self,
+        values: &Self::Target,
+        next_values: &Self::Target,
+        interval: f32,
+    ) {
+        // The values change by the same amount in a scaled interval of the inner timeline, so the
+        // velocity is relative to the outer (scaled) time.
+        if self.speed == 0.0 {
+            self.timeline.start_with(values);
+        } else {
+            self.timeline
+                .start_with_velocity(values, next_values, interval * self.speed.abs());
+        }
+    }
+
+    fn responds_to_velocity(&self) -> bool {
+        self.speed != 0.0 && self.timeline.responds_to_velocity()
+    }
+
+    fn update(&self, values: &mut Self::Target, time: f32) {
+        self.timeline.update(values, self.to_inner_time(time));
+    }
+
+    fn update_velocity_scaled(&self, velocities: &mut Self::Target, time: f32, scale: f32) {
+        // Reversed infinite timelines are held at their start, and do not move at all.
+        let scale = if self.speed < 0.0 && !self.timeline.duration().is_finite() {
+            0.0
+        } else {
+            scale * self.speed
+        };
+        self.timeline
+            .update_velocity_scaled(velocities, self.to_inner_time(time), scale);
+    }
+}
+
+impl<T: Timeline> TimelineOrBuilder<ScaledTimeline<T>> for ScaledTimeline<T> {
+    fn build(self) -> MergedTimeline<ScaledTimeline<T>> {
+        MergedTimeline::of([self])
+    }
+}
+
+/// Specifies one end of the window played by a [`ClippedTimeline`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ClipTime {
+    /// Time on the original timeline, in the same units as its [`duration`](Timeline::duration),
+    /// generally seconds.
+    Seconds(f32),
+    /// Fraction of the original timeline's entire [`duration`](Timeline::duration), including any
+    /// delay and repetitions, from `0.0` to `1.0`.
+    ///
+    /// Timelines that repeat infinitely do not have a finite duration, so for those, the fraction
+    /// is relative to the delay and first cycle only.
+    Normalized(f32),
+}
+
+/// A [`Timeline`] that plays only part of an inner timeline.
+///
+/// Clipping selects a window of the inner timeline, between a start and end [`ClipTime`], and
+/// shifts it so that it starts at time zero. The [`duration`](Timeline::duration) is that of the
+/// window. Before the window starts, the values are the ones at the start of the window, and after
+/// it ends, they hold the ones at the end of the window. Optionally, the window can be
+/// [looped](Self::looping) instead, so that it replays indefinitely.
+///
+/// Start values given to [`start_with`](Timeline::start_with) are passed through to the inner
+/// timeline, and therefore only have an effect if the window includes the beginning of the inner
+/// timeline's first cycle.
+///
+/// Clipped timelines can be used anywhere a [`TimelineOrBuilder`] is accepted, including
+/// [`StateAnimatorBuilder::on`](crate::animator::StateAnimatorBuilder::on); to use regular
+/// timelines for other states of the same animator, convert them with [`ClippedTimeline::from`].
+#[derive(Clone, Debug)]
+pub struct ClippedTimeline<T: Timeline> {
+    end: f32,
+    looping: bool,
+    start: f32,
+    timeline: T,
+}
+
+impl<T: Timeline> ClippedTimeline<T> {
+    /// Creates a [`ClippedTimeline`] that plays the specified `timeline` from `start` to `end`.
+    ///
+    /// The window is limited to the inner timeline's duration. If `end` is before `start`, then
+    /// the window is empty and the timeline holds the values at `start`. A NaN `start` or `end` is
+    /// treated as the start or end of the inner timeline, respectively.
+    pub fn new(timeline: T, start: ClipTime, end: ClipTime) -> Self {
+        let total_duration = timeline.duration();
+        let reference_duration = if total_duration.is_finite() {
+            total_duration
+        } else {
+            timeline.delay() + timeline.cycle_duration().unwrap_or(0.0)
+        };
+        let resolve = |time: ClipTime, default: f32| match time {
+            ClipTime::Seconds(seconds) if seconds.is_nan() => default,
+            ClipTime::Normalized(fraction) if fraction.is_nan() => default,
+            ClipTime::Seconds(seconds) => seconds.clamp(0.0, total_duration),
+            ClipTime::Normalized(fraction) => fraction.clamp(0.0, 1.0) * reference_duration,
+        };
+        let start = resolve(start, 0.0);
+        let end = resolve(end, total_duration).max(start);
+        Self {
+            end,
+            looping: false,
+            start,
+            timeline,
+        }
+    }
+
+    /// Configures whether the window plays repeatedly, instead of ending at the end time.
+    pub fn looping(mut self, looping: bool) -> Self {
+        self.looping = looping;
+        self
+    }
+
+    /// Gets the start of the window, in the time units of the inner timeline.
+    pub fn start(&self) -> f32 {
+        self.start
+    }
+
+    /// Gets the end of the window, in the time units of the inner timeline.
+    pub fn end(&self) -> f32 {
+        self.end
+    }
+
+    /// Gets a reference to the inner timeline.
+    pub fn timeline(&self) -> &T {
+        &self.timeline
+    }
+
+    fn window_duration(&self) -> f32 {
+        self.end - self.start
+    }
+
+    fn to_inner_time(&self, time: f32) -> f32 {
+        let window_duration = self.window_duration();
+        let time = time.max(0.0);
+        if !self.looping || window_duration <= 0.0 {
+            return self.start + time.min(window_duration);
+        }
+        // Same end-of-cycle adjustment as the TimeScale uses, so that the values at the end of the
+        // window are actually reached before looping back to the start.
+        let (quot, rem) = (time / window_duration, time % window_duration);
+        if rem == 0.0 && quot >= 1.0 {
+            self.end
+        } else {
+            self.start + rem
+        }
+    }
+
+    // Markers crossed in a single pass through the window, where `offset` is the (outer) time at
+    // which the pass starts.
+    fn window_markers_between(
+        &self,
+        prev_time: f32,
+        time: f32,
+        offset: f32,
+        cycle: u32,
+    ) -> impl Iterator<Item = MarkerCrossing> + '_ {
+        let inner_offset = self.start - offset;
+        self.timeline
+            .markers_between(prev_time + inner_offset, time + inner_offset)
+            .into_iter()
+            .filter(|crossing| crossing.time >= self.start && crossing.time <= self.end)
+            .map(move |crossing| MarkerCrossing {
+                time: crossing.time - inner_offset,
+                cycle,
+                ..crossing
+            })
+    }
+}
+
+impl<T: Timeline> From<T> for ClippedTimeline<T> {
+    fn from(value: T) -> Self {
+        let duration = value.duration();
+        ClippedTimeline::new(value, ClipTime::Seconds(0.0), ClipTime::Seconds(duration))
+    }
+}
+
+impl<T: Timeline> Timeline for ClippedTimeline<T> {
+    type Target = T::Target;
+
+    fn cycle_duration(&self) -> Option<f32> {
+        Some(self.window_duration() - self.delay())
+    }
+
+    fn delay(&self) -> f32 {
+        if self.looping {
+            0.0
+        } else {
+            (self.timeline.delay() - self.start).clamp(0.0, self.window_duration())
+        }
+    }
+
+    fn duration(&self) -> f32 {
+        if self.looping && self.window_duration() > 0.0 {
+            f32::INFINITY
+        } else {
+            self.window_duration()
+        }
+    }
+
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        let window_duration = self.window_duration();
+        if !self.looping || window_duration <= 0.0 {
+            return self
+                .window_markers_between(prev_time, time, 0.0, 0)
+                .collect();
+        }
+        let cycle_index_at = |time: f32| (time / window_duration).floor().max(0.0);
+        // As with timescales, the end of one pass coincides with the start of the next, so the pass
+        // before the one containing the earlier time must also be checked.
+        let first_cycle = (cycle_index_at(prev_time.min(time)) - 1.0).max(0.0);
+        let last_cycle = cycle_index_at(prev_time.max(time));
+        if !last_cycle.is_finite() {
+            return Vec::new();
+        }
+        let mut crossings = Vec::new();
+        for cycle_index in first_cycle as u32..=last_cycle as u32 {
+            let offset = cycle_index as f32 * window_duration;
+            crossings.extend(self.window_markers_between(prev_time, time, offset, cycle_index));
+        }
+        sort_crossings(&mut crossings, time < prev_time);
+        crossings
+    }
+
+    fn repeat(&self) -> Repeat {
+        if self.looping {
+            Repeat::Infinite
+        } else {
+            Repeat::None
+        }
+    }
+
+    fn start_with(&mut self, values: &Self::Target) {
+        self.timeline.start_with(values);
+    }
+
+    fn start_with_velocity(
+        &mut self,
+        values: &Self::Target,
+        next_values: &Self::Target,
+        interval: f32,
+    ) {
+        self.timeline
+            .start_with_velocity(values, next_values, interval);
+    }
+
+    fn responds_to_velocity(&self) -> bool {
+        self.timeline.responds_to_velocity()
+    }
+
+    fn update(&self, values: &mut Self::Target, time: f32) {
+        self.timeline.update(values, self.to_inner_time(time));
+    }
+
+    fn update_velocity_scaled(&self, velocities: &mut Self::Target, time: f32, scale: f32) {
+        // Values are held, rather than moving, outside the window.
+        let is_held = time < 0.0 || (!self.looping && time > self.window_duration());
+        let scale = if is_held { 0.0 } else { scale };
+        self.timeline
+            .update_velocity_scaled(velocities, self.to_inner_time(time), scale);
+    }
+}
+
+impl<T: Timeline> TimelineOrBuilder<ClippedTimeline<T>> for ClippedTimeline<T> {
+    fn build(self) -> MergedTimeline<ClippedTimeline<T>> {
+        MergedTimeline::of([self])
+    }
+}
+
+/// Describes whether a timeline applies its values before it starts and after it ends, equivalent
+/// to the CSS
+/// [`animation-fill-mode`](https://developer.mozilla.org/en-US/docs/Web/CSS/animation-fill-mode)
+/// property.
+///
+/// The "before" period is the [`delay`](Timeline::delay) and the "after" period is any time beyond
+/// the timeline's [`duration`](Timeline::duration). A timeline that does not fill a period leaves
+/// the target values untouched during that period, so that they keep whatever values they had from
+/// other sources, such as a previous timeline or the application itself.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum FillMode {
+    /// Values are only updated while the animation is active, i.e. neither during the delay nor
+    /// after the animation has ended.
+    None,
+    /// Values at the end of the animation are retained after it ends.
+    Forwards,
+    /// Values at the start of the animation (the 0% keyframe) are applied during the delay.
+    Backwards,
+    /// Combines [`Forwards`](Self::Forwards) and [`Backwards`](Self::Backwards): the start values
+    /// are applied during the delay and the end values are retained after the animation ends.
+    #[default]
+    Both,
+}
+
+impl FillMode {
+    /// Gets whether the start values are applied during the delay period.
+    pub fn fills_backwards(&self) -> bool {
+        matches!(self, Self::Backwards | Self::Both)
+    }
+
+    /// Gets whether the end values are retained after the animation has ended.
+    pub fn fills_forwards(&self) -> bool {
+        matches!(self, Self::Forwards | Self::Both)
+    }
+}
+
+/// Describes the direction in which each cycle of a timeline plays, equivalent to the CSS
+/// [`animation-direction`](https://developer.mozilla.org/en-US/docs/Web/CSS/animation-direction)
+/// property.
+///
+/// Unlike the [`reverse`](TimelineConfigurationBuilder::reverse) setting, which plays forward and
+/// backward within a single cycle, each pass in a given direction takes up an entire cycle. Passes
+/// that play backward also run their easing backward, so that e.g. an `ease-in` curve looks like
+/// an `ease-out` curve when reversed, as specified by CSS.
+///
+/// If both are used, then the direction applies to the entire cycle including its reversal, i.e. a
+/// reversed cycle starts and ends at the 100% keyframe and reaches the 0% keyframe halfway through.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum PlaybackDirection {
+    /// Every cycle plays forward, from the 0% keyframe to the 100% keyframe.
+    #[default]
+    Normal,
+    /// Every cycle plays backward, from the 100% keyframe to the 0% keyframe.
+    Reverse,
+    /// The first cycle plays forward, and subsequent cycles alternate between playing backward and
+    /// forward.
+    Alternate,
+    /// The first cycle plays backward, and subsequent cycles alternate between playing forward and
+    /// backward.
+    AlternateReverse,
+}
+
+impl PlaybackDirection {
+    /// Gets whether the cycle with the specified (zero-based) index plays backward.
+    pub fn is_cycle_reversed(&self, cycle_index: u32) -> bool {
+        match self {
+            Self::Normal => false,
+            Self::Reverse => true,
+            Self::Alternate => cycle_index % 2 == 1,
+            Self::AlternateReverse => !Self::Alternate.is_cycle_reversed(cycle_index),
+        }
+    }
+}
+
+/// Describes the looping behavior of an animation timeline.
+///
+/// Equality is structural, so e.g. `Repeat::Times(2)` is not equal to `Repeat::Fractional(2.0)`
+/// even though both play the same number of cycles. To compare the number of cycles, use
+/// [`cmp_repetitions`](Self::cmp_repetitions).
+///
+/// **Breaking change:** `Repeat` no longer implements [Eq] (only [PartialEq]), because
+/// [`Fractional`](Self::Fractional) holds an `f32`. Code that relied on `Repeat: Eq`, e.g. as part
+/// of a derived `Eq` or a [HashMap](std::collections::HashMap) key, needs to compare repeats
+/// with [`cmp_repetitions`](Self::cmp_repetitions) or store the [repetitions](Self::repetitions)
+/// instead.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Repeat {
+    /// Animation does not repeat; it plays once and then ends.
+    #[default]
+    None,
+    /// Animation repeats for a given number of cycles, looping or reversing back to the beginning
+    /// each time. Ends after the last cycle is completed.
+    Times(u32),
+    /// Animation repeats for a possibly-fractional number of cycles, and ends partway through the
+    /// last cycle if the number is not whole.
+    ///
+    /// As with [`Times`](Self::Times), the value is the number of repetitions _after_ the first
+    /// cycle; for example, `Repeat::Fractional(1.5)` plays the animation 2.5 times, equivalent to
+    /// the CSS `animation-iteration-count: 2.5`. Values below `-1.0` are treated as `-1.0`, i.e.
+    /// the animation has no active duration.
+    Fractional(f32),
+    /// Animation repeats infinitely and never ends, looping or reversing back to the beginning each
+    /// time it repeats.
+    Infinite,
+}
+
+impl Repeat {
+    /// Creates a [`Repeat`] that plays a total of `count` cycles, including the first, which is
+    /// equivalent to the CSS `animation-iteration-count`.
+    ///
+    /// Whole numbers of cycles use [`Repeat::None`] or [`Repeat::Times`], other numbers use
+    /// [`Repeat::Fractional`] and an infinite `count` uses [`Repeat::Infinite`]. A `count` that is
+    /// not a number is treated as `1.0`.
+    ///
+    /// Since every variant counts repetitions _after_ the first cycle, a `count` of less than one
+    /// cycle is encoded as a _negative_ fractional repetition, e.g. `Repeat::iterations(0.5)` is
+    /// `Repeat::Fractional(-0.5)`: the first cycle is cut short by half a cycle. Prefer this
+    /// constructor over writing such values by hand.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use mina_core::timeline::Repeat;
+    ///
+    /// assert_eq!(Repeat::iterations(1.0), Repeat::None);
+    /// assert_eq!(Repeat::iterations(3.0), Repeat::Times(2));
+    /// assert_eq!(Repeat::iterations(0.5), Repeat::Fractional(-0.5));
+    /// ```
+    pub fn iterations(count: f32) -> Self {
+        if count.is_nan() || count == 1.0 {
+            Repeat::None
+        } else if count == f32::INFINITY {
+            Repeat::Infinite
+        } else if count > 1.0 && count.fract() == 0.0 && count <= u32::MAX as f32 {
+            Repeat::Times((count - 1.0) as u32)
+        } else {
+            Repeat::Fractional(count - 1.0)
+        }
+    }
+
+    /// Compares the number of [repetitions](Self::repetitions) of two repeats, regardless of how
+    /// they are specified; e.g. `Repeat::Times(2)` and `Repeat::Fractional(2.0)` are equal.
+    pub fn cmp_repetitions(&self, other: &Self) -> Ordering {
+        self.repetitions().total_cmp(&other.repetitions())
+    }
+
+    /// Gets the number of repetitions after the first cycle, which is [f32::INFINITY] for
+    /// [`Repeat::Infinite`].
+    pub fn repetitions(&self) -> f32 {
+        match self {
+            Repeat::None => 0.0,
+            Repeat::Times(value) => *value as f32,
+            Repeat::Fractional(value) => value.max(-1.0),
+            Repeat::Infinite => f32::INFINITY,
+        }
+    }
+}
+
+/// Helper function typically used by [`Timeline`] implementations at the beginning of their
+/// [`update`](Timeline::update) method, which performs lookup tasks common to all timelines,
+/// including converting real time to normalized time and finding the closest frame.
+///
+/// Encapsulates all of the generic logic that does _not_ require knowing the specific
+/// [SubTimeline](crate::timeline_helpers::SubTimeline) fields and types.
+///
+/// Returns [`None`] if the timeline has no keyframes, or if the `time` is before the start or after
+/// the end of the timeline and its [`FillMode`] does not fill that period; in both cases, the
+/// timeline should not update any values.
+pub fn prepare_frame(
+    time: f32,
+    boundary_times: &[f32],
+    timescale: &TimeScale,
+) -> Option<(f32, usize, bool)> {
+    if boundary_times.is_empty() {
+        return None;
+    }
+    let fill_mode = timescale.get_fill_mode();
+    let (normalized_time, enable_start_override) = match timescale.get_position(time) {
+        TimeScalePosition::Active(t, loop_state) => {
+            (t, !loop_state.is_repeating && !loop_state.is_reversing)
+        }
+        TimeScalePosition::NotStarted if fill_mode.fills_backwards() => {
+            (timescale.get_start_position(), true)
+        }
+        TimeScalePosition::Ended(t) if fill_mode.fills_forwards() => (t, false),
+        TimeScalePosition::NotStarted | TimeScalePosition::Ended(_) => return None,
+    };
+    let frame_index = find_frame_index(boundary_times, normalized_time);
+    Some((normalized_time, frame_index, enable_start_override))
+}
+
+/// Finds the index of the keyframe containing the `normalized_time`, given the normalized times of
+/// all keyframes in order.
+pub(crate) fn find_frame_index(boundary_times: &[f32], normalized_time: f32) -> usize {
+    match boundary_times.binary_search_by(|t| t.total_cmp(&normalized_time)) {
+        Ok(index) => index,
+        Err(next_index) => next_index.max(1) - 1,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ordered_float::OrderedFloat;
+    use std::collections::HashMap;
+
+    #[derive(Clone, Debug, Default, PartialEq)]
+    struct TestValues {
+        foo: u8,
+        bar: u32,
+        baz: f32,
+    }
+
+    // Setting up a timeline without proc macros requires a lot of boilerplate, so for the purposes
+    // of testing merged timelines, we instead use fake timelines here. The stub is only capable of
+    // producing exact matches, i.e. does not interpolate between times.
+    #[derive(Clone)]
+    struct StubTimeline {
+        cycle_duration: Option<f32>,
+        delay: f32,
+        duration: f32,
+        repeat: Repeat,
+        frames: HashMap<OrderedFloat<f32>, StubFrame>,
+        markers: Vec<(f32, &'static str)>,
+    }
+
+    impl StubTimeline {
+        fn new() -> Self {
+            Self {
+                cycle_duration: None,
+                delay: 0.,
+                duration: 0.,
+                repeat: Repeat::None,
+                frames: HashMap::new(),
+                markers: Vec::new(),
+            }
+        }
+
+        // Markers must be added in chronological order.
+        fn add_marker(mut self, time: f32, name: &'static str) -> Self {
+            self.markers.push((time, name));
+            self
+        }
+
+        fn add_frame(
+            mut self,
+            time: f32,
+            foo: Option<u8>,
+            bar: Option<u32>,
+            baz: Option<f32>,
+        ) -> Self {
+            self.frames
+                .insert(OrderedFloat(time), StubFrame { foo, bar, baz });
+            self
+        }
+
+        fn set_cycle_duration(mut self, duration: f32) -> Self {
+            self.cycle_duration = Some(duration);
+            self
+        }
+
+        fn set_delay(mut self, delay: f32) -> Self {
+            self.delay = delay;
+            self
+        }
+
+        fn set_duration(mut self, duration: f32) -> Self {
+            self.duration = duration;
+            self
+        }
+
+        fn set_repeat(mut self, repeat: Repeat) -> Self {
+            self.repeat = repeat;
+            self
+        }
+    }
+
+    impl Timeline for StubTimeline {
+        type Target = TestValues;
+
+        fn cycle_duration(&self) -> Option<f32> {
+            self.cycle_duration
+        }
+
+        fn delay(&self) -> f32 {
+            self.delay
+        }
+
+        fn duration(&self) -> f32 {
+            self.duration
+        }
+
+        fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+            let mut crossings = self
+                .markers
+                .iter()
+                .filter(|&&(t, _)| match time < prev_time {
+                    true => t >= time && t < prev_time,
+                    false => t > prev_time && t <= time,
+                })
+                .map(|&(t, name)| marker_crossing(name, t, 0))
+                .collect::<Vec<_>>();
+            if time < prev_time {
+                crossings.reverse();
+            }
+            crossings
+        }
+
+        fn repeat(&self) -> Repeat {
+            self.repeat
+        }
+
+        fn start_with(&mut self, values: &Self::Target) {
+            if let Some(first_frame) = self.frames.get_mut(&OrderedFloat(0.0)) {
+                first_frame.foo = Some(values.foo);
+                first_frame.bar = Some(values.bar);
+                first_frame.baz = Some(values.baz);
+            }
+        }
+
+        fn update(&self, values: &mut Self::Target, time: f32) {
+            if let Some(frame) = self.frames.get(&OrderedFloat(time)) {
+                if let Some(foo) = frame.foo {
+                    values.foo = foo;
+                }
+                if let Some(bar) = frame.bar {
+                    values.bar = bar;
+                }
+                if let Some(baz) = frame.baz {
                     values.baz = baz;
                 }
             }
@@ -642,137 +1914,872 @@ mod tests {
         baz: Option<f32>,
     }
 
-    mod merged_timeline {
-        use super::*;
+    fn marker_crossing(name: &str, time: f32, cycle: u32) -> MarkerCrossing {
+        MarkerCrossing {
+            name: name.to_string(),
+            time,
+            cycle,
+        }
+    }
+
+    mod merged_timeline {
+        use super::*;
+
+        #[test]
+        fn delegates_to_component_timelines() {
+            let timeline1 = StubTimeline::new()
+                .add_frame(0.1, Some(10), Some(555), Some(0.12))
+                .add_frame(0.2, Some(20), None, None)
+                .add_frame(0.3, Some(30), Some(777), None);
+            let timeline2 = StubTimeline::new()
+                .add_frame(0.1, None, None, Some(1.5))
+                .add_frame(0.2, None, None, Some(2.5))
+                .add_frame(0.3, None, None, Some(6.8));
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2]);
+
+            let mut values = <[TestValues; 3]>::default();
+            merged_timeline.update(&mut values[0], 0.1);
+            merged_timeline.update(&mut values[1], 0.2);
+            merged_timeline.update(&mut values[2], 0.3);
+
+            assert_eq!(
+                values[0],
+                TestValues {
+                    foo: 10,
+                    bar: 555,
+                    baz: 1.5
+                }
+            );
+            assert_eq!(
+                values[1],
+                TestValues {
+                    foo: 20,
+                    bar: 0,
+                    baz: 2.5
+                }
+            );
+            assert_eq!(
+                values[2],
+                TestValues {
+                    foo: 30,
+                    bar: 777,
+                    baz: 6.8
+                }
+            );
+        }
+
+        #[test]
+        fn when_all_cycle_durations_same_then_returns_cycle_duration() {
+            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
+            let timeline2 = StubTimeline::new().set_cycle_duration(5.5);
+            let timeline3 = StubTimeline::new().set_cycle_duration(5.5);
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.cycle_duration(), Some(5.5));
+        }
+
+        #[test]
+        fn when_any_cycle_duration_different_returns_undefined_cycle_duration() {
+            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
+            let timeline2 = StubTimeline::new().set_cycle_duration(4.5);
+            let timeline3 = StubTimeline::new().set_cycle_duration(5.5);
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.cycle_duration(), None);
+        }
+
+        #[test]
+        fn when_any_cycle_duration_undefined_returns_undefined_cycle_duration() {
+            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
+            let timeline2 = StubTimeline::new().set_cycle_duration(5.5);
+            let timeline3 = StubTimeline::new();
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.cycle_duration(), None);
+        }
+
+        #[test]
+        fn returns_minimum_delay() {
+            let timeline1 = StubTimeline::new().set_delay(2.0);
+            let timeline2 = StubTimeline::new().set_delay(5.0);
+            let timeline3 = StubTimeline::new().set_delay(0.1);
+
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.delay(), 0.1);
+        }
+
+        #[test]
+        fn when_all_durations_fixed_returns_max_duration() {
+            let timeline1 = StubTimeline::new().set_duration(10.);
+            let timeline2 = StubTimeline::new().set_duration(5.);
+            let timeline3 = StubTimeline::new().set_duration(15.);
+
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.duration(), 15.);
+        }
+
+        #[test]
+        fn when_any_duration_infinite_returns_infinite_duration() {
+            let timeline1 = StubTimeline::new().set_duration(f32::INFINITY);
+            let timeline2 = StubTimeline::new().set_duration(5.);
+            let timeline3 = StubTimeline::new().set_duration(15.);
+
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.duration(), f32::INFINITY);
+        }
+
+        #[test]
+        fn when_no_timelines_repeat_returns_no_repeat() {
+            let timeline1 = StubTimeline::new();
+            let timeline2 = StubTimeline::new();
+            let timeline3 = StubTimeline::new();
+
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline.repeat(), Repeat::None);
+        }
+
+        #[test]
+        fn when_some_timelines_repeat_returns_max_repeat() {
+            let timeline1 = StubTimeline::new();
+            let timeline2 = StubTimeline::new().set_repeat(Repeat::Times(1));
+            let timeline3 = StubTimeline::new().set_repeat(Repeat::Infinite);
+
+            let merged_timeline1 = MergedTimeline::of([timeline1.clone(), timeline2.clone()]);
+            let merged_timeline2 = MergedTimeline::of([timeline1, timeline2, timeline3]);
+
+            assert_eq!(merged_timeline1.repeat(), Repeat::Times(1));
+            assert_eq!(merged_timeline2.repeat(), Repeat::Infinite);
+        }
+
+        #[test]
+        fn markers_between_interleaves_component_markers_in_order() {
+            let timeline1 = StubTimeline::new()
+                .add_marker(0.5, "x")
+                .add_marker(2.0, "z");
+            let timeline2 = StubTimeline::new().add_marker(1.0, "y");
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2]);
+
+            assert_eq!(
+                merged_timeline.markers_between(0.0, 3.0),
+                &[
+                    marker_crossing("x", 0.5, 0),
+                    marker_crossing("y", 1.0, 0),
+                    marker_crossing("z", 2.0, 0),
+                ]
+            );
+            assert_eq!(
+                merged_timeline.markers_between(3.0, 0.0),
+                &[
+                    marker_crossing("z", 2.0, 0),
+                    marker_crossing("y", 1.0, 0),
+                    marker_crossing("x", 0.5, 0),
+                ]
+            );
+        }
+    }
+
+    mod sequence_timeline {
+        use super::*;
+
+        #[test]
+        fn plays_timelines_in_order() {
+            let timeline1 = StubTimeline::new()
+                .set_duration(2.0)
+                .add_frame(1.0, Some(10), None, None)
+                .add_frame(2.0, Some(20), None, None);
+            let timeline2 = StubTimeline::new()
+                .set_duration(3.0)
+                .add_frame(1.0, None, Some(100), None)
+                .add_frame(3.0, None, Some(300), None);
+            let sequence = SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
+
+            let mut values = <[TestValues; 3]>::default();
+            sequence.update(&mut values[0], 1.0);
+            sequence.update(&mut values[1], 3.0);
+            sequence.update(&mut values[2], 5.0);
+
+            assert_eq!(
+                values[0],
+                TestValues {
+                    foo: 10,
+                    bar: 0,
+                    baz: 0.0
+                }
+            );
+            assert_eq!(
+                values[1],
+                TestValues {
+                    foo: 20,
+                    bar: 100,
+                    baz: 0.0
+                }
+            );
+            assert_eq!(
+                values[2],
+                TestValues {
+                    foo: 20,
+                    bar: 300,
+                    baz: 0.0
+                }
+            );
+        }
+
+        #[test]
+        fn blends_each_timeline_from_end_of_previous() {
+            let timeline1 = StubTimeline::new()
+                .set_duration(2.0)
+                .add_frame(0.0, Some(1), None, None)
+                .add_frame(2.0, Some(20), None, None);
+            let timeline2 = StubTimeline::new()
+                .set_duration(3.0)
+                .add_frame(0.0, Some(5), Some(50), Some(0.5))
+                .add_frame(3.0, Some(30), Some(300), None);
+            let sequence = SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
+
+            let mut values = TestValues::default();
+            sequence.update(&mut values, 2.0);
+
+            assert_eq!(
+                values,
+                TestValues {
+                    foo: 20,
+                    bar: 50,
+                    baz: 0.5
+                }
+            );
+        }
+
+        #[test]
+        fn start_with_overrides_first_timeline() {
+            let timeline1 = StubTimeline::new()
+                .set_duration(2.0)
+                .add_frame(0.0, Some(1), None, None)
+                .add_frame(2.0, Some(20), None, None);
+            let timeline2 =
+                StubTimeline::new()
+                    .set_duration(3.0)
+                    .add_frame(0.0, None, Some(50), None);
+            let mut sequence =
+                SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
+
+            sequence.start_with(&TestValues {
+                foo: 8,
+                bar: 9,
+                baz: 1.5,
+            });
+            let mut values = TestValues::default();
+            sequence.update(&mut values, 0.0);
+
+            assert_eq!(
+                values,
+                TestValues {
+                    foo: 8,
+                    bar: 9,
+                    baz: 1.5
+                }
+            );
+        }
 
         #[test]
-        fn delegates_to_component_timelines() {
+        fn when_repeating_then_replays_all_timelines() {
             let timeline1 = StubTimeline::new()
-                .add_frame(0.1, Some(10), Some(555), Some(0.12))
-                .add_frame(0.2, Some(20), None, None)
-                .add_frame(0.3, Some(30), Some(777), None);
+                .set_duration(2.0)
+                .add_frame(1.0, Some(10), None, None)
+                .add_frame(2.0, Some(20), None, None);
             let timeline2 = StubTimeline::new()
-                .add_frame(0.1, None, None, Some(1.5))
-                .add_frame(0.2, None, None, Some(2.5))
-                .add_frame(0.3, None, None, Some(6.8));
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2]);
+                .set_duration(3.0)
+                .add_frame(1.0, None, Some(100), None)
+                .add_frame(3.0, None, Some(300), None);
+            let sequence = SequenceTimelineBuilder::new()
+                .then(MergedTimeline::from(timeline1))
+                .then(MergedTimeline::from(timeline2))
+                .repeat(Repeat::Times(1))
+                .build();
 
             let mut values = <[TestValues; 3]>::default();
-            merged_timeline.update(&mut values[0], 0.1);
-            merged_timeline.update(&mut values[1], 0.2);
-            merged_timeline.update(&mut values[2], 0.3);
+            sequence.update(&mut values[0], 6.0);
+            sequence.update(&mut values[1], 10.0);
+            sequence.update(&mut values[2], 12.0);
 
             assert_eq!(
                 values[0],
                 TestValues {
                     foo: 10,
-                    bar: 555,
-                    baz: 1.5
+                    bar: 0,
+                    baz: 0.0
                 }
             );
             assert_eq!(
                 values[1],
                 TestValues {
                     foo: 20,
-                    bar: 0,
-                    baz: 2.5
+                    bar: 300,
+                    baz: 0.0
                 }
             );
             assert_eq!(
                 values[2],
                 TestValues {
-                    foo: 30,
-                    bar: 777,
-                    baz: 6.8
+                    foo: 20,
+                    bar: 300,
+                    baz: 0.0
                 }
             );
         }
 
         #[test]
-        fn when_all_cycle_durations_same_then_returns_cycle_duration() {
-            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
-            let timeline2 = StubTimeline::new().set_cycle_duration(5.5);
-            let timeline3 = StubTimeline::new().set_cycle_duration(5.5);
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+        fn duration_is_sum_of_timeline_durations() {
+            let timeline1 = StubTimeline::new().set_delay(1.0).set_duration(3.0);
+            let timeline2 = StubTimeline::new().set_delay(0.5).set_duration(2.0);
+            let sequence = SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
+
+            assert_eq!(sequence.delay(), 1.0);
+            assert_eq!(sequence.cycle_duration(), Some(4.0));
+            assert_eq!(sequence.duration(), 5.0);
+            assert_eq!(sequence.repeat(), Repeat::None);
+        }
 
-            assert_eq!(merged_timeline.cycle_duration(), Some(5.5));
+        #[test]
+        fn when_repeating_then_duration_excludes_repeated_delay() {
+            let timeline1 = StubTimeline::new().set_delay(1.0).set_duration(3.0);
+            let timeline2 = StubTimeline::new().set_duration(2.0);
+            let sequence = SequenceTimelineBuilder::new()
+                .then(MergedTimeline::from(timeline1))
+                .then(MergedTimeline::from(timeline2))
+                .repeat(Repeat::Times(2))
+                .build();
+
+            assert_eq!(sequence.cycle_duration(), Some(4.0));
+            assert_eq!(sequence.duration(), 13.0);
+            assert_eq!(sequence.repeat(), Repeat::Times(2));
         }
 
         #[test]
-        fn when_any_cycle_duration_different_returns_undefined_cycle_duration() {
-            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
-            let timeline2 = StubTimeline::new().set_cycle_duration(4.5);
-            let timeline3 = StubTimeline::new().set_cycle_duration(5.5);
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+        fn when_repeat_fractional_then_duration_includes_partial_cycle() {
+            let timeline1 = StubTimeline::new().set_delay(1.0).set_duration(3.0);
+            let timeline2 = StubTimeline::new().set_duration(2.0);
+            let sequence = SequenceTimelineBuilder::new()
+                .then(MergedTimeline::from(timeline1))
+                .then(MergedTimeline::from(timeline2))
+                .repeat(Repeat::Fractional(0.5))
+                .build();
+
+            assert_eq!(sequence.duration(), 7.0);
+        }
 
-            assert_eq!(merged_timeline.cycle_duration(), None);
+        #[test]
+        fn when_any_duration_infinite_then_cycle_undefined() {
+            let timeline1 = StubTimeline::new().set_duration(f32::INFINITY);
+            let timeline2 = StubTimeline::new().set_duration(2.0);
+            let sequence = SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
+
+            assert_eq!(sequence.cycle_duration(), None);
+            assert_eq!(sequence.duration(), f32::INFINITY);
         }
 
         #[test]
-        fn when_any_cycle_duration_undefined_returns_undefined_cycle_duration() {
-            let timeline1 = StubTimeline::new().set_cycle_duration(5.5);
-            let timeline2 = StubTimeline::new().set_cycle_duration(5.5);
-            let timeline3 = StubTimeline::new();
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+        fn markers_between_offsets_markers_by_stage_start() {
+            let timeline1 = StubTimeline::new().set_duration(2.0).add_marker(1.0, "a");
+            let timeline2 = StubTimeline::new().set_duration(3.0).add_marker(1.5, "b");
+            let sequence = SequenceTimeline::of([timeline1, timeline2].map(MergedTimeline::from));
 
-            assert_eq!(merged_timeline.cycle_duration(), None);
+            assert_eq!(
+                sequence.markers_between(0.0, 5.0),
+                &[marker_crossing("a", 1.0, 0), marker_crossing("b", 3.5, 0)]
+            );
         }
 
         #[test]
-        fn returns_minimum_delay() {
-            let timeline1 = StubTimeline::new().set_delay(2.0);
-            let timeline2 = StubTimeline::new().set_delay(5.0);
-            let timeline3 = StubTimeline::new().set_delay(0.1);
+        fn when_repeating_then_markers_between_reports_every_cycle() {
+            let timeline1 = StubTimeline::new()
+                .set_delay(1.0)
+                .set_duration(3.0)
+                .add_marker(2.0, "a");
+            let timeline2 = StubTimeline::new().set_duration(2.0).add_marker(1.0, "b");
+            let sequence = SequenceTimelineBuilder::new()
+                .then(MergedTimeline::from(timeline1))
+                .then(MergedTimeline::from(timeline2))
+                .repeat(Repeat::Times(1))
+                .build();
 
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+            assert_eq!(
+                sequence.markers_between(0.0, 20.0),
+                &[
+                    marker_crossing("a", 2.0, 0),
+                    marker_crossing("b", 4.0, 0),
+                    marker_crossing("a", 6.0, 1),
+                    marker_crossing("b", 8.0, 1),
+                ]
+            );
+            assert_eq!(
+                sequence.markers_between(7.0, 1.0),
+                &[
+                    marker_crossing("a", 6.0, 1),
+                    marker_crossing("b", 4.0, 0),
+                    marker_crossing("a", 2.0, 0),
+                ]
+            );
+        }
 
-            assert_eq!(merged_timeline.delay(), 0.1);
+        #[test]
+        fn when_repeat_fractional_then_markers_after_end_not_reported() {
+            let timeline1 = StubTimeline::new().set_duration(2.0).add_marker(1.0, "a");
+            let timeline2 = StubTimeline::new().set_duration(2.0).add_marker(1.0, "b");
+            let sequence = SequenceTimelineBuilder::new()
+                .then(MergedTimeline::from(timeline1))
+                .then(MergedTimeline::from(timeline2))
+                .repeat(Repeat::Fractional(0.5))
+                .build();
+
+            assert_eq!(
+                sequence.markers_between(0.0, 10.0),
+                &[
+                    marker_crossing("a", 1.0, 0),
+                    marker_crossing("b", 3.0, 0),
+                    marker_crossing("a", 5.0, 1),
+                ]
+            );
+        }
+    }
+
+    mod clipped_timeline {
+        use super::*;
+
+        fn stub_timeline() -> StubTimeline {
+            StubTimeline::new()
+                .set_delay(1.0)
+                .set_duration(10.0)
+                .set_cycle_duration(9.0)
+                .add_frame(0.0, Some(0), None, None)
+                .add_frame(2.0, Some(20), None, None)
+                .add_frame(3.0, Some(30), None, None)
+                .add_frame(3.5, Some(35), None, None)
+                .add_frame(4.0, Some(40), None, None)
+                .add_frame(6.0, Some(60), None, None)
+                .add_marker(3.0, "a")
+                .add_marker(6.0, "b")
+        }
+
+        fn sample(timeline: &impl Timeline<Target = TestValues>, time: f32) -> u8 {
+            let mut values = TestValues::default();
+            timeline.update(&mut values, time);
+            values.foo
         }
 
         #[test]
-        fn when_all_durations_fixed_returns_max_duration() {
-            let timeline1 = StubTimeline::new().set_duration(10.);
-            let timeline2 = StubTimeline::new().set_duration(5.);
-            let timeline3 = StubTimeline::new().set_duration(15.);
+        fn when_clipped_by_seconds_then_plays_window_from_zero() {
+            let timeline = stub_timeline().clip(ClipTime::Seconds(2.0), ClipTime::Seconds(4.0));
+
+            assert_eq!(timeline.delay(), 0.0);
+            assert_eq!(timeline.duration(), 2.0);
+            assert_eq!(timeline.cycle_duration(), Some(2.0));
+            assert_eq!(timeline.repeat(), Repeat::None);
+            assert_eq!(sample(&timeline, -1.0), 20);
+            assert_eq!(sample(&timeline, 0.0), 20);
+            assert_eq!(sample(&timeline, 1.5), 35);
+            assert_eq!(sample(&timeline, 2.0), 40);
+            assert_eq!(sample(&timeline, 5.0), 40);
+        }
 
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+        #[test]
+        fn when_clip_time_is_nan_then_uses_inner_timeline_bounds() {
+            let timeline =
+                stub_timeline().clip(ClipTime::Seconds(f32::NAN), ClipTime::Normalized(f32::NAN));
+
+            assert_eq!(timeline.start(), 0.0);
+            assert_eq!(timeline.end(), 10.0);
+            assert_eq!(timeline.duration(), 10.0);
+            assert_eq!(sample(&timeline, 3.0), 30);
+        }
 
-            assert_eq!(merged_timeline.duration(), 15.);
+        #[test]
+        fn when_clipped_by_normalized_time_then_window_is_fraction_of_duration() {
+            let timeline =
+                stub_timeline().clip(ClipTime::Normalized(0.2), ClipTime::Normalized(0.6));
+
+            assert_eq!(timeline.start(), 2.0);
+            assert_eq!(timeline.end(), 6.0);
+            assert_eq!(timeline.duration(), 4.0);
+            assert_eq!(sample(&timeline, 4.0), 60);
         }
 
         #[test]
-        fn when_any_duration_infinite_returns_infinite_duration() {
-            let timeline1 = StubTimeline::new().set_duration(f32::INFINITY);
-            let timeline2 = StubTimeline::new().set_duration(5.);
-            let timeline3 = StubTimeline::new().set_duration(15.);
+        fn when_repeating_infinitely_then_normalized_time_relative_to_first_cycle() {
+            let timeline = stub_timeline()
+                .set_duration(f32::INFINITY)
+                .clip(ClipTime::Normalized(0.2), ClipTime::Normalized(0.4));
 
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+            assert_eq!(timeline.start(), 2.0);
+            assert_eq!(timeline.end(), 4.0);
+        }
 
-            assert_eq!(merged_timeline.duration(), f32::INFINITY);
+        #[test]
+        fn when_window_includes_delay_then_delay_is_preserved() {
+            let timeline = stub_timeline().clip(ClipTime::Seconds(0.0), ClipTime::Seconds(4.0));
+
+            assert_eq!(timeline.delay(), 1.0);
+            assert_eq!(timeline.duration(), 4.0);
+            assert_eq!(timeline.cycle_duration(), Some(3.0));
         }
 
         #[test]
-        fn when_no_timelines_repeat_returns_no_repeat() {
-            let timeline1 = StubTimeline::new();
-            let timeline2 = StubTimeline::new();
-            let timeline3 = StubTimeline::new();
+        fn when_end_before_start_then_holds_start() {
+            let timeline = stub_timeline().clip(ClipTime::Seconds(3.0), ClipTime::Seconds(1.0));
 
-            let merged_timeline = MergedTimeline::of([timeline1, timeline2, timeline3]);
+            assert_eq!(timeline.duration(), 0.0);
+            assert_eq!(sample(&timeline, 0.0), 30);
+            assert_eq!(sample(&timeline, 1.0), 30);
+        }
 
-            assert_eq!(merged_timeline.repeat(), Repeat::None);
+        #[test]
+        fn when_looping_then_replays_window() {
+            let timeline = stub_timeline()
+                .clip(ClipTime::Seconds(2.0), ClipTime::Seconds(4.0))
+                .looping(true);
+
+            assert_eq!(timeline.delay(), 0.0);
+            assert_eq!(timeline.duration(), f32::INFINITY);
+            assert_eq!(timeline.cycle_duration(), Some(2.0));
+            assert_eq!(timeline.repeat(), Repeat::Infinite);
+            assert_eq!(sample(&timeline, 2.0), 40);
+            assert_eq!(sample(&timeline, 3.5), 35);
+            assert_eq!(sample(&timeline, 4.0), 40);
+            assert_eq!(sample(&timeline, 5.0), 30);
         }
 
         #[test]
-        fn when_some_timelines_repeat_returns_max_repeat() {
-            let timeline1 = StubTimeline::new();
-            let timeline2 = StubTimeline::new().set_repeat(Repeat::Times(1));
-            let timeline3 = StubTimeline::new().set_repeat(Repeat::Infinite);
+        fn markers_between_reports_only_markers_in_window() {
+            let timeline = stub_timeline().clip(ClipTime::Seconds(2.0), ClipTime::Seconds(4.0));
 
-            let merged_timeline1 = MergedTimeline::of([timeline1.clone(), timeline2.clone()]);
-            let merged_timeline2 = MergedTimeline::of([timeline1, timeline2, timeline3]);
+            assert_eq!(
+                timeline.markers_between(-1.0, 10.0),
+                &[marker_crossing("a", 1.0, 0)]
+            );
+        }
 
-            assert_eq!(merged_timeline1.repeat(), Repeat::Times(1));
-            assert_eq!(merged_timeline2.repeat(), Repeat::Infinite);
+        #[test]
+        fn when_looping_then_markers_between_reports_every_pass() {
+            let timeline = stub_timeline()
+                .clip(ClipTime::Seconds(3.0), ClipTime::Seconds(6.0))
+                .looping(true);
+
+            assert_eq!(
+                timeline.markers_between(-1.0, 6.0),
+                &[
+                    marker_crossing("a", 0.0, 0),
+                    marker_crossing("b", 3.0, 0),
+                    marker_crossing("a", 3.0, 1),
+                    marker_crossing("b", 6.0, 1),
+                    marker_crossing("a", 6.0, 2),
+                ]
+            );
+            assert_eq!(
+                timeline.markers_between(6.0, 1.0),
+                &[marker_crossing("a", 3.0, 1), marker_crossing("b", 3.0, 0),]
+            );
+        }
+    }
+
+    mod scaled_timeline {
+        use super::*;
+
+        fn stub_timeline() -> StubTimeline {
+            StubTimeline::new()
+                .set_delay(1.0)
+                .set_duration(4.0)
+                .set_cycle_duration(3.0)
+                .add_frame(0.0, Some(0), None, None)
+                .add_frame(1.0, Some(10), None, None)
+                .add_frame(4.0, Some(40), None, None)
+                .add_marker(1.0, "a")
+                .add_marker(3.0, "b")
+        }
+
+        fn sample(timeline: &impl Timeline<Target = TestValues>, time: f32) -> u8 {
+            let mut values = TestValues::default();
+            timeline.update(&mut values, time);
+            values.foo
+        }
+
+        #[test]
+        fn when_speed_increased_then_timing_is_compressed() {
+            let timeline = stub_timeline().with_speed(2.0);
+
+            assert_eq!(timeline.delay(), 0.5);
+            assert_eq!(timeline.duration(), 2.0);
+            assert_eq!(timeline.cycle_duration(), Some(1.5));
+            assert_eq!(sample(&timeline, 0.5), 10);
+            assert_eq!(sample(&timeline, 2.0), 40);
+            assert_eq!(
+                timeline.markers_between(0.0, 2.0),
+                &[marker_crossing("a", 0.5, 0), marker_crossing("b", 1.5, 0)]
+            );
+        }
+
+        #[test]
+        fn when_speed_decreased_then_timing_is_stretched() {
+            let timeline = stub_timeline().with_speed(0.5);
+
+            assert_eq!(timeline.delay(), 2.0);
+            assert_eq!(timeline.duration(), 8.0);
+            assert_eq!(timeline.cycle_duration(), Some(6.0));
+            assert_eq!(sample(&timeline, 2.0), 10);
+            assert_eq!(sample(&timeline, 8.0), 40);
+        }
+
+        #[test]
+        fn when_speed_negative_then_plays_in_reverse_without_delay() {
+            let timeline = stub_timeline().with_speed(-2.0);
+
+            assert_eq!(timeline.delay(), 0.0);
+            assert_eq!(timeline.duration(), 2.0);
+            assert_eq!(timeline.cycle_duration(), Some(1.5));
+            assert_eq!(sample(&timeline, 0.0), 40);
+            assert_eq!(sample(&timeline, 1.5), 10);
+            assert_eq!(sample(&timeline, 2.0), 0);
+            assert_eq!(
+                timeline.markers_between(0.0, 2.0),
+                &[marker_crossing("b", 0.5, 0), marker_crossing("a", 1.5, 0)]
+            );
+        }
+
+        #[test]
+        fn when_speed_zero_then_holds_start() {
+            let timeline = stub_timeline().with_speed(0.0);
+
+            assert_eq!(timeline.delay(), 0.0);
+            assert_eq!(timeline.duration(), f32::INFINITY);
+            assert_eq!(sample(&timeline, 10.0), 0);
+            assert_eq!(timeline.markers_between(0.0, 10.0), &[]);
+        }
+
+        #[test]
+        fn when_speed_nan_then_treated_as_zero() {
+            let timeline = stub_timeline().with_speed(f32::NAN);
+
+            assert_eq!(timeline.speed(), 0.0);
+            assert_eq!(timeline.delay(), 0.0);
+            assert_eq!(timeline.duration(), f32::INFINITY);
+            assert_eq!(timeline.cycle_duration(), Some(f32::INFINITY));
+            assert_eq!(sample(&timeline, 10.0), 0);
+        }
+
+        #[test]
+        fn when_repeating_infinitely_and_speed_negative_then_holds_start() {
+            let timeline = stub_timeline().set_duration(f32::INFINITY).with_speed(-1.0);
+
+            assert_eq!(timeline.duration(), f32::INFINITY);
+            assert_eq!(sample(&timeline, 5.0), 0);
+            assert_eq!(timeline.markers_between(0.0, 10.0), &[]);
+        }
+
+        #[test]
+        fn can_be_merged() {
+            let timeline1 = stub_timeline().with_speed(2.0);
+            let timeline2 = StubTimeline::new()
+                .set_duration(6.0)
+                .add_frame(1.0, None, Some(100), None)
+                .with_speed(0.5);
+            let merged_timeline = MergedTimeline::of([timeline1, timeline2]);
+
+            let mut values = TestValues::default();
+            merged_timeline.update(&mut values, 2.0);
+
+            assert_eq!(merged_timeline.duration(), 12.0);
+            assert_eq!(values.foo, 40);
+            assert_eq!(values.bar, 100);
+        }
+    }
+
+    mod prepare_frame {
+        use super::*;
+
+        const BOUNDARY_TIMES: [f32; 3] = [0.0, 0.5, 1.0];
+
+        fn timescale(fill_mode: FillMode) -> TimeScale {
+            TimeScale::new(4.0, 2.0, Repeat::None, false).with_fill_mode(fill_mode)
+        }
+
+        #[test]
+        fn when_active_then_prepares_frame_regardless_of_fill_mode() {
+            for fill_mode in [
+                FillMode::None,
+                FillMode::Forwards,
+                FillMode::Backwards,
+                FillMode::Both,
+            ] {
+                assert_eq!(
+                    prepare_frame(5.0, &BOUNDARY_TIMES, &timescale(fill_mode)),
+                    Some((0.75, 1, true))
+                );
+            }
+        }
+
+        #[test]
+        fn when_iteration_start_skips_cycles_then_first_played_cycle_enables_start_override() {
+            let timescale =
+                TimeScale::new(4.0, 0.0, Repeat::Infinite, false).with_iteration_start(1.25);
+
+            assert_eq!(
+                prepare_frame(1.0, &BOUNDARY_TIMES, &timescale),
+                Some((0.5, 1, true))
+            );
+            assert_eq!(
+                prepare_frame(5.0, &BOUNDARY_TIMES, &timescale),
+                Some((0.5, 1, false))
+            );
+        }
+
+        #[test]
+        fn when_filling_backwards_then_prepares_start_frame_during_delay() {
+            assert_eq!(
+                prepare_frame(1.0, &BOUNDARY_TIMES, &timescale(FillMode::Backwards)),
+                Some((0.0, 0, true))
+            );
+            assert_eq!(
+                prepare_frame(1.0, &BOUNDARY_TIMES, &timescale(FillMode::Both)),
+                Some((0.0, 0, true))
+            );
+        }
+
+        #[test]
+        fn when_not_filling_backwards_then_skips_delay() {
+            assert_eq!(
+                prepare_frame(1.0, &BOUNDARY_TIMES, &timescale(FillMode::None)),
+                None
+            );
+            assert_eq!(
+                prepare_frame(1.0, &BOUNDARY_TIMES, &timescale(FillMode::Forwards)),
+                None
+            );
+        }
+
+        #[test]
+        fn when_filling_forwards_then_prepares_end_frame_after_end() {
+            assert_eq!(
+                prepare_frame(7.0, &BOUNDARY_TIMES, &timescale(FillMode::Forwards)),
+                Some((1.0, 2, false))
+            );
+            assert_eq!(
+                prepare_frame(7.0, &BOUNDARY_TIMES, &timescale(FillMode::Both)),
+                Some((1.0, 2, false))
+            );
+        }
+
+        #[test]
+        fn when_not_filling_forwards_then_skips_after_end() {
+            assert_eq!(
+                prepare_frame(7.0, &BOUNDARY_TIMES, &timescale(FillMode::None)),
+                None
+            );
+            assert_eq!(
+                prepare_frame(7.0, &BOUNDARY_TIMES, &timescale(FillMode::Backwards)),
+                None
+            );
+        }
+    }
+
+    mod configuration_builder {
+        use super::*;
+
+        // Builder that only implements the required methods, as an older builder would.
+        #[derive(Debug, Default, PartialEq)]
+        struct MinimalBuilder {
+            duration_seconds: f32,
+        }
+
+        impl TimelineConfigurationBuilder for MinimalBuilder {
+            type KeyframeData = TestValues;
+
+            fn default_easing(self, _default_easing: Easing) -> Self {
+                self
+            }
+
+            fn delay_seconds(self, _delay_seconds: f32) -> Self {
+                self
+            }
+
+            fn duration_seconds(self, duration_seconds: f32) -> Self {
+                Self { duration_seconds }
+            }
+
+            fn keyframe(self, _builder: impl KeyframeBuilder<Data = TestValues>) -> Self {
+                self
+            }
+
+            fn repeat(self, _repeat: Repeat) -> Self {
+                self
+            }
+
+            fn reverse(self, _reverse: bool) -> Self {
+                self
+            }
+        }
+
+        #[test]
+        fn when_optional_settings_not_implemented_then_ignores_them() {
+            let builder = MinimalBuilder::default()
+                .duration_seconds(2.0)
+                .fill_mode(FillMode::None)
+                .direction(PlaybackDirection::Reverse)
+                .iteration_start(0.5)
+                .repeat_delay_seconds(1.0)
+                .marker(Marker::at("middle", 0.5));
+
+            assert_eq!(
+                builder,
+                MinimalBuilder {
+                    duration_seconds: 2.0
+                }
+            );
+        }
+    }
+
+    mod repeat {
+        use super::*;
+
+        #[test]
+        fn equality_is_structural() {
+            assert_eq!(Repeat::Fractional(2.0), Repeat::Fractional(2.0));
+            assert_ne!(Repeat::None, Repeat::Times(0));
+            assert_ne!(Repeat::Times(2), Repeat::Fractional(2.0));
+        }
+
+        #[test]
+        fn cmp_repetitions_compares_by_number_of_repetitions() {
+            let cmp = |a: Repeat, b: Repeat| a.cmp_repetitions(&b);
+
+            assert_eq!(cmp(Repeat::None, Repeat::Times(0)), Ordering::Equal);
+            assert_eq!(
+                cmp(Repeat::Times(2), Repeat::Fractional(2.0)),
+                Ordering::Equal
+            );
+            assert_eq!(
+                cmp(Repeat::Times(1), Repeat::Fractional(1.5)),
+                Ordering::Less
+            );
+            assert_eq!(
+                cmp(Repeat::Fractional(1.5), Repeat::Times(2)),
+                Ordering::Less
+            );
+            assert_eq!(
+                cmp(Repeat::Fractional(1e9), Repeat::Infinite),
+                Ordering::Less
+            );
+        }
+
+        #[test]
+        fn iterations_counts_first_cycle() {
+            assert_eq!(Repeat::iterations(0.0), Repeat::Fractional(-1.0));
+            assert_eq!(Repeat::iterations(1.0), Repeat::None);
+            assert_eq!(Repeat::iterations(2.0), Repeat::Times(1));
+            assert_eq!(Repeat::iterations(2.5), Repeat::Fractional(1.5));
+            assert_eq!(Repeat::iterations(f32::INFINITY), Repeat::Infinite);
+            assert_eq!(Repeat::iterations(f32::NAN), Repeat::None);
         }
     }
 }
@@ -6,8 +6,8 @@
 
 use crate::{
     easing::{Easing, EasingFunction},
-    interpolation::Lerp,
-    timeline::Keyframe,
+    interpolation::{lerp_weighted, Lerp, VectorSpace},
+    timeline::{find_frame_index, Keyframe},
 };
 use std::fmt::Debug;
 
@@ -38,6 +38,10 @@ pub struct SubTimeline<Value: Clone> {
     frames: Vec<SplitKeyframe<Value>>,
     frame_index_map: Vec<usize>,
     start_frame_override: Option<SplitKeyframe<Value>>,
+    start_velocity_override: Option<(Value, f32)>,
+    // First segment of the original frames, kept when resampling so that overridden start values
+    // are still blended with the original easing and timing.
+    start_segment: Option<[SplitKeyframe<Value>; 2]>,
 }
 
 impl<Value: Clone + Lerp> SubTimeline<Value> {
@@ -110,6 +114,8 @@ impl<Value: Clone + Lerp> SubTimeline<Value> {
             frames: converted_frames,
             frame_index_map,
             start_frame_override: None,
+            start_velocity_override: None,
+            start_segment: None,
         }
     }
 
@@ -120,17 +126,51 @@ impl<Value: Clone + Lerp> SubTimeline<Value> {
     /// This is typically used when blending animations; the newly-active timeline begins where the
     /// previously-active timeline ended or was interrupted.
     ///
+    /// Any velocity previously set by [`override_start_velocity`](Self::override_start_velocity)
+    /// is cleared.
+    ///
     /// If the sub-timeline is empty, i.e. not used, then this does nothing.
     ///
     /// # Arguments
     ///
     /// * `value` - New value to use for the 0% frame position.
     pub fn override_start_value(&mut self, value: Value) {
-        if let Some(first_frame) = self.frames.first() {
+        if let Some(first_frame) = self.first_frame() {
             self.start_frame_override = Some(first_frame.with_value(value));
+            self.start_velocity_override = None;
+        }
+    }
+
+    /// Sets an initial velocity to use along with the overridden start value from
+    /// [`override_start_value`](Self::override_start_value), which will be used only when the
+    /// first frame's easing responds to velocity (see [`EasingFunction::velocity_response`]).
+    ///
+    /// Since values are only required to implement [`Lerp`], the velocity is specified as the value
+    /// that the property would reach after a short `interval` if it continued moving at the same
+    /// rate. For example, if the start value is `10.0` and the property is moving at `100.0` units
+    /// per second, then `next_value` could be `11.0` with an `interval` of `0.01`.
+    ///
+    /// If the sub-timeline is empty, i.e. not used, then this does nothing.
+    ///
+    /// # Arguments
+    ///
+    /// * `next_value` - Value that the property would have after the `interval`.
+    /// * `interval` - Time, in seconds, between the start value and `next_value`.
+    pub fn override_start_velocity(&mut self, next_value: Value, interval: f32) {
+        if !self.frames.is_empty() && interval > 0.0 {
+            self.start_velocity_override = Some((next_value, interval));
         }
     }
 
+    /// Checks whether the easing of the first keyframe responds to the velocity set by
+    /// [`override_start_velocity`](Self::override_start_velocity).
+    ///
+    /// Returns `false` if the sub-timeline is empty.
+    pub fn responds_to_velocity(&self) -> bool {
+        self.first_frame()
+            .is_some_and(|frame| frame.easing.velocity_response(0.0).is_some())
+    }
+
     /// Gets the value for this sub-timeline's property at a given position.
     ///
     /// Does not perform a full search of keyframes based on the time; instead this expects the
@@ -158,14 +198,142 @@ impl<Value: Clone + Lerp> SubTimeline<Value> {
         let normalized_time = normalized_time.clamp(0.0, 1.0);
         let bounding_frames =
             self.get_bounding_frames(normalized_time, index_hint, enable_start_override)?;
+        if let (Some(override_frame), Some((next_value, interval))) =
+            (&self.start_frame_override, &self.start_velocity_override)
+        {
+            if enable_start_override && std::ptr::eq(bounding_frames[0], override_frame) {
+                let value = interpolate_value_with_velocity(
+                    &bounding_frames,
+                    normalized_time,
+                    next_value,
+                    *interval,
+                );
+                if let Some(value) = value {
+                    return Some(value);
+                }
+            }
+        }
         Some(interpolate_value(&bounding_frames, normalized_time))
     }
 
+    /// Gets the velocity of this sub-timeline's property at a given position, i.e. the rate at
+    /// which the value is changing per unit of real time (generally seconds).
+    ///
+    /// The velocity is computed from the slope of the easing (see [`Easing::slope`]) and the
+    /// difference between the bounding keyframe values, and is expressed as a value of the same
+    /// type. This requires the value type to implement [`VectorSpace`]. If the `normalized_rate` is
+    /// zero, the velocity is zero.
+    ///
+    /// Arguments are the same as for [`value_at`](Self::value_at), with the addition of:
+    ///
+    /// * `normalized_rate` - Rate at which the `normalized_time` is changing per unit of real time,
+    ///   e.g. as given by
+    ///   [`TimeScale::get_normalized_rate`](crate::time_scale::TimeScale::get_normalized_rate).
+    pub fn velocity_at(
+        &self,
+        normalized_time: f32,
+        index_hint: usize,
+        enable_start_override: bool,
+        normalized_rate: f32,
+    ) -> Option<Value>
+    where
+        Value: VectorSpace,
+    {
+        if self.frame_index_map.is_empty() {
+            return None;
+        }
+        let normalized_time = normalized_time.clamp(0.0, 1.0);
+        let bounding_frames =
+            self.get_bounding_frames(normalized_time, index_hint, enable_start_override)?;
+        if let (Some(override_frame), Some((next_value, interval))) =
+            (&self.start_frame_override, &self.start_velocity_override)
+        {
+            if enable_start_override && std::ptr::eq(bounding_frames[0], override_frame) {
+                let velocity = interpolate_velocity_with_velocity(
+                    &bounding_frames,
+                    normalized_time,
+                    next_value,
+                    *interval,
+                    normalized_rate,
+                );
+                if let Some(velocity) = velocity {
+                    return Some(velocity);
+                }
+            }
+        }
+        Some(interpolate_velocity(
+            &bounding_frames,
+            normalized_time,
+            normalized_rate,
+        ))
+    }
+
+    /// Creates a copy of this sub-timeline that is sampled from the original at each of the
+    /// `sample_times`, with linear interpolation between samples.
+    ///
+    /// This is used to [bake](crate::bake::BakeProperties) a timeline one property at a time. Any
+    /// overridden start value or velocity is not sampled, and does not apply to the copy.
+    ///
+    /// The original first segment, i.e. up to the second keyframe, is also kept in the copy, and is
+    /// used instead of the samples whenever a start value is overridden on the copy. This way, the
+    /// copy blends from the new start value with the original easing, over the original duration
+    /// of the segment, and still responds to velocity if the original easing does.
+    ///
+    /// # Arguments
+    ///
+    /// * `boundary_times` - Normalized times of the keyframes in the original timeline, which are
+    ///   used to determine the `index_hint` (see [`value_at`](Self::value_at)) of each sample.
+    /// * `sample_times` - Normalized times at which to sample the values, in ascending order and
+    ///   including both `0.0` and `1.0`. These are the keyframe times of the new sub-timeline, and
+    ///   should be used as its boundary times.
+    pub fn resample(&self, boundary_times: &[f32], sample_times: &[f32]) -> Self {
+        let Some(frames) = sample_times
+            .iter()
+            .map(|&time| {
+                let index_hint = find_frame_index(boundary_times, time);
+                let value = self.value_at(time, index_hint, false)?;
+                Some(SplitKeyframe::new(time, value, Easing::Linear))
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            return Self::empty();
+        };
+        if frames.is_empty() {
+            return Self::empty();
+        }
+        let start_segment = self
+            .start_segment
+            .clone()
+            .or_else(|| match self.frames.as_slice() {
+                [first_frame, second_frame, ..] => {
+                    Some([first_frame.clone(), second_frame.clone()])
+                }
+                _ => None,
+            });
+        Self {
+            frame_index_map: (0..frames.len()).collect(),
+            frames,
+            start_frame_override: None,
+            start_velocity_override: None,
+            start_segment,
+        }
+    }
+
     fn empty() -> Self {
         Self {
             frame_index_map: vec![],
             frames: vec![],
             start_frame_override: None,
+            start_velocity_override: None,
+            start_segment: None,
+        }
+    }
+
+    // First frame of the original timeline, whose easing applies to overridden start values.
+    fn first_frame(&self) -> Option<&SplitKeyframe<Value>> {
+        match &self.start_segment {
+            Some([first_frame, _]) => Some(first_frame),
+            None => self.frames.first(),
         }
     }
 
@@ -175,6 +343,13 @@ impl<Value: Clone + Lerp> SubTimeline<Value> {
         index_hint: usize,
         enable_start_override: bool,
     ) -> Option<[&SplitKeyframe<Value>; 2]> {
+        if let (Some(override_frame), Some([_, end_frame])) =
+            (&self.start_frame_override, &self.start_segment)
+        {
+            if enable_start_override && normalized_time < end_frame.normalized_time {
+                return Some([override_frame, end_frame]);
+            }
+        }
         let index_at = *self.frame_index_map.get(index_hint)?;
         let frame_at = self.get_frame(index_at, enable_start_override)?;
         if normalized_time < frame_at.normalized_time {
@@ -204,7 +379,7 @@ impl<Value: Clone + Lerp> SubTimeline<Value> {
             if let Some(ref override_frame) = self.start_frame_override {
                 Some(override_frame)
             } else {
-                self.frames.get(0)
+                self.frames.first()
             }
         } else {
             self.frames.get(index)
@@ -269,10 +444,78 @@ fn interpolate_value<Value: Clone + Lerp>(
     start_frame.value.lerp(&end_frame.value, y)
 }
 
+fn interpolate_value_with_velocity<Value: Clone + Lerp>(
+    bounding_frames: &[&SplitKeyframe<Value>; 2],
+    time: f32,
+    next_value: &Value,
+    interval: f32,
+) -> Option<Value> {
+    let [start_frame, end_frame] = bounding_frames;
+    let duration = end_frame.normalized_time - start_frame.normalized_time;
+    if duration == 0.0 {
+        return None;
+    }
+    let easing = &start_frame.easing;
+    let x = (time - start_frame.normalized_time) / duration;
+    let velocity_response = easing.velocity_response(x)?;
+    let y = easing.calc(x);
+    // The initial velocity is (next_value - start) / interval. Adding it to the normal eased value
+    // gives: start + y * (end - start) + velocity_response * (next_value - start) / interval.
+    let velocity_weight = velocity_response / interval;
+    Some(lerp_weighted(
+        [&start_frame.value, &end_frame.value, next_value],
+        [1.0 - y - velocity_weight, y, velocity_weight],
+    ))
+}
+
+fn interpolate_velocity<Value: Clone + VectorSpace>(
+    bounding_frames: &[&SplitKeyframe<Value>; 2],
+    time: f32,
+    normalized_rate: f32,
+) -> Value {
+    let [start_frame, end_frame] = bounding_frames;
+    let duration = end_frame.normalized_time - start_frame.normalized_time;
+    if duration == 0.0 || normalized_rate == 0.0 {
+        return end_frame.value.scaled_difference(&end_frame.value, 0.0);
+    }
+    let x = (time - start_frame.normalized_time) / duration;
+    let slope = start_frame.easing.slope(x) * normalized_rate / duration;
+    end_frame.value.scaled_difference(&start_frame.value, slope)
+}
+
+fn interpolate_velocity_with_velocity<Value: Clone + VectorSpace>(
+    bounding_frames: &[&SplitKeyframe<Value>; 2],
+    time: f32,
+    next_value: &Value,
+    interval: f32,
+    normalized_rate: f32,
+) -> Option<Value> {
+    let [start_frame, end_frame] = bounding_frames;
+    let duration = end_frame.normalized_time - start_frame.normalized_time;
+    if duration == 0.0 || normalized_rate == 0.0 {
+        return None;
+    }
+    // The velocity response has no analytic derivative, so the velocity is approximated from the
+    // values slightly before and after the time, without leaving the current frame.
+    let step = VELOCITY_TIME_STEP * duration;
+    let prev_time = (time - step).max(start_frame.normalized_time);
+    let next_time = (time + step).min(end_frame.normalized_time);
+    let prev = interpolate_value_with_velocity(bounding_frames, prev_time, next_value, interval)?;
+    let next = interpolate_value_with_velocity(bounding_frames, next_time, next_value, interval)?;
+    let slope = normalized_rate / (next_time - prev_time);
+    Some(next.scaled_difference(&prev, slope))
+}
+
+/// Fraction of a frame's duration between the sample points used to approximate velocities that
+/// cannot be computed analytically.
+const VELOCITY_TIME_STEP: f32 = 1e-3;
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::spring::Spring;
     use crate::timeline::{Repeat, Timeline};
+    use approx::assert_relative_eq;
 
     #[derive(Clone, Debug, Default, PartialEq)]
     struct TestValues {
@@ -356,10 +599,7 @@ mod tests {
             if self.boundary_times.is_empty() {
                 return;
             }
-            let frame_index = match self.boundary_times.binary_search_by(|t| t.total_cmp(&time)) {
-                Ok(index) => index,
-                Err(next_index) => next_index.max(1) - 1,
-            };
+            let frame_index = self.frame_index(time);
             if let Some(foo) = self.foo.value_at(time, frame_index, enable_start_override) {
                 target.foo = foo;
             }
@@ -368,6 +608,34 @@ mod tests {
             }
         }
 
+        fn resample(&self, sample_times: Vec<f32>) -> Self {
+            Self {
+                foo: self.foo.resample(&self.boundary_times, &sample_times),
+                bar: self.bar.resample(&self.boundary_times, &sample_times),
+                boundary_times: sample_times,
+            }
+        }
+
+        fn frame_index(&self, time: f32) -> usize {
+            match self.boundary_times.binary_search_by(|t| t.total_cmp(&time)) {
+                Ok(index) => index,
+                Err(next_index) => next_index.max(1) - 1,
+            }
+        }
+
+        // Only `bar` supports velocities, since `foo` is an integer.
+        fn velocities_at(&self, time: f32, normalized_rate: f32) -> TestValues {
+            let mut velocities = TestValues::default();
+            let frame_index = self.frame_index(time);
+            if let Some(bar) = self
+                .bar
+                .velocity_at(time, frame_index, true, normalized_rate)
+            {
+                velocities.bar = bar;
+            }
+            velocities
+        }
+
         fn values_at(&self, time: f32) -> TestValues {
             let mut target = TestValues::default();
             self.update(&mut target, time);
@@ -380,7 +648,7 @@ mod tests {
             target
         }
 
-        fn updated_values_at<'a>(&self, values: &TestValues, time: f32) -> TestValues {
+        fn updated_values_at(&self, values: &TestValues, time: f32) -> TestValues {
             let mut updated_values = values.clone();
             self.update(&mut updated_values, time);
             updated_values
@@ -411,6 +679,17 @@ mod tests {
             self.bar.override_start_value(values.bar);
         }
 
+        fn start_with_velocity(
+            &mut self,
+            values: &Self::Target,
+            next_values: &Self::Target,
+            interval: f32,
+        ) {
+            self.start_with(values);
+            self.foo.override_start_velocity(next_values.foo, interval);
+            self.bar.override_start_velocity(next_values.bar, interval);
+        }
+
         fn update(&self, target: &mut Self::Target, time: f32) {
             self.update_with_override(target, time, true);
         }
@@ -534,10 +813,10 @@ mod tests {
         let timeline = TestTimeline::new(keyframes, Easing::OutQuad);
 
         assert_eq!(timeline.values_at(0.0).round(), TestValues::new(0, 0.0));
-        assert_eq!(timeline.values_at(0.2).round(), TestValues::new(20, 49.0));
-        assert_eq!(timeline.values_at(0.4).round(), TestValues::new(31, 78.0));
-        assert_eq!(timeline.values_at(0.6).round(), TestValues::new(37, 94.0));
-        assert_eq!(timeline.values_at(0.8).round(), TestValues::new(40, 99.0));
+        assert_eq!(timeline.values_at(0.2).round(), TestValues::new(14, 36.0));
+        assert_eq!(timeline.values_at(0.4).round(), TestValues::new(26, 64.0));
+        assert_eq!(timeline.values_at(0.6).round(), TestValues::new(34, 84.0));
+        assert_eq!(timeline.values_at(0.8).round(), TestValues::new(38, 96.0));
         assert_eq!(timeline.values_at(1.0).round(), TestValues::new(40, 100.0));
     }
 
@@ -566,14 +845,14 @@ mod tests {
         assert_eq!(timeline.values_at(0.2).round(), TestValues::new(50, 100.0));
         assert_eq!(timeline.values_at(0.3).round(), TestValues::new(75, 250.0));
         assert_eq!(timeline.values_at(0.4).round(), TestValues::new(100, 400.0));
-        assert_eq!(timeline.values_at(0.5).round(), TestValues::new(135, 824.0));
+        assert_eq!(timeline.values_at(0.5).round(), TestValues::new(143, 919.0));
         assert_eq!(
             timeline.values_at(0.6).round(),
             TestValues::new(150, 1000.0)
         );
         assert_eq!(
             timeline.values_at(0.7).round(),
-            TestValues::new(185, 3825.0)
+            TestValues::new(193, 4461.0)
         );
         assert_eq!(
             timeline.values_at(0.8).round(),
@@ -581,7 +860,7 @@ mod tests {
         );
         assert_eq!(
             timeline.values_at(0.9).round(),
-            TestValues::new(206, 5625.0)
+            TestValues::new(215, 6502.0)
         );
         assert_eq!(
             timeline.values_at(1.0).round(),
@@ -634,4 +913,184 @@ mod tests {
             TestValues::new(50, 0.0)
         );
     }
+
+    #[test]
+    fn when_start_velocity_overridden_then_spring_continues_in_direction_of_velocity() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::full(10, 0.0), None),
+            Keyframe::new(1.0, TestKeyframeData::full(50, 100.0), None),
+        ];
+        let spring_easing = Easing::Custom(Box::new(Spring::default().easing()));
+        let mut still_timeline = TestTimeline::new(keyframes.clone(), spring_easing.clone());
+        let mut moving_timeline = TestTimeline::new(keyframes, spring_easing);
+
+        still_timeline.start_with(&TestValues::new(50, 100.0));
+        moving_timeline.start_with_velocity(
+            &TestValues::new(50, 100.0),
+            &TestValues::new(55, 110.0),
+            0.01,
+        );
+
+        // The still timeline is already at its end value, so it doesn't move.
+        assert_eq!(still_timeline.values_at(0.02), TestValues::new(50, 100.0));
+        // The moving timeline overshoots before springing back to the end value.
+        let moving_values = moving_timeline.values_at(0.02);
+        assert!(moving_values.foo > 50);
+        assert!(moving_values.bar > 100.0);
+        assert_eq!(moving_timeline.values_at(1.0), TestValues::new(50, 100.0));
+    }
+
+    #[test]
+    fn when_start_velocity_overridden_with_non_spring_easing_then_ignores_velocity() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::full(10, 0.0), None),
+            Keyframe::new(1.0, TestKeyframeData::full(50, 100.0), None),
+        ];
+        let mut timeline = TestTimeline::new(keyframes, Easing::default());
+
+        timeline.start_with_velocity(&TestValues::new(30, 20.0), &TestValues::new(20, 10.0), 0.01);
+
+        assert_eq!(timeline.values_at(0.0), TestValues::new(30, 20.0));
+        assert_eq!(timeline.values_at(0.5), TestValues::new(40, 60.0));
+        assert_eq!(timeline.values_at(1.0), TestValues::new(50, 100.0));
+    }
+
+    #[test]
+    fn responds_to_velocity_only_when_first_easing_has_velocity_response() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::new(Some(10), None), None),
+            Keyframe::new(1.0, TestKeyframeData::new(Some(50), None), None),
+        ];
+        let spring_easing = Easing::Custom(Box::new(Spring::default().easing()));
+        let spring_timeline = TestTimeline::new(keyframes.clone(), spring_easing);
+        let linear_timeline = TestTimeline::new(keyframes, Easing::default());
+
+        assert!(spring_timeline.foo.responds_to_velocity());
+        assert!(!spring_timeline.bar.responds_to_velocity());
+        assert!(!linear_timeline.foo.responds_to_velocity());
+    }
+
+    #[test]
+    fn when_start_value_overridden_again_then_clears_velocity() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::full(10, 0.0), None),
+            Keyframe::new(1.0, TestKeyframeData::full(50, 100.0), None),
+        ];
+        let spring_easing = Easing::Custom(Box::new(Spring::default().easing()));
+        let mut timeline = TestTimeline::new(keyframes, spring_easing);
+
+        timeline.start_with_velocity(
+            &TestValues::new(50, 100.0),
+            &TestValues::new(55, 110.0),
+            0.01,
+        );
+        timeline.start_with(&TestValues::new(50, 100.0));
+
+        assert_eq!(timeline.values_at(0.02), TestValues::new(50, 100.0));
+    }
+
+    #[test]
+    fn when_resampled_then_interpolates_linearly_between_samples() {
+        let keyframes = vec![
+            Keyframe::new(0.25, TestKeyframeData::new(None, Some(50.0)), None),
+            Keyframe::new(0.5, TestKeyframeData::new(Some(80), Some(200.0)), None),
+        ];
+        let timeline = TestTimeline::new(keyframes, Easing::Linear);
+        let resampled = timeline.resample(vec![0.0, 0.5, 1.0]);
+
+        assert_eq!(resampled.values_at(0.0), TestValues::new(0, 0.0));
+        assert_eq!(resampled.values_at(0.25), TestValues::new(40, 100.0));
+        assert_eq!(resampled.values_at(0.5), TestValues::new(80, 200.0));
+        assert_eq!(resampled.values_at(0.75), TestValues::new(80, 200.0));
+    }
+
+    #[test]
+    fn when_resampled_and_start_value_overridden_then_blends_over_original_first_segment() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::full(0, 0.0), None),
+            Keyframe::new(0.5, TestKeyframeData::full(100, 100.0), None),
+            Keyframe::new(1.0, TestKeyframeData::full(0, 0.0), None),
+        ];
+        let mut timeline = TestTimeline::new(keyframes, Easing::OutQuad);
+        let mut resampled = timeline.resample(vec![0.0, 0.25, 0.5, 0.75, 1.0]);
+
+        timeline.start_with(&TestValues::new(60, 60.0));
+        resampled.start_with(&TestValues::new(60, 60.0));
+
+        assert_eq!(resampled.values_at(0.0), TestValues::new(60, 60.0));
+        assert_eq!(resampled.values_at(0.1), timeline.values_at(0.1));
+        assert_eq!(resampled.values_at(0.4), timeline.values_at(0.4));
+        assert_eq!(resampled.values_at(0.5), TestValues::new(100, 100.0));
+        assert_eq!(
+            resampled.values_at(0.625),
+            resampled.non_overridden_values_at(0.625)
+        );
+        assert_eq!(
+            resampled.non_overridden_values_at(0.125).bar,
+            timeline.non_overridden_values_at(0.25).bar / 2.0
+        );
+    }
+
+    #[test]
+    fn when_resampled_then_responds_to_velocity_of_original_first_easing() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::new(None, Some(10.0)), None),
+            Keyframe::new(1.0, TestKeyframeData::new(None, Some(50.0)), None),
+        ];
+        let spring_easing = Easing::Custom(Box::new(Spring::default().easing()));
+        let timeline = TestTimeline::new(keyframes, spring_easing);
+        let resampled = timeline.resample(vec![0.0, 0.5, 1.0]);
+
+        assert!(resampled.bar.responds_to_velocity());
+    }
+
+    #[test]
+    fn when_linear_then_velocity_is_frame_difference_over_frame_duration() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::new(None, Some(0.0)), None),
+            Keyframe::new(0.5, TestKeyframeData::new(None, Some(0.0)), None),
+            Keyframe::new(1.0, TestKeyframeData::new(None, Some(1000.0)), None),
+        ];
+        let timeline = TestTimeline::new(keyframes, Easing::default());
+
+        assert_eq!(timeline.velocities_at(0.25, 1.0).bar, 0.0);
+        assert_relative_eq!(timeline.velocities_at(0.75, 1.0).bar, 2000.0);
+        assert_relative_eq!(timeline.velocities_at(0.75, 0.5).bar, 1000.0);
+        assert_relative_eq!(timeline.velocities_at(0.75, -0.5).bar, -1000.0);
+        assert_eq!(timeline.velocities_at(0.75, 0.0), TestValues::default());
+    }
+
+    #[test]
+    fn when_eased_then_velocity_follows_easing_slope() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::full(0, 0.0), None),
+            Keyframe::new(1.0, TestKeyframeData::full(0, 100.0), None),
+        ];
+        let timeline = TestTimeline::new(keyframes, Easing::InOut);
+
+        assert_relative_eq!(timeline.velocities_at(0.0, 1.0).bar, 0.0, epsilon = 0.01);
+        assert!(timeline.velocities_at(0.25, 1.0).bar < timeline.velocities_at(0.5, 1.0).bar);
+        assert_relative_eq!(timeline.velocities_at(1.0, 1.0).bar, 0.0, epsilon = 0.01);
+    }
+
+    #[test]
+    fn when_start_velocity_overridden_then_velocity_starts_at_initial_velocity() {
+        let keyframes = vec![
+            Keyframe::new(0.0, TestKeyframeData::new(None, Some(0.0)), None),
+            Keyframe::new(1.0, TestKeyframeData::new(None, Some(100.0)), None),
+        ];
+        let spring_easing = Easing::Custom(Box::new(Spring::default().easing()));
+        let mut still_timeline = TestTimeline::new(keyframes.clone(), spring_easing.clone());
+        let mut moving_timeline = TestTimeline::new(keyframes, spring_easing);
+
+        still_timeline.start_with(&TestValues::new(0, 100.0));
+        moving_timeline.start_with_velocity(
+            &TestValues::new(0, 100.0),
+            &TestValues::new(0, 110.0),
+            0.01,
+        );
+
+        assert_relative_eq!(still_timeline.velocities_at(0.0, 1.0).bar, 0.0);
+        assert!(moving_timeline.velocities_at(0.0, 1.0).bar > 0.0);
+    }
 }
@@ -1,6 +1,7 @@
 use mina::{
-    prepare_frame, Easing, Keyframe, KeyframeBuilder, Repeat, SubTimeline, TimeScale, Timeline,
-    TimelineBuilder, TimelineBuilderArguments, TimelineConfiguration, TimelineConfigurationBuilder,
+    prepare_frame, Easing, Keyframe, KeyframeBuilder, MarkerCrossing, Repeat, SubTimeline,
+    TimeScale, Timeline, TimelineBuilder, TimelineBuilderArguments, TimelineConfiguration,
+    TimelineConfigurationBuilder,
 };
 
 // Demonstrates how to write an entire set of timeline and keyframe classes explicitly, without
@@ -57,6 +58,10 @@ impl Timeline for StyleTimeline {
         self.timescale.get_duration()
     }
 
+    fn markers_between(&self, prev_time: f32, time: f32) -> Vec<MarkerCrossing> {
+        self.timescale.markers_between(prev_time, time)
+    }
+
     fn repeat(&self) -> Repeat {
         self.timescale.get_repeat()
     }
@@ -92,6 +97,29 @@ impl Timeline for StyleTimeline {
             values.scale = scale;
         }
     }
+
+    // Only the `scale` has a velocity, since integers don't implement `VectorSpace`. This is the
+    // equivalent of marking only that field with `#[animate(velocity)]`.
+    fn update_velocity_scaled(&self, velocities: &mut Style, time: f32, scale: f32) {
+        let (normalized_time, frame_index, enable_start_override, normalized_rate) =
+            match prepare_frame(time, self.boundary_times.as_slice(), &self.timescale) {
+                Some((normalized_time, frame_index, enable_start_override)) => (
+                    normalized_time,
+                    frame_index,
+                    enable_start_override,
+                    self.timescale.get_normalized_rate(time) * scale,
+                ),
+                None => (0.0, 0, false, 0.0),
+            };
+        if let Some(scale) = self.t_scale.velocity_at(
+            normalized_time,
+            frame_index,
+            enable_start_override,
+            normalized_rate,
+        ) {
+            velocities.scale = scale;
+        }
+    }
 }
 
 impl TimelineBuilder<StyleTimeline> for TimelineConfiguration<StyleKeyframeData> {
@@ -2,9 +2,10 @@ use proc_macro::TokenStream;
 use proc_macro2::{Ident, Span, TokenStream as TokenStream2};
 use quote::{format_ident, quote};
 use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
 use syn::{
     parse2, parse_macro_input, parse_str, spanned::Spanned, Data, DeriveInput, Error, Field,
-    Fields, Lit, Meta, Path, Result, Token, Visibility,
+    Fields, Lit, Meta, Path, Result, Token, Type, Visibility,
 };
 
 pub fn animate_impl(input: TokenStream) -> TokenStream {
@@ -47,7 +48,7 @@ fn expand_animate(input: DeriveInput) -> Result<TokenStream2> {
         let attr_name = parsed_attr.name.to_string();
         match attr_name.as_str() {
             "remote" => {
-                let Lit::Str(value) = parsed_attr.value else {
+                let Some(Lit::Str(value)) = parsed_attr.value else {
                     return Err(Error::new(
                         parsed_attr.span,
                         "Expected value of 'remote' attribute to be a string.",
@@ -78,7 +79,7 @@ fn expand_animate(input: DeriveInput) -> Result<TokenStream2> {
 
     let builder_shortcuts = builder_shortcuts(&name, remote_name, &anim_fields);
     let timeline_struct = timeline_struct(remote_name, &vis, &anim_fields)?;
-    let timeline_builder_impl = timeline_builder_impl(remote_name, &anim_fields);
+    let timeline_builder_impl = timeline_builder_impl(remote_name, &anim_fields)?;
     let keyframe_struct = keyframe_struct(remote_name, &vis, &anim_fields);
     let keyframe_builder = keyframe_builder(&remote_path, &vis, &anim_fields);
     let animate = quote! {
@@ -148,12 +149,61 @@ fn builder_shortcuts(
     }
 }
 
-fn is_animatable(field: &Field) -> bool {
-    field.attrs.iter().any(|attr| {
-        let Meta::Path(ref path) = attr.meta else {
-            return false;
+#[derive(Default)]
+struct FieldOptions {
+    velocity: bool,
+    via: Option<Type>,
+}
+
+fn field_options(field: &Field) -> Result<FieldOptions> {
+    let mut options = FieldOptions::default();
+    for attr in &field.attrs {
+        let Meta::List(ref list) = attr.meta else {
+            continue;
         };
-        is_simple_path(path, "animate")
+        if !is_simple_path(&list.path, "animate") {
+            continue;
+        }
+        let parsed_attrs =
+            list.parse_args_with(Punctuated::<AnimateAttributeInput, Token![,]>::parse_terminated)?;
+        for parsed_attr in parsed_attrs {
+            let attr_name = parsed_attr.name.to_string();
+            match attr_name.as_str() {
+                "velocity" => {
+                    if parsed_attr.value.is_some() {
+                        return Err(Error::new(
+                            parsed_attr.span,
+                            "The 'velocity' attribute does not take a value.",
+                        ));
+                    }
+                    options.velocity = true;
+                }
+                "via" => {
+                    let Some(Lit::Str(value)) = parsed_attr.value else {
+                        return Err(Error::new(
+                            parsed_attr.span,
+                            "Expected value of 'via' attribute to be a string.",
+                        ));
+                    };
+                    options.via = Some(parse_str::<Type>(&value.value())?);
+                }
+                _ => {
+                    return Err(Error::new(
+                        list.span(),
+                        format!("Unrecognized animation attribute: {attr_name}"),
+                    ))
+                }
+            };
+        }
+    }
+    Ok(options)
+}
+
+fn is_animatable(field: &Field) -> bool {
+    field.attrs.iter().any(|attr| match attr.meta {
+        Meta::Path(ref path) => is_simple_path(path, "animate"),
+        Meta::List(ref list) => is_simple_path(&list.path, "animate"),
+        _ => false,
     })
 }
 
@@ -249,22 +299,29 @@ fn keyframe_struct(
     values_struct
 }
 
-fn timeline_builder_impl(remote_name: &Ident, target_fields: &[&Field]) -> TokenStream2 {
+fn timeline_builder_impl(remote_name: &Ident, target_fields: &[&Field]) -> Result<TokenStream2> {
     let timeline_name = format_ident!("{remote_name}Timeline");
     let keyframe_data_name = format_ident!("{remote_name}KeyframeData");
-    let sub_timeline_initializers = target_fields.iter().map(|f| {
-        let field_name = f.ident.as_ref().unwrap();
-        let sub_name = format_ident!("t_{field_name}");
-        quote! {
-            #sub_name: ::mina::SubTimeline::from_keyframes(
-                &args.keyframes,
-                std::default::Default::default(),
-                |keyframe| keyframe.#field_name,
-                args.default_easing.clone()
-            )
-        }
-    });
-    quote! {
+    let sub_timeline_initializers = target_fields
+        .iter()
+        .map(|f| {
+            let field_name = f.ident.as_ref().unwrap();
+            let sub_name = format_ident!("t_{field_name}");
+            let get_value = match field_options(f)?.via {
+                Some(_) => quote! { keyframe.#field_name.map(std::convert::Into::into) },
+                None => quote! { keyframe.#field_name },
+            };
+            Ok(quote! {
+                #sub_name: ::mina::SubTimeline::from_keyframes(
+                    &args.keyframes,
+                    std::default::Default::default(),
+                    |keyframe| #get_value,
+                    args.default_easing.clone()
+                )
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(quote! {
         impl ::mina::TimelineBuilder<#timeline_name>
         for ::mina::TimelineConfiguration<#keyframe_data_name>
         {
@@ -285,7 +342,7 @@ fn timeline_builder_impl(remote_name: &Ident, target_fields: &[&Field]) -> Token
                 ::mina::MergedTimeline::of([::mina::TimelineBuilder::build(self)])
             }
         }
-    }
+    })
 }
 
 fn timeline_struct(
@@ -299,28 +356,123 @@ fn timeline_struct(
         .map(|f| {
             let Field { ident, ty, .. } = f;
             let name = format_ident!("t_{}", ident.as_ref().unwrap());
-            Ok(quote! { #name: ::mina::SubTimeline<#ty> })
+            let sub_type = field_options(f)?.via.unwrap_or_else(|| ty.clone());
+            Ok(quote! { #name: ::mina::SubTimeline<#sub_type> })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    // Fields with a `via` attribute are stored in the sub-timeline as the interpolation type, and
+    // converted to and from the field type at the boundaries.
+    let convert = |f: &Field, value: TokenStream2| -> Result<TokenStream2> {
+        Ok(match field_options(f)?.via {
+            Some(_) => quote! { std::convert::Into::into(#value) },
+            None => value,
+        })
+    };
+    let value_assignments = target_fields
+        .iter()
+        .map(|f| {
+            let field_name = f.ident.as_ref().unwrap();
+            let sub_name = format_ident!("t_{field_name}");
+            let value = convert(f, quote! { #field_name })?;
+            Ok(quote! {
+                if let Some(#field_name) = self
+                    .#sub_name
+                    .value_at(normalized_time, frame_index, enable_start_override)
+                {
+                    target.#field_name = #value;
+                }
+            })
         })
         .collect::<Result<Vec<_>>>()?;
-    let value_assignments = target_fields.iter().map(|f| {
-        let field_name = f.ident.as_ref().unwrap();
-        let sub_name = format_ident!("t_{field_name}");
+    // Velocities require a vector space, so they are only computed for fields that opt in.
+    let velocity_assignments = target_fields
+        .iter()
+        .map(|f| {
+            if !field_options(f)?.velocity {
+                return Ok(None);
+            }
+            let field_name = f.ident.as_ref().unwrap();
+            let sub_name = format_ident!("t_{field_name}");
+            let velocity = convert(f, quote! { #field_name })?;
+            Ok(Some(quote! {
+                if let Some(#field_name) = self.#sub_name.velocity_at(
+                    normalized_time,
+                    frame_index,
+                    enable_start_override,
+                    normalized_rate,
+                ) {
+                    velocities.#field_name = #velocity;
+                }
+            }))
+        })
+        .collect::<Result<Vec<_>>>()?
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+    let update_velocity = if velocity_assignments.is_empty() {
+        quote!()
+    } else {
         quote! {
-            if let Some(#field_name) = self
-                .#sub_name
-                .value_at(normalized_time, frame_index, enable_start_override)
-            {
-                target.#field_name = #field_name;
+            fn update_velocity_scaled(
+                &self,
+                velocities: &mut Self::Target,
+                time: f32,
+                scale: f32,
+            ) {
+                // When the timeline is not active, properties are not moving; a zero rate makes
+                // the sub-timelines report zero velocity.
+                let (normalized_time, frame_index, enable_start_override, normalized_rate) =
+                    match ::mina::prepare_frame(
+                        time,
+                        self.boundary_times.as_slice(),
+                        &self.timescale,
+                    ) {
+                        Some((normalized_time, frame_index, enable_start_override)) => (
+                            normalized_time,
+                            frame_index,
+                            enable_start_override,
+                            self.timescale.get_normalized_rate(time) * scale,
+                        ),
+                        None => (0.0, 0, false, 0.0),
+                    };
+                #(#velocity_assignments)*
             }
         }
-    });
-    let start_value_assignments = target_fields.iter().map(|f| {
-        let field_name = f.ident.as_ref().unwrap();
-        let sub_name = format_ident!("t_{field_name}");
+    };
+    let start_value_assignments = target_fields
+        .iter()
+        .map(|f| {
+            let field_name = f.ident.as_ref().unwrap();
+            let sub_name = format_ident!("t_{field_name}");
+            let value = convert(f, quote! { values.#field_name })?;
+            Ok(quote! {
+                self.#sub_name.override_start_value(#value);
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let start_velocity_assignments = target_fields
+        .iter()
+        .map(|f| {
+            let field_name = f.ident.as_ref().unwrap();
+            let sub_name = format_ident!("t_{field_name}");
+            let value = convert(f, quote! { values.#field_name })?;
+            let next_value = convert(f, quote! { next_values.#field_name })?;
+            Ok(quote! {
+                self.#sub_name.override_start_value(#value);
+                self.#sub_name.override_start_velocity(#next_value, interval);
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let resampled_fields = target_fields.iter().map(|f| {
+        let sub_name = format_ident!("t_{}", f.ident.as_ref().unwrap());
         quote! {
-            self.#sub_name.override_start_value(values.#field_name);
+            #sub_name: self.#sub_name.resample(&self.boundary_times, &sample_times)
         }
     });
+    let sub_names = target_fields
+        .iter()
+        .map(|f| format_ident!("t_{}", f.ident.as_ref().unwrap()))
+        .collect::<Vec<_>>();
     let timeline_struct = quote! {
         #[derive(std::clone::Clone, std::fmt::Debug)]
         #target_visibility struct #name {
@@ -344,6 +496,14 @@ fn timeline_struct(
                 self.timescale.get_duration()
             }
 
+            fn markers_between(
+                &self,
+                prev_time: f32,
+                time: f32,
+            ) -> std::vec::Vec<::mina::MarkerCrossing> {
+                self.timescale.markers_between(prev_time, time)
+            }
+
             fn repeat(&self) -> Repeat {
                 self.timescale.get_repeat()
             }
@@ -352,6 +512,19 @@ fn timeline_struct(
                 #(#start_value_assignments)*
             }
 
+            fn start_with_velocity(
+                &mut self,
+                values: &Self::Target,
+                next_values: &Self::Target,
+                interval: f32,
+            ) {
+                #(#start_velocity_assignments)*
+            }
+
+            fn responds_to_velocity(&self) -> bool {
+                false #(|| self.#sub_names.responds_to_velocity())*
+            }
+
             fn update(&self, target: &mut Self::Target, time: f32) {
                 let Some((normalized_time, frame_index, enable_start_override)) =
                     ::mina::prepare_frame(time, self.boundary_times.as_slice(), &self.timescale)
@@ -360,6 +533,8 @@ fn timeline_struct(
                 };
                 #(#value_assignments)*
             }
+
+            #update_velocity
         }
 
         impl ::mina::TimelineOrBuilder<#name> for #name {
@@ -367,6 +542,20 @@ fn timeline_struct(
                 ::mina::MergedTimeline::of([self])
             }
         }
+
+        impl ::mina::BakeProperties for #name {
+            fn bake_properties(&self, sample_rate: f32) -> Self {
+                let sample_times = ::mina::cycle_sample_times(
+                    self.timescale.get_cycle_duration(),
+                    sample_rate,
+                );
+                Self {
+                    #(#resampled_fields,)*
+                    timescale: self.timescale.clone(),
+                    boundary_times: sample_times,
+                }
+            }
+        }
     };
     Ok(timeline_struct)
 }
@@ -375,17 +564,24 @@ fn timeline_struct(
 struct AnimateAttributeInput {
     span: Span,
     name: Ident,
-    _separator: Token![=],
-    value: Lit,
+    _separator: Option<Token![=]>,
+    value: Option<Lit>,
 }
 
 impl Parse for AnimateAttributeInput {
     fn parse(input: ParseStream) -> Result<Self> {
+        let span = input.span();
+        let name = input.parse()?;
+        let separator: Option<Token![=]> = input.parse()?;
+        let value = match separator {
+            Some(_) => Some(input.parse()?),
+            None => None,
+        };
         Ok(Self {
-            span: input.span(),
-            name: input.parse()?,
-            _separator: input.parse()?,
-            value: input.parse()?,
+            span,
+            name,
+            _separator: separator,
+            value,
         })
     }
 }
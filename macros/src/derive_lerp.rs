@@ -0,0 +1,180 @@
+use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, spanned::Spanned, Data, DataEnum, DeriveInput, Error, Field,
+    Fields, Ident, Index, Meta, Result, WherePredicate,
+};
+
+pub fn lerp_impl(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_lerp(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+fn expand_lerp(input: DeriveInput) -> Result<TokenStream2> {
+    let DeriveInput {
+        ident: name,
+        data,
+        mut generics,
+        ..
+    } = input;
+    let type_params = generics
+        .type_params()
+        .map(|param| param.ident.clone())
+        .collect::<Vec<_>>();
+    let (body, bounds) = match data {
+        Data::Struct(struct_data) => (
+            struct_body(&struct_data.fields)?,
+            field_bounds(&struct_data.fields, &type_params)?,
+        ),
+        Data::Enum(enum_data) => (enum_body(&enum_data)?, Vec::new()),
+        Data::Union(union_data) => {
+            return Err(Error::new(
+                union_data.union_token.span(),
+                "derive(Lerp) does not support unions.",
+            ))
+        }
+    };
+    generics.make_where_clause().predicates.extend(bounds);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::mina::Lerp for #name #type_generics #where_clause {
+            // Unit structs and structs with only skipped fields don't use the arguments.
+            #[allow(unused_variables)]
+            fn lerp(&self, y1: &Self, x: f32) -> Self {
+                #body
+            }
+        }
+    })
+}
+
+fn enum_body(enum_data: &DataEnum) -> Result<TokenStream2> {
+    if let Some(variant) = enum_data
+        .variants
+        .iter()
+        .find(|v| !matches!(v.fields, Fields::Unit))
+    {
+        return Err(Error::new(
+            variant.span(),
+            "derive(Lerp) requires an enum with only unit variants.",
+        ));
+    }
+    // Enum values can't be blended, so they switch at the midpoint of the interpolation.
+    Ok(quote! {
+        if x < 0.5 {
+            std::clone::Clone::clone(self)
+        } else {
+            std::clone::Clone::clone(y1)
+        }
+    })
+}
+
+fn struct_body(fields: &Fields) -> Result<TokenStream2> {
+    match fields {
+        Fields::Named(fields) => {
+            let values = fields
+                .named
+                .iter()
+                .map(|f| {
+                    let field_name = f.ident.as_ref().unwrap();
+                    let value = field_value(f, quote! { #field_name })?;
+                    Ok(quote! { #field_name: #value })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote! { Self { #(#values),* } })
+        }
+        Fields::Unnamed(fields) => {
+            let values = fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| {
+                    let index = Index::from(i);
+                    field_value(f, quote! { #index })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            Ok(quote! { Self(#(#values),*) })
+        }
+        Fields::Unit => Ok(quote! { Self }),
+    }
+}
+
+// Bounds are only needed for fields that depend on the type parameters; other field types are
+// checked by the generated body, and naming them in the bounds could expose private types.
+fn field_bounds(fields: &Fields, type_params: &[Ident]) -> Result<Vec<WherePredicate>> {
+    let mut bounds = Vec::new();
+    for field in fields {
+        let ty = &field.ty;
+        if !mentions_any(quote! { #ty }, type_params) {
+            continue;
+        }
+        bounds.push(match lerp_mode(field)? {
+            LerpMode::Interpolate => parse_quote! { #ty: ::mina::Lerp },
+            LerpMode::Skip | LerpMode::Step => parse_quote! { #ty: std::clone::Clone },
+        });
+    }
+    Ok(bounds)
+}
+
+fn mentions_any(tokens: TokenStream2, idents: &[Ident]) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(ident) => idents.contains(&ident),
+        TokenTree::Group(group) => mentions_any(group.stream(), idents),
+        _ => false,
+    })
+}
+
+fn field_value(field: &Field, member: TokenStream2) -> Result<TokenStream2> {
+    let value = match lerp_mode(field)? {
+        LerpMode::Interpolate => quote! { ::mina::Lerp::lerp(&self.#member, &y1.#member, x) },
+        LerpMode::Skip => quote! { std::clone::Clone::clone(&self.#member) },
+        LerpMode::Step => quote! {
+            if x < 0.5 {
+                std::clone::Clone::clone(&self.#member)
+            } else {
+                std::clone::Clone::clone(&y1.#member)
+            }
+        },
+    };
+    Ok(value)
+}
+
+enum LerpMode {
+    Interpolate,
+    Skip,
+    Step,
+}
+
+fn lerp_mode(field: &Field) -> Result<LerpMode> {
+    let mut mode = None;
+    for attr in &field.attrs {
+        if !attr.path().is_ident("lerp") {
+            continue;
+        }
+        let Meta::List(ref list) = attr.meta else {
+            return Err(Error::new(
+                attr.span(),
+                "Expected a lerp attribute of the form #[lerp(skip)] or #[lerp(step)].",
+            ));
+        };
+        list.parse_nested_meta(|meta| {
+            let next_mode = if meta.path.is_ident("skip") {
+                LerpMode::Skip
+            } else if meta.path.is_ident("step") {
+                LerpMode::Step
+            } else {
+                return Err(meta.error("Unrecognized lerp attribute."));
+            };
+            if mode.is_some() {
+                return Err(meta.error(
+                    "Only one of #[lerp(skip)] or #[lerp(step)] can be specified for a field.",
+                ));
+            }
+            mode = Some(next_mode);
+            Ok(())
+        })?;
+    }
+    Ok(mode.unwrap_or(LerpMode::Interpolate))
+}
@@ -139,6 +139,7 @@ impl Parse for AnimatorDefaults {
 }
 
 #[cfg_attr(feature = "parse-debug", derive(Debug))]
+#[allow(dead_code)] // Tokens are kept for spans and parse-debug output.
 enum AnimatorDefaultValues {
     None,
     Expr(Expr),
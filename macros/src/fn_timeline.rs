@@ -1,3 +1,7 @@
+// Parsed tokens are kept in the syntax types for their spans and for parse-debug output, even
+// where the expansion does not read them.
+#![allow(dead_code)]
+
 use proc_macro::TokenStream;
 use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::quote;
@@ -91,6 +95,22 @@ fn builder_create_timeline(name: &Path, config: &TimelineConfig) -> Result<Token
     let delay_setter = delay.map(|delay_seconds| {
         quote! { .delay_seconds(#delay_seconds) }
     });
+    let repeat_delay = match &config.repeat_delay {
+        Some(repeat_delay) => {
+            Some(repeat_delay.value.as_f32()? * seconds_multiplier(&repeat_delay.value)?)
+        }
+        None => None,
+    };
+    let repeat_delay_setter = repeat_delay.map(|repeat_delay_seconds| {
+        quote! { .repeat_delay_seconds(#repeat_delay_seconds) }
+    });
+    let iteration_start_setter = match &config.iteration_start {
+        Some(iteration_start) => {
+            let cycles = iteration_start.value.as_f32()?;
+            Some(quote! { .iteration_start(#cycles) })
+        }
+        None => None,
+    };
     let easing_setter = config.easing.as_ref().map(|easing| {
         quote! { .default_easing(#easing) }
     });
@@ -99,12 +119,34 @@ fn builder_create_timeline(name: &Path, config: &TimelineConfig) -> Result<Token
             let times: u32 = lit_int.base10_parse()?;
             Some(quote! { .repeat(::mina::Repeat::Times(#times)) })
         }
+        Some(KeyframeRepeatArgument::Fractional(lit_float)) => {
+            let repetitions: f32 = lit_float.base10_parse()?;
+            Some(quote! { .repeat(::mina::Repeat::Fractional(#repetitions)) })
+        }
         Some(KeyframeRepeatArgument::Infinite(_)) => {
             Some(quote! { .repeat(::mina::Repeat::Infinite) })
         }
         _ => None,
     };
     let reverse_setter = config.reverse.map(|_| quote! { .reverse(true) });
+    let direction_setter = config.direction.as_ref().map(|direction| {
+        let variant = match direction.direction {
+            DirectionKeyword::Normal(_) => quote! { Normal },
+            DirectionKeyword::Reverse(_) => quote! { Reverse },
+            DirectionKeyword::Alternate(_) => quote! { Alternate },
+            DirectionKeyword::AlternateReverse(..) => quote! { AlternateReverse },
+        };
+        quote! { .direction(::mina::PlaybackDirection::#variant) }
+    });
+    let fill_mode_setter = config.fill_mode.as_ref().map(|fill_mode| {
+        let variant = match fill_mode.mode {
+            FillModeKeyword::None(_) => quote! { None },
+            FillModeKeyword::Forwards(_) => quote! { Forwards },
+            FillModeKeyword::Backwards(_) => quote! { Backwards },
+            FillModeKeyword::Both(_) => quote! { Both },
+        };
+        quote! { .fill_mode(::mina::FillMode::#variant) }
+    });
     let keyframe_appenders = config
         .keyframes
         .iter()
@@ -116,7 +158,11 @@ fn builder_create_timeline(name: &Path, config: &TimelineConfig) -> Result<Token
             #delay_setter
             #easing_setter
             #repeat_setter
+            #repeat_delay_setter
+            #iteration_start_setter
             #reverse_setter
+            #direction_setter
+            #fill_mode_setter
             #(#keyframe_appenders)*
             .build()
     })
@@ -173,8 +219,12 @@ pub struct TimelineConfig {
     pub _span: Span,
     pub duration: Option<TimelineDurationArgument>,
     pub delay: Option<TimelineDelayArgument>,
+    pub direction: Option<TimelineDirectionArgument>,
     pub easing: Option<Path>,
+    pub fill_mode: Option<TimelineFillModeArgument>,
+    pub iteration_start: Option<TimelineIterationStartArgument>,
     pub repeat: Option<KeyframeRepeatArgument>,
+    pub repeat_delay: Option<TimelineRepeatDelayArgument>,
     pub reverse: Option<kw::reverse>,
     pub keyframes: Vec<KeyframeConfig>,
 }
@@ -185,8 +235,12 @@ impl TimelineConfig {
             _span: span,
             duration: None,
             delay: None,
+            direction: None,
             easing: None,
+            fill_mode: None,
+            iteration_start: None,
             repeat: None,
+            repeat_delay: None,
             reverse: None,
             keyframes: Vec::new(),
         }
@@ -203,6 +257,14 @@ impl Parse for TimelineConfig {
                 config.duration = Some(input.parse()?);
             } else if input.peek(kw::after) {
                 config.delay = Some(input.parse()?);
+            } else if input.peek(kw::every) {
+                config.repeat_delay = Some(input.parse()?);
+            } else if input.peek(kw::direction) {
+                config.direction = Some(input.parse()?);
+            } else if input.peek(kw::fill) {
+                config.fill_mode = Some(input.parse()?);
+            } else if input.peek(kw::start) {
+                config.iteration_start = Some(input.parse()?);
             } else if input.peek(kw::reverse) {
                 config.reverse = Some(input.parse()?);
             } else if input.peek(kw::infinite) {
@@ -238,7 +300,8 @@ impl Parse for TimelineConfig {
                     concat!(
                         "Token type is not supported in timeline syntax. Expected one of: ",
                         "[for] {duration}, after {delay}, {Easing}, reverse, {repeat}x, infinite, ",
-                        "from {keyframe}, to {keyframe}, or {pos}% {keyframe}."
+                        "every {repeat delay}, direction {direction}, fill {mode}, ",
+                        "start {cycles}, from {keyframe}, to {keyframe}, or {pos}% {keyframe}."
                     ),
                 ));
             }
@@ -331,6 +394,134 @@ impl Parse for TimelineDelayArgument {
     }
 }
 
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub struct TimelineRepeatDelayArgument {
+    pub _prefix: kw::every,
+    pub value: NumericLit,
+}
+
+impl Parse for TimelineRepeatDelayArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _prefix: input.parse()?,
+            value: input.parse()?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub struct TimelineIterationStartArgument {
+    pub _prefix: kw::start,
+    pub value: NumericLit,
+}
+
+impl Parse for TimelineIterationStartArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let prefix = input.parse()?;
+        let value: NumericLit = input.parse()?;
+        if !value.suffix().is_empty() {
+            return Err(Error::new(
+                value.span(),
+                "Iteration start must be a number of cycles without a suffix, e.g. 'start 0.5'.",
+            ));
+        }
+        Ok(Self {
+            _prefix: prefix,
+            value,
+        })
+    }
+}
+
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub struct TimelineDirectionArgument {
+    pub _prefix: kw::direction,
+    pub direction: DirectionKeyword,
+}
+
+impl Parse for TimelineDirectionArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _prefix: input.parse()?,
+            direction: input.parse()?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub enum DirectionKeyword {
+    Normal(kw::normal),
+    Reverse(kw::reverse),
+    Alternate(kw::alternate),
+    AlternateReverse(kw::alternate, Token![-], kw::reverse),
+}
+
+impl Parse for DirectionKeyword {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::normal) {
+            Ok(Self::Normal(input.parse()?))
+        } else if input.peek(kw::reverse) {
+            Ok(Self::Reverse(input.parse()?))
+        } else if input.peek(kw::alternate) {
+            let alternate = input.parse()?;
+            if input.peek(Token![-]) {
+                Ok(Self::AlternateReverse(alternate, input.parse()?, input.parse()?))
+            } else {
+                Ok(Self::Alternate(alternate))
+            }
+        } else {
+            Err(Error::new(
+                input.span(),
+                concat!(
+                    "Invalid direction; expected one of 'normal', 'reverse', 'alternate' or ",
+                    "'alternate-reverse'"
+                ),
+            ))
+        }
+    }
+}
+
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub struct TimelineFillModeArgument {
+    pub _prefix: kw::fill,
+    pub mode: FillModeKeyword,
+}
+
+impl Parse for TimelineFillModeArgument {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        Ok(Self {
+            _prefix: input.parse()?,
+            mode: input.parse()?,
+        })
+    }
+}
+
+#[cfg_attr(feature = "parse-debug", derive(Debug))]
+pub enum FillModeKeyword {
+    None(kw::none),
+    Forwards(kw::forwards),
+    Backwards(kw::backwards),
+    Both(kw::both),
+}
+
+impl Parse for FillModeKeyword {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(kw::none) {
+            Ok(Self::None(input.parse()?))
+        } else if input.peek(kw::forwards) {
+            Ok(Self::Forwards(input.parse()?))
+        } else if input.peek(kw::backwards) {
+            Ok(Self::Backwards(input.parse()?))
+        } else if input.peek(kw::both) {
+            Ok(Self::Both(input.parse()?))
+        } else {
+            Err(Error::new(
+                input.span(),
+                "Invalid fill mode; expected one of 'none', 'forwards', 'backwards' or 'both'",
+            ))
+        }
+    }
+}
+
 #[cfg_attr(feature = "parse-debug", derive(Debug))]
 pub struct KeyframeConfig {
     pub position: KeyframePositionArgument,
@@ -374,6 +565,7 @@ pub enum KeyframePositionArgument {
 #[cfg_attr(feature = "parse-debug", derive(Debug))]
 pub enum KeyframeRepeatArgument {
     Fixed(LitInt),
+    Fractional(LitFloat),
     Infinite(kw::infinite),
 }
 
@@ -383,13 +575,13 @@ impl Parse for KeyframeRepeatArgument {
             Ok(Self::Infinite(input.parse()?))
         } else {
             let lit = input.parse::<Lit>()?;
-            if let Lit::Int(lit_int) = lit {
-                Ok(Self::Fixed(lit_int))
-            } else {
-                Err(Error::new(
+            match lit {
+                Lit::Int(lit_int) => Ok(Self::Fixed(lit_int)),
+                Lit::Float(lit_float) => Ok(Self::Fractional(lit_float)),
+                _ => Err(Error::new(
                     lit.span(),
-                    "Repeat argument must be an integer literal",
-                ))
+                    "Repeat argument must be an integer or float literal",
+                )),
             }
         }
     }
@@ -422,4 +614,14 @@ pub mod kw {
     custom_keyword!(after); // Timeline delay
     custom_keyword!(reverse); // Timeline auto-reverses
     custom_keyword!(infinite); // Timeline repeats infinitely
+    custom_keyword!(every); // Delay between repetitions
+    custom_keyword!(direction); // Timeline playback direction
+    custom_keyword!(normal); // Direction: normal
+    custom_keyword!(alternate); // Direction: alternate or alternate-reverse
+    custom_keyword!(fill); // Timeline fill mode
+    custom_keyword!(none); // Fill mode: none
+    custom_keyword!(forwards); // Fill mode: forwards
+    custom_keyword!(backwards); // Fill mode: backwards
+    custom_keyword!(both); // Fill mode: both
+    custom_keyword!(start); // Timeline iteration start
 }
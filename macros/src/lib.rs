@@ -8,6 +8,7 @@ extern crate proc_macro;
 use proc_macro::TokenStream;
 
 mod derive_animate;
+mod derive_lerp;
 mod fn_animator;
 mod fn_timeline;
 
@@ -21,6 +22,11 @@ pub fn derive_animate(input: TokenStream) -> TokenStream {
     derive_animate::animate_impl(input)
 }
 
+#[proc_macro_derive(Lerp, attributes(lerp))]
+pub fn derive_lerp(input: TokenStream) -> TokenStream {
+    derive_lerp::lerp_impl(input)
+}
+
 #[proc_macro]
 pub fn timeline(input: TokenStream) -> TokenStream {
     fn_timeline::timeline_impl(input)
@@ -13,6 +13,9 @@
 //! - Easily specify delayed, repeating or reversing animations.
 //! - Merge heterogeneous animations/transitions into a single timeline; e.g. define a _single_
 //!   animation that pulses in and out infinitely but also scales or slides in only once.
+//! - Chain animations into a [sequence](crate::SequenceTimeline) that plays them back-to-back.
+//! - [Stagger] a single animation across many targets, such as list items.
+//! - Drive animations with [spring](crate::Spring) physics that preserve momentum when interrupted.
 //! - Use with any GUI or creative coding environment -
 //!   [integration examples](https://github.com/focustense/mina/tree/main/examples) are provided for
 //!   [nannou](https://nannou.cc/), [bevy](https://bevyengine.org/) and
@@ -146,15 +149,32 @@
 pub mod prelude;
 
 pub use mina_core::{
+    angle::{AnglePolicy, Clockwise, CounterClockwise, Degrees, Radians, Raw, ShortestArc},
     animator::{EnumStateAnimator, State, StateAnimator, StateAnimatorBuilder},
-    easing::{Easing, EasingFunction},
-    interpolation::Lerp,
+    bake::{cycle_sample_times, BakeProperties, BakedTimeline},
+    easing::{
+        BounceEasing, Easing, EasingDirection, EasingFunction, ElasticEasing, FnEasing, LinearStop,
+        ParseEasingError, ParseEasingErrorKind, PiecewiseLinearEasing, StepPosition, StepsEasing,
+    },
+    interpolation::{Integer, Lerp, Panic, RangePolicy, Saturate, VectorSpace, Wrap},
+    marker::{Marker, MarkerCrossing, MarkerPosition, MAX_BUFFERED_CROSSINGS},
+    player::{PlayerState, TimelinePlayer},
+    spring::{Spring, SpringEasing},
+    stagger::{Stagger, StaggerOrigin, StaggeredTimeline},
     timeline::{
-        Animate, Keyframe, KeyframeBuilder, MergedTimeline, Repeat, Timeline, TimelineBuilder,
-        TimelineConfiguration, TimelineConfigurationBuilder,
+        Animate, ClipTime, ClippedTimeline, FillMode, Keyframe, KeyframeBuilder, MergedTimeline,
+        PlaybackDirection, Repeat, ScaledTimeline, SequenceTimeline, SequenceTimelineBuilder,
+        Timeline, TimelineBuilder, TimelineConfiguration, TimelineConfigurationBuilder,
+        TimelineExt,
     },
 };
 
+#[cfg(feature = "color")]
+pub use mina_core::color;
+
+#[cfg(feature = "glam")]
+pub use mina_core::glam::{Nlerp, Slerp, Squad, SquadRotation};
+
 #[doc(hidden)]
 pub use mina_core::{
     time_scale::TimeScale,
@@ -207,7 +227,7 @@ pub use mina_core::{
 /// animator.set_state(&State::Idle);
 /// assert_eq!(animator.current_values(), &Style { alpha: 0.75, size: 70 });
 /// animator.advance(0.8);
-/// assert_eq!(animator.current_values(), &Style { alpha: 0.554, size: 62 });
+/// assert_eq!(animator.current_values(), &Style { alpha: 0.5904063, size: 64 });
 /// animator.advance(1.2);
 /// assert_eq!(animator.current_values(), &Style { alpha: 0.5, size: 60 });
 /// ```
@@ -236,7 +256,8 @@ pub use mina_macros::animator;
 ///    traits.
 /// 3. All _animated_ fields must implement [`Lerp`].
 ///    - A blanket implementation is provided for all primitive numeric types.
-///    - Other types may need explicit implementations and/or a newtype for unowned types.
+///    - Other types may need explicit implementations and/or a newtype for unowned types. Structs
+///      and unit-only enums can derive the implementation with [`Lerp`](macro@Lerp).
 ///    - **To exclude fields** from animation, either because it is not `Lerp`able or simply because
 ///      it is intended to be constant, add the `#[animate]` helper attribute to all fields which
 ///      _should_ be animated; any remaining fields not decorated will be ignored.
@@ -319,8 +340,127 @@ pub use mina_macros::animator;
 /// calling builder methods manually), the resulting [Timeline] instance has a target of `Style` and
 /// operates directly on `Style` structs. There is no need to pass around newtypes or other
 /// wrappers, or provide any conversion methods.
+///
+/// # Interpolation
+///
+/// By default, each field is interpolated using its own [`Lerp`] implementation. To interpolate a
+/// field differently without changing its type, add a `via` attribute naming another [`Lerp`] type
+/// that the field can be converted to and from (using [`From`] or [`Into`]). The field's values
+/// are converted to the `via` type for interpolation, and the results converted back.
+///
+/// A common case is rotation, which is usually stored as a plain number but should turn the short
+/// way round, rather than from 350° back through 180° to reach 10°:
+///
+/// ```
+/// use mina::prelude::*;
+/// use mina::Degrees;
+///
+/// #[derive(Animate, Clone, Debug, Default, PartialEq)]
+/// struct Style {
+///     #[animate(via = "Degrees")]
+///     rotation: f32,
+/// }
+///
+/// let spin = timeline!(Style 1s from { rotation: 350.0 } to { rotation: 10.0 });
+/// let mut style = Style::default();
+/// spin.update(&mut style, 0.75);
+///
+/// assert_eq!(style.rotation, 5.0);
+/// ```
+///
+/// Similarly, integer fields clamp any values outside their range by default, which can happen
+/// with easings that overshoot; to wrap around or panic instead, use an [`Integer`] wrapper with
+/// a different [`RangePolicy`], e.g. `#[animate(via = "Integer<u8, Wrap>")]`.
+///
+/// A field with a `via` attribute is always animated, the same as if it had the `#[animate]`
+/// attribute.
+///
+/// # Velocity
+///
+/// Generated timelines can report the rate at which fields are changing, using
+/// [`Timeline::update_velocity`]. Velocities are only meaningful for types that form a vector
+/// space, such as floating-point numbers, so each field must opt in with a `velocity` attribute,
+/// and its type (or its `via` type) must implement [`VectorSpace`]. Fields without the attribute
+/// are never updated by [`Timeline::update_velocity`].
+///
+/// ```
+/// use mina::prelude::*;
+///
+/// #[derive(Animate, Clone, Debug, Default, PartialEq)]
+/// struct Style {
+///     #[animate(velocity)]
+///     x: f32,
+///     #[animate]
+///     size: u16,
+/// }
+///
+/// let slide = timeline!(Style 2s from { x: 0.0, size: 10 } to { x: 100.0, size: 20 });
+/// let mut velocities = Style::default();
+/// slide.update_velocity(&mut velocities, 1.0);
+///
+/// assert_eq!(velocities, Style { x: 50.0, size: 0 });
+/// ```
 pub use mina_macros::Animate;
 
+/// Derives the [`Lerp`] trait for a `struct` or `enum` type.
+///
+/// Structs, including tuple structs, are interpolated component-wise, i.e. by `lerp`ing each field
+/// individually, which requires every field to implement [`Lerp`]. This makes it easy to animate
+/// composite values such as rectangles, padding or shadows as a single field of an
+/// [`Animate`](macro@Animate) type.
+///
+/// Fields can be excluded from interpolation with helper attributes:
+///
+/// - `#[lerp(skip)]` always keeps the value of the starting (`y0`) value.
+/// - `#[lerp(step)]` keeps the starting value until halfway through the interpolation, and then
+///   switches to the ending (`y1`) value.
+///
+/// Both attributes require the field to implement [`Clone`], but not [`Lerp`].
+///
+/// Enums must have only unit variants, and step in the same way as a `#[lerp(step)]` field; they
+/// must also implement [`Clone`].
+///
+/// # Example
+///
+/// ```
+/// use mina::prelude::*;
+///
+/// #[derive(Clone, Debug, Default, Lerp, PartialEq)]
+/// enum Visibility {
+///     #[default]
+///     Visible,
+///     Hidden,
+/// }
+///
+/// #[derive(Clone, Debug, Default, Lerp, PartialEq)]
+/// struct Offset(f32, f32);
+///
+/// #[derive(Clone, Debug, Default, Lerp, PartialEq)]
+/// struct Shadow {
+///     offset: Offset,
+///     blur: f32,
+///     visibility: Visibility,
+///     #[lerp(skip)]
+///     name: String,
+/// }
+///
+/// let y0 = Shadow { offset: Offset(0.0, 0.0), blur: 2.0, ..Default::default() };
+/// let y1 = Shadow {
+///     offset: Offset(4.0, 8.0),
+///     blur: 6.0,
+///     visibility: Visibility::Hidden,
+///     name: "end".to_string(),
+/// };
+///
+/// assert_eq!(
+///     y0.lerp(&y1, 0.25),
+///     Shadow { offset: Offset(1.0, 2.0), blur: 3.0, ..Default::default() }
+/// );
+/// assert_eq!(y0.lerp(&y1, 0.75).visibility, Visibility::Hidden);
+/// assert_eq!(y0.lerp(&y1, 1.0).name, "");
+/// ```
+pub use mina_macros::Lerp;
+
 /// Configures and creates a [`Timeline`] for an [`Animate`](macro@Animate) type.
 ///
 /// Provides a more ergonomic, CSS-like alternative to the builder syntax using
@@ -344,15 +484,15 @@ pub use mina_macros::Animate;
 ///
 /// let mut values = Style::default();
 /// timeline.update(&mut values, 0.25);
-/// assert_eq!(values, Style { alpha: 0.578125, size: 58 });
+/// assert_eq!(values, Style { alpha: 0.68906903, size: 69 });
 /// timeline.update(&mut values, 0.5);
-/// assert_eq!(values, Style { alpha: 0.75, size: 75 });
+/// assert_eq!(values, Style { alpha: 0.84232163, size: 84 });
 /// timeline.update(&mut values, 1.0);
 /// assert_eq!(values, Style { alpha: 1.0, size: 100 });
 /// timeline.update(&mut values, 1.25);
-/// assert_eq!(values, Style { alpha: 0.921875, size: 92 });
+/// assert_eq!(values, Style { alpha: 0.9532677, size: 95 });
 /// timeline.update(&mut values, 1.5);
-/// assert_eq!(values, Style { alpha: 0.75, size: 75 });
+/// assert_eq!(values, Style { alpha: 0.84232163, size: 84 });
 /// timeline.update(&mut values, 2.0);
 /// assert_eq!(values, Style { alpha: 0.5, size: 50 });
 /// ```
@@ -1,7 +1,9 @@
 //! Includes the types commonly used for building animations.
 
 pub use crate::{
-    animator, timeline, Animate, Easing, EnumStateAnimator, KeyframeBuilder, MergedTimeline,
-    Repeat, State, StateAnimator, StateAnimatorBuilder, Timeline, TimelineBuilder,
-    TimelineConfiguration, TimelineConfigurationBuilder,
+    animator, timeline, Animate, BakeProperties, ClipTime, Easing, EnumStateAnimator, FillMode,
+    KeyframeBuilder, Lerp, Marker, MergedTimeline, PlaybackDirection, Repeat, ScaledTimeline,
+    SequenceTimeline, SequenceTimelineBuilder, Spring, Stagger, StaggerOrigin, State,
+    StateAnimator, StateAnimatorBuilder, Timeline, TimelineBuilder, TimelineConfiguration,
+    TimelineConfigurationBuilder, TimelineExt, TimelinePlayer,
 };
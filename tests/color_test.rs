@@ -0,0 +1,24 @@
+#![cfg(feature = "color")]
+
+use mina::color::{Oklch, Rgba};
+use mina::prelude::*;
+use mina::Lerp;
+
+#[derive(Animate, Clone, Debug, Default, PartialEq)]
+struct Swatch {
+    fill: Oklch,
+    size: f32,
+}
+
+#[test]
+fn when_color_field_animated_then_blends_in_field_color_space() {
+    let red = Oklch::from(Rgba::new(1.0, 0.0, 0.0, 1.0));
+    let blue = Oklch::from(Rgba::new(0.0, 0.0, 1.0, 1.0));
+    let timeline = timeline!(Swatch 2s from { fill: red } to { fill: blue });
+
+    let mut values = Swatch::default();
+    timeline.update(&mut values, 1.0);
+
+    assert_eq!(values.fill, red.lerp(&blue, 0.5));
+    assert!(values.fill.c > red.c.min(blue.c) * 0.9);
+}
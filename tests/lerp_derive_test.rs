@@ -0,0 +1,172 @@
+use mina::prelude::*;
+
+#[derive(Clone, Copy, Debug, Default, Lerp, PartialEq)]
+struct Rect {
+    x: f32,
+    y: f32,
+    width: u32,
+    height: u32,
+}
+
+#[derive(Clone, Copy, Debug, Default, Lerp, PartialEq)]
+struct Padding(f32, f32);
+
+#[derive(Clone, Copy, Debug, Default, Lerp, PartialEq)]
+enum Cursor {
+    #[default]
+    Arrow,
+    Hand,
+}
+
+#[derive(Clone, Debug, Default, Lerp, PartialEq)]
+struct Labeled {
+    value: f32,
+    #[lerp(step)]
+    cursor: Cursor,
+    #[lerp(skip)]
+    label: String,
+}
+
+#[derive(Clone, Debug, Lerp, PartialEq)]
+struct Placeholder;
+
+#[derive(Clone, Debug, Default, Lerp, PartialEq)]
+struct Pair<T> {
+    first: T,
+    second: T,
+}
+
+#[derive(Clone, Debug, Lerp, PartialEq)]
+struct Tagged<T, U> {
+    value: f32,
+    #[lerp(skip)]
+    tag: T,
+    #[lerp(step)]
+    state: Option<U>,
+}
+
+#[test]
+fn when_named_struct_then_interpolates_each_field() {
+    let y0 = Rect {
+        x: 0.0,
+        y: 10.0,
+        width: 100,
+        height: 50,
+    };
+    let y1 = Rect {
+        x: 20.0,
+        y: 30.0,
+        width: 200,
+        height: 150,
+    };
+
+    assert_eq!(
+        y0.lerp(&y1, 0.25),
+        Rect {
+            x: 5.0,
+            y: 15.0,
+            width: 125,
+            height: 75
+        }
+    );
+}
+
+#[test]
+fn when_tuple_struct_then_interpolates_each_field() {
+    assert_eq!(
+        Padding(0.0, 10.0).lerp(&Padding(10.0, 20.0), 0.5),
+        Padding(5.0, 15.0)
+    );
+}
+
+#[test]
+fn when_unit_enum_then_steps_at_midpoint() {
+    assert_eq!(Cursor::Arrow.lerp(&Cursor::Hand, 0.49), Cursor::Arrow);
+    assert_eq!(Cursor::Arrow.lerp(&Cursor::Hand, 0.5), Cursor::Hand);
+}
+
+#[test]
+fn when_field_has_step_or_skip_attribute_then_does_not_interpolate_field() {
+    let y0 = Labeled {
+        value: 0.0,
+        cursor: Cursor::Arrow,
+        label: "start".to_string(),
+    };
+    let y1 = Labeled {
+        value: 10.0,
+        cursor: Cursor::Hand,
+        label: "end".to_string(),
+    };
+
+    let early = y0.lerp(&y1, 0.25);
+    let late = y0.lerp(&y1, 0.75);
+
+    assert_eq!((early.value, early.cursor), (2.5, Cursor::Arrow));
+    assert_eq!((late.value, late.cursor), (7.5, Cursor::Hand));
+    assert_eq!(late.label, "start");
+}
+
+#[test]
+fn when_unit_struct_then_is_unchanged() {
+    assert_eq!(Placeholder.lerp(&Placeholder, 0.5), Placeholder);
+}
+
+#[test]
+fn when_generic_struct_then_interpolates_with_type_argument() {
+    let y0 = Pair {
+        first: 0.0f32,
+        second: 100.0,
+    };
+    let y1 = Pair {
+        first: 10.0,
+        second: 0.0,
+    };
+
+    assert_eq!(
+        y0.lerp(&y1, 0.5),
+        Pair {
+            first: 5.0,
+            second: 50.0
+        }
+    );
+}
+
+#[test]
+fn when_generic_field_not_interpolated_then_type_argument_only_needs_clone() {
+    let y0 = Tagged {
+        value: 0.0,
+        tag: "start".to_string(),
+        state: Some(vec![1]),
+    };
+    let y1 = Tagged {
+        value: 10.0,
+        tag: "end".to_string(),
+        state: None,
+    };
+
+    assert_eq!(
+        y0.lerp(&y1, 0.75),
+        Tagged {
+            value: 7.5,
+            tag: "start".to_string(),
+            state: None
+        }
+    );
+}
+
+#[test]
+fn when_derived_type_is_animated_field_then_animates_in_timeline() {
+    #[derive(Animate, Clone, Debug, Default, PartialEq)]
+    struct Style {
+        bounds: Rect,
+        padding: Padding,
+    }
+
+    let timeline = timeline!(Style 2s
+        from { padding: Padding(0.0, 0.0) }
+        to { padding: Padding(8.0, 4.0) });
+    let mut values = Style::default();
+    timeline.update(&mut values, 1.0);
+
+    assert_eq!(values.padding, Padding(4.0, 2.0));
+}
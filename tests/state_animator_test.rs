@@ -1,4 +1,7 @@
+#![allow(clippy::bool_assert_comparison)]
+
 use mina::prelude::*;
+use mina::MarkerCrossing;
 
 #[derive(Clone, Debug, Default, Eq, PartialEq, State)]
 enum Interaction {
@@ -18,6 +21,11 @@ impl Style {
     }
 }
 
+#[derive(Animate, Clone, Debug, Default, PartialEq)]
+struct Position {
+    x: f32,
+}
+
 mod using_builder {
     use super::*;
 
@@ -238,6 +246,122 @@ mod using_builder {
         assert_eq!(animator.is_ended(), true);
     }
 
+    #[test]
+    fn when_timeline_is_sequence_then_animates_each_timeline_in_order() {
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, SequenceTimelineBuilder::new()
+                .then(Style::timeline()
+                    .duration_seconds(2.0)
+                    .keyframe(Style::keyframe(1.0).x(40)))
+                .then(Style::timeline()
+                    .duration_seconds(2.0)
+                    .keyframe(Style::keyframe(0.0).x(0).y(20))
+                    .keyframe(Style::keyframe(1.0).x(80).y(40))))
+            .build();
+
+        let frame_values = run_animator(&mut animator, 1.0, 5.0);
+
+        assert_eq!(frame_values, &[
+            Style { x: 0, y: 0 },
+            Style { x: 20, y: 0 },
+            // Second timeline starts where the first one ended
+            Style { x: 40, y: 20 },
+            Style { x: 60, y: 30 },
+            Style { x: 80, y: 40 },
+            Style { x: 80, y: 40 },
+        ]);
+        assert!(animator.is_ended());
+    }
+
+    #[test]
+    fn when_state_changed_during_spring_then_preserves_velocity() {
+        let spring = Spring::default();
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, Position::timeline()
+                .spring(spring)
+                .keyframe(Position::keyframe(1.0).x(100.0)))
+            .on(Interaction::B, Position::timeline()
+                .spring(spring)
+                .keyframe(Position::keyframe(1.0).x(0.0)))
+            .build();
+
+        animator.advance(0.1);
+        let interrupted_x = animator.current_values().x;
+        animator.set_state(&Interaction::B);
+        animator.advance(0.02);
+        let continued_x = animator.current_values().x;
+        animator.advance(spring.settle_duration());
+        let settled_x = animator.current_values().x;
+
+        assert!(interrupted_x > 0.0 && interrupted_x < 100.0);
+        assert!(
+            continued_x > interrupted_x,
+            "Expected {continued_x} to keep moving past {interrupted_x}"
+        );
+        assert_eq!(settled_x, 0.0);
+        assert!(animator.is_ended());
+    }
+
+    #[test]
+    fn when_state_changed_to_baked_timeline_then_blends_like_original() {
+        let timeline_a = || Style::timeline()
+            .duration_seconds(5.0)
+            .keyframe(Style::keyframe(0.0).x(0))
+            .keyframe(Style::keyframe(1.0).x(100));
+        let timeline_b = Style::timeline()
+            .duration_seconds(5.0)
+            .default_easing(Easing::OutQuad)
+            .keyframe(Style::keyframe(0.0).x(100).y(50))
+            .keyframe(Style::keyframe(1.0).x(20).y(80))
+            .build();
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, timeline_a())
+            .on(Interaction::B, timeline_b.clone())
+            .build();
+        let mut baked_animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, timeline_a())
+            .on(Interaction::B, timeline_b.bake_properties(1.0))
+            .build();
+
+        run_animator(&mut animator, 1.0, 3.0);
+        run_animator(&mut baked_animator, 1.0, 3.0);
+        animator.set_state(&Interaction::B);
+        baked_animator.set_state(&Interaction::B);
+        let frame_values = run_animator(&mut animator, 0.5, 6.0);
+        let baked_frame_values = run_animator(&mut baked_animator, 0.5, 6.0);
+
+        assert_eq!(baked_frame_values[0], Style { x: 60, y: 0 });
+        assert_eq!(baked_frame_values, frame_values);
+    }
+
+    #[test]
+    fn when_easing_is_closure_then_animates_along_closure_curve() {
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, Style::timeline()
+                .duration_seconds(4.0)
+                .default_easing(Easing::from_fn(|x| x * x))
+                .keyframe(Style::keyframe(0.0).x(0).y(0))
+                .keyframe(Style::keyframe(0.5).x(100).y(100)
+                    .easing(Easing::from_fn(|x| if x < 0.5 { 0.0 } else { 1.0 })))
+                .keyframe(Style::keyframe(1.0).x(200).y(200)))
+            .build();
+
+        let frame_values = run_animator(&mut animator, 1.0, 4.0);
+
+        assert_eq!(frame_values, &[
+            Style { x: 0, y: 0 },
+            Style { x: 25, y: 25 },
+            Style { x: 100, y: 100 },
+            Style { x: 200, y: 200 },
+            Style { x: 200, y: 200 },
+        ]);
+    }
+
     #[test]
     fn when_state_is_not_animated_then_is_ended() {
         let mut animator = StateAnimatorBuilder::new()
@@ -250,6 +374,85 @@ mod using_builder {
 
         assert_eq!(animator.is_ended(), true);
     }
+
+    #[test]
+    fn when_timeline_scaled_then_animates_at_scaled_speed() {
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, Style::timeline()
+                .duration_seconds(4.0)
+                .keyframe(Style::keyframe(0.0).x(0))
+                .keyframe(Style::keyframe(1.0).x(100))
+                .build()
+                .with_speed(2.0))
+            .on(Interaction::B, ScaledTimeline::from(Style::timeline()
+                .duration_seconds(2.0)
+                .keyframe(Style::keyframe(1.0).x(50))
+                .build()))
+            .build();
+
+        let frame_values_a = run_animator(&mut animator, 0.5, 2.0);
+        animator.set_state(&Interaction::B);
+        let frame_values_b = run_animator(&mut animator, 1.0, 2.0);
+
+        assert_eq!(frame_values_a, &[
+            Style { x: 0, y: 0 },
+            Style { x: 25, y: 0 },
+            Style { x: 50, y: 0 },
+            Style { x: 75, y: 0 },
+            Style { x: 100, y: 0 },
+        ]);
+        assert_eq!(frame_values_b, &[
+            Style { x: 100, y: 0 },
+            Style { x: 75, y: 0 },
+            Style { x: 50, y: 0 },
+        ]);
+    }
+
+    #[test]
+    fn when_timeline_has_markers_then_buffers_crossed_markers() {
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, Position::timeline()
+                .duration_seconds(2.0)
+                .repeat(Repeat::Times(1))
+                .marker(Marker::at("start", 0.0))
+                .marker(Marker::at("half", 0.5))
+                .keyframe(Position::keyframe(1.0).x(10.0)))
+            .build();
+
+        animator.advance(0.5);
+        let markers_first_step = marker_names(animator.take_markers());
+        animator.advance(3.0);
+        animator.advance(1.0);
+        let markers_next_steps = marker_names(animator.take_markers());
+
+        assert_eq!(markers_first_step, &["start"]);
+        assert_eq!(markers_next_steps, &["half", "start", "half"]);
+        assert!(animator.take_markers().is_empty());
+    }
+
+    #[test]
+    fn when_state_changed_then_reports_markers_of_new_timeline_from_start() {
+        let mut animator = StateAnimatorBuilder::new()
+            .from_state(Interaction::A)
+            .on(Interaction::A, Position::timeline()
+                .duration_seconds(2.0)
+                .marker(Marker::at("a_end", 1.0))
+                .keyframe(Position::keyframe(1.0).x(10.0)))
+            .on(Interaction::B, Position::timeline()
+                .duration_seconds(2.0)
+                .marker(Marker::at("b_start", 0.0))
+                .marker(Marker::at_seconds("b_second", 1.0))
+                .keyframe(Position::keyframe(1.0).x(20.0)))
+            .build();
+
+        animator.advance(1.0);
+        animator.set_state(&Interaction::B);
+        animator.advance(1.0);
+
+        assert_eq!(marker_names(animator.take_markers()), &["b_start", "b_second"]);
+    }
 }
 
 mod using_macro {
@@ -311,6 +514,10 @@ mod using_macro {
     }
 }
 
+fn marker_names(crossings: Vec<MarkerCrossing>) -> Vec<String> {
+    crossings.into_iter().map(|crossing| crossing.name).collect()
+}
+
 fn run_animator(
     animator: &mut impl StateAnimator<State = Interaction, Values = Style>,
     time_step: f32,
@@ -0,0 +1,440 @@
+use mina::prelude::*;
+
+#[derive(Animate, Clone, Debug, Default, PartialEq)]
+struct Style {
+    #[animate(velocity)]
+    x: f32,
+    #[animate(velocity)]
+    y: f32,
+}
+
+fn sample(timeline: &impl Timeline<Target = Style>, time: f32) -> Style {
+    let mut values = Style { x: -1.0, y: -1.0 };
+    timeline.update(&mut values, time);
+    values
+}
+
+mod direction {
+    use super::*;
+
+    #[test]
+    fn when_direction_normal_then_plays_forward() {
+        let timeline = timeline!(Style 2s 1x direction normal from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(sample(&timeline, 0.5).x, 25.0);
+        assert_eq!(sample(&timeline, 2.5).x, 25.0);
+    }
+
+    #[test]
+    fn when_direction_reverse_then_plays_backward() {
+        let timeline = timeline!(Style 2s 1x direction reverse from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(sample(&timeline, 0.5).x, 75.0);
+        assert_eq!(sample(&timeline, 2.5).x, 75.0);
+        assert_eq!(sample(&timeline, 5.0).x, 0.0);
+    }
+
+    #[test]
+    fn when_direction_alternate_then_cycles_alternate_starting_forward() {
+        let timeline = timeline!(Style 2s 1x direction alternate from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(sample(&timeline, 0.5).x, 25.0);
+        assert_eq!(sample(&timeline, 2.5).x, 75.0);
+        assert_eq!(sample(&timeline, 5.0).x, 0.0);
+    }
+
+    #[test]
+    fn when_direction_alternate_reverse_then_cycles_alternate_starting_backward() {
+        let timeline = timeline!(Style 2s 1x direction alternate-reverse
+            from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(sample(&timeline, 0.5).x, 75.0);
+        assert_eq!(sample(&timeline, 2.5).x, 25.0);
+        assert_eq!(sample(&timeline, 5.0).x, 100.0);
+    }
+
+    #[test]
+    fn when_playing_backward_then_easing_is_mirrored() {
+        let forward = timeline!(Style 2s Easing::InQuad from { x: 0.0 } to { x: 100.0 });
+        let backward = timeline!(Style 2s Easing::InQuad direction reverse
+            from { x: 0.0 } to { x: 100.0 });
+
+        for step in 0..=8 {
+            let time = step as f32 * 0.25;
+            assert_eq!(sample(&backward, time).x, sample(&forward, 2.0 - time).x);
+        }
+    }
+}
+
+mod repeat {
+    use super::*;
+
+    #[test]
+    fn when_repeat_fractional_then_ends_partway_through_cycle() {
+        let timeline = timeline!(Style 2s 1.5x from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(timeline.duration(), 5.0);
+        assert_eq!(timeline.repeat(), Repeat::Fractional(1.5));
+        assert_eq!(sample(&timeline, 4.5).x, 25.0);
+        assert_eq!(sample(&timeline, 6.0).x, 50.0);
+    }
+
+    #[test]
+    fn when_repeat_delay_then_waits_between_cycles() {
+        let timeline = timeline!(Style 2s 2x every 500ms from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(timeline.duration(), 7.0);
+        assert_eq!(sample(&timeline, 1.0).x, 50.0);
+        assert_eq!(sample(&timeline, 2.25).x, 100.0);
+        assert_eq!(sample(&timeline, 3.0).x, 25.0);
+        assert_eq!(sample(&timeline, 6.0).x, 50.0);
+    }
+
+    #[test]
+    fn when_iteration_start_offset_then_starts_partway_through_cycle() {
+        let timeline = Style::timeline()
+            .duration_seconds(2.0)
+            .delay_seconds(1.0)
+            .iteration_start(0.5)
+            .keyframe(Style::keyframe(0.0).x(0.0))
+            .keyframe(Style::keyframe(1.0).x(100.0))
+            .build();
+
+        assert_eq!(timeline.duration(), 3.0);
+        assert_eq!(sample(&timeline, 0.5).x, 50.0);
+        assert_eq!(sample(&timeline, 1.5).x, 75.0);
+        assert_eq!(sample(&timeline, 2.5).x, 25.0);
+        assert_eq!(sample(&timeline, 4.0).x, 50.0);
+    }
+
+    #[test]
+    fn when_iteration_start_in_macro_then_starts_partway_through_cycle() {
+        let timeline = timeline!(Style 2s after 1s start 0.5 from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(timeline.duration(), 3.0);
+        assert_eq!(sample(&timeline, 1.5).x, 75.0);
+        assert_eq!(sample(&timeline, 2.5).x, 25.0);
+    }
+
+    #[test]
+    fn when_repeat_from_iterations_then_plays_total_cycle_count() {
+        let timeline = Style::timeline()
+            .duration_seconds(2.0)
+            .repeat(Repeat::iterations(0.5))
+            .keyframe(Style::keyframe(0.0).x(0.0))
+            .keyframe(Style::keyframe(1.0).x(100.0))
+            .build();
+
+        assert_eq!(timeline.duration(), 1.0);
+        assert_eq!(sample(&timeline, 0.5).x, 25.0);
+        assert_eq!(sample(&timeline, 2.0).x, 50.0);
+    }
+}
+
+mod clip {
+    use super::*;
+
+    #[test]
+    fn when_clipped_then_plays_only_window() {
+        let timeline = timeline!(Style 4s from { x: 0.0 } to { x: 100.0 })
+            .clip(ClipTime::Seconds(1.0), ClipTime::Seconds(3.0));
+
+        assert_eq!(timeline.duration(), 2.0);
+        assert_eq!(sample(&timeline, 0.0).x, 25.0);
+        assert_eq!(sample(&timeline, 1.0).x, 50.0);
+        assert_eq!(sample(&timeline, 5.0).x, 75.0);
+    }
+
+    #[test]
+    fn when_clipped_and_looping_then_repeats_window() {
+        let timeline = timeline!(Style 4s from { x: 0.0 } to { x: 100.0 })
+            .clip(ClipTime::Normalized(0.25), ClipTime::Normalized(0.75))
+            .looping(true);
+
+        assert_eq!(sample(&timeline, 0.0).x, 25.0);
+        assert_eq!(sample(&timeline, 2.0).x, 75.0);
+        assert_eq!(sample(&timeline, 3.0).x, 50.0);
+    }
+}
+
+mod bake {
+    use super::*;
+
+    #[derive(Animate, Clone, Debug, PartialEq)]
+    struct Label {
+        text: String,
+        #[animate]
+        x: f32,
+    }
+
+    #[test]
+    fn when_properties_baked_then_interpolates_linearly_between_samples() {
+        let timeline = timeline!(Style 2s after 1s Easing::OutQuad from { x: 0.0 } to { x: 100.0 });
+        let baked = timeline.bake_properties(2.0);
+        let first_sample = sample(&timeline, 1.5).x;
+
+        assert!(first_sample > 25.0);
+        assert_eq!(sample(&baked, 0.5), Style { x: 0.0, y: -1.0 });
+        assert_eq!(sample(&baked, 1.25).x, first_sample / 2.0);
+        assert_eq!(sample(&baked, 1.5), sample(&timeline, 1.5));
+        assert_eq!(sample(&baked, 2.0), sample(&timeline, 2.0));
+        assert_eq!(sample(&baked, 4.0), Style { x: 100.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_merged_timeline_baked_then_bakes_each_part() {
+        let timeline = timeline!(Style [
+            2s from { x: 0.0 } to { x: 100.0 },
+            1s from { y: 0.0 } to { y: 10.0 }
+        ]);
+        let baked = timeline.bake_properties(4.0);
+
+        assert_eq!(sample(&baked, 0.5), Style { x: 25.0, y: 5.0 });
+        assert_eq!(sample(&baked, 1.5), Style { x: 75.0, y: 10.0 });
+    }
+
+    #[test]
+    fn when_properties_baked_then_does_not_update_other_fields() {
+        let timeline = timeline!(Label 1s from { x: 0.0 } to { x: 10.0 });
+        let baked = timeline.bake_properties(10.0);
+        let mut label = Label {
+            text: "label".to_string(),
+            x: -1.0,
+        };
+
+        baked.update(&mut label, 0.5);
+
+        assert_eq!(
+            label,
+            Label {
+                text: "label".to_string(),
+                x: 5.0,
+            }
+        );
+    }
+}
+
+mod velocity {
+    use super::*;
+
+    fn velocity(timeline: &impl Timeline<Target = Style>, time: f32) -> Style {
+        let mut velocities = Style { x: -1.0, y: -1.0 };
+        timeline.update_velocity(&mut velocities, time);
+        velocities
+    }
+
+    #[test]
+    fn when_active_then_velocity_is_rate_of_change() {
+        let timeline = timeline!(Style 2s after 1s from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(velocity(&timeline, 0.5), Style { x: 0.0, y: -1.0 });
+        assert_eq!(velocity(&timeline, 2.0), Style { x: 50.0, y: -1.0 });
+        assert_eq!(velocity(&timeline, 5.0), Style { x: 0.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_playing_backward_then_velocity_is_negative() {
+        let timeline = timeline!(Style 2s reverse from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(velocity(&timeline, 0.5).x, 100.0);
+        assert_eq!(velocity(&timeline, 1.5).x, -100.0);
+    }
+
+    #[test]
+    fn when_scaled_then_velocity_is_scaled() {
+        let timeline = timeline!(Style 2s from { x: 0.0 } to { x: 100.0 }).with_speed(-2.0);
+
+        assert_eq!(velocity(&timeline, 0.5).x, -100.0);
+    }
+
+    #[test]
+    fn when_spring_then_responds_to_velocity() {
+        let spring_timeline = Style::timeline()
+            .spring(Spring::default())
+            .keyframe(Style::keyframe(1.0).x(100.0))
+            .build();
+        let eased_timeline = timeline!(Style 2s from { x: 0.0 } to { x: 100.0 });
+
+        assert!(spring_timeline.responds_to_velocity());
+        assert!(!spring_timeline
+            .clone()
+            .with_speed(0.0)
+            .responds_to_velocity());
+        assert!(!eased_timeline.responds_to_velocity());
+    }
+
+    #[test]
+    fn when_fill_none_and_not_active_then_velocity_is_zero() {
+        let timeline = timeline!(Style 2s after 1s fill none from { x: 0.0 } to { x: 100.0 });
+
+        assert_eq!(velocity(&timeline, 0.5), Style { x: 0.0, y: -1.0 });
+        assert_eq!(velocity(&timeline, 2.0), Style { x: 50.0, y: -1.0 });
+        assert_eq!(velocity(&timeline, 5.0), Style { x: 0.0, y: -1.0 });
+    }
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq)]
+    struct Opacity(f32);
+
+    impl Lerp for Opacity {
+        fn lerp(&self, y1: &Self, x: f32) -> Self {
+            Self(self.0.lerp(&y1.0, x))
+        }
+    }
+
+    #[derive(Animate, Clone, Debug, PartialEq)]
+    struct Layer {
+        #[animate(velocity)]
+        x: f32,
+        #[animate]
+        opacity: Opacity,
+        #[animate]
+        z_index: u8,
+    }
+
+    #[test]
+    fn when_velocity_not_enabled_then_field_is_not_updated() {
+        let timeline = timeline!(Layer 2s
+            from { x: 0.0, opacity: Opacity(0.0), z_index: 0 }
+            to { x: 100.0, opacity: Opacity(1.0), z_index: 10 });
+        let mut velocities = Layer {
+            x: -1.0,
+            opacity: Opacity(-1.0),
+            z_index: 99,
+        };
+
+        timeline.update_velocity(&mut velocities, 1.0);
+
+        assert_eq!(
+            velocities,
+            Layer {
+                x: 50.0,
+                opacity: Opacity(-1.0),
+                z_index: 99,
+            }
+        );
+    }
+}
+
+mod fill_mode {
+    use super::*;
+
+    #[test]
+    fn when_fill_mode_not_specified_then_fills_both_ways() {
+        let timeline = timeline!(Style 2s after 1s from { x: 10.0 } to { x: 20.0 });
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: 10.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 2.0), Style { x: 15.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: 20.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_fill_none_then_does_not_update_outside_active_period() {
+        let timeline = timeline!(Style 2s after 1s fill none from { x: 10.0 } to { x: 20.0 });
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: -1.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 2.0), Style { x: 15.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: -1.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_fill_forwards_then_only_holds_end_values() {
+        let timeline = timeline!(Style 2s after 1s fill forwards from { x: 10.0 } to { x: 20.0 });
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: -1.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: 20.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_fill_backwards_then_only_applies_start_values_during_delay() {
+        let timeline = timeline!(Style 2s after 1s fill backwards from { x: 10.0 } to { x: 20.0 });
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: 10.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: -1.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_fill_both_then_fills_both_ways() {
+        let timeline = timeline!(Style 2s after 1s fill both from { x: 10.0 } to { x: 20.0 });
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: 10.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: 20.0, y: -1.0 });
+    }
+
+    #[test]
+    fn when_fill_mode_set_with_builder_then_applies_to_timeline() {
+        let timeline = Style::timeline()
+            .duration_seconds(2.0)
+            .delay_seconds(1.0)
+            .fill_mode(FillMode::None)
+            .keyframe(Style::keyframe(0.0).x(10.0))
+            .keyframe(Style::keyframe(1.0).x(20.0))
+            .build();
+
+        assert_eq!(sample(&timeline, 0.5), Style { x: -1.0, y: -1.0 });
+        assert_eq!(sample(&timeline, 5.0), Style { x: -1.0, y: -1.0 });
+    }
+}
+
+mod via {
+    use super::*;
+    use mina::{CounterClockwise, Degrees, Integer, Wrap};
+
+    #[derive(Animate, Clone, Debug, Default, PartialEq)]
+    struct Transform {
+        #[animate(via = "Degrees")]
+        rotation: f32,
+        #[animate(velocity, via = "Degrees<CounterClockwise>")]
+        skew: f32,
+        #[animate]
+        x: f32,
+        scale: f32,
+    }
+
+    #[test]
+    fn when_field_has_via_attribute_then_interpolates_as_via_type() {
+        let timeline = timeline!(Transform 2s
+            from { rotation: 350.0, skew: 10.0, x: 0.0 }
+            to { rotation: 10.0, skew: 20.0, x: 100.0 });
+
+        let mut values = Transform::default();
+        timeline.update(&mut values, 1.5);
+
+        assert_eq!(values.rotation, 5.0);
+        assert_eq!(values.skew, 107.5);
+        assert_eq!(values.x, 75.0);
+        assert_eq!(values.scale, 0.0);
+    }
+
+    #[test]
+    fn when_angle_field_has_velocity_then_velocity_is_unwrapped_turn() {
+        let timeline = timeline!(Transform 1s Easing::Linear
+            from { skew: 10.0 }
+            to { skew: 195.0 });
+
+        let mut velocities = Transform::default();
+        timeline.update_velocity(&mut velocities, 0.5);
+
+        assert_eq!(velocities.skew, -175.0);
+    }
+
+    #[derive(Animate, Clone, Debug, Default, PartialEq)]
+    struct Overlay {
+        #[animate]
+        alpha: u8,
+        #[animate(via = "Integer<u8, Wrap>")]
+        hue: u8,
+    }
+
+    #[test]
+    fn when_easing_overshoots_integer_range_then_applies_range_policy() {
+        let timeline = timeline!(Overlay 1s Easing::OutBack
+            from { alpha: 0, hue: 0 }
+            to { alpha: 255, hue: 255 });
+
+        let mut values = Overlay::default();
+        timeline.update(&mut values, 0.6);
+
+        assert_eq!(values.alpha, 255);
+        assert!(values.hue < 128);
+    }
+}